@@ -1,14 +1,58 @@
 {
 AppConfig {
     general: GeneralConfig {
+        theme: None,
         rounded_corners: true,
         show_borders: true,
         highlight_symbol: Some(String::from(">> ")),
         favorite_symbol: Some(String::from("★ ")),
+        ellipsis: Some(String::from("…")),
         favorite_key: Some(String::from("alt+f")),
+        favorite_up_key: Some(String::from("alt+shift+up")),
+        favorite_down_key: Some(String::from("alt+shift+down")),
+        numbered_favorites: false,
+        columns: 1,
+        layout: ListLayout::Top,
+        max_visible_entries: 0,
+        hide_key: Some(String::from("alt+h")),
         jump_to_top_key: Some(String::from("alt+up")),
         jump_to_bottom_key: Some(String::from("alt+down")),
+        debug_overlay_key: None,
+        half_page_down_key: None,
+        half_page_up_key: None,
+        file_sort_key: None,
+        hidden_files_key: Some(String::from("ctrl+h")),
+        bookmark_key: Some(String::from("alt+b")),
+        open_with_key: Some(String::from("ctrl+o")),
+        delete_component_key: Some(String::from("ctrl+backspace")),
+        parent_dir_key: Some(String::from("alt+left")),
+        mark_key: Some(String::from("ctrl+space")),
+        recursive_key: Some(String::from("alt+r")),
+        open_dir_key: Some(String::from("ctrl+enter")),
+        keep_open_key: Some(String::from("alt+enter")),
+        run_detached_key: Some(String::from("shift+enter")),
+        copy_key: Some(String::from("ctrl+y")),
+        force_kill_key: Some(String::from("alt+enter")),
+        process_refresh_key: Some(String::from("ctrl+r")),
+        window_switcher_key: Some(String::from("alt+w")),
+        print_key: Some(String::from("ctrl+p")),
+        clear_history_key: Some(String::from("alt+c")),
+        reload_config_key: Some(String::from("ctrl+r")),
+        help_key: Some(String::from("f1")),
+        dismiss_warning_key: Some(String::from("alt+x")),
+        paste_key: Some(String::from("ctrl+v")),
+        tick_rate_ms: 80,
+        desktop_environment: None,
         clipboard_command: None,
+        paste_command: None,
+        file_opener: None,
+        dir_opener: None,
+        terminal: None,
+        history_path: None,
+        launch_method: LaunchMethod::Spawn,
+        pre_launch_hook: None,
+        post_launch_hook: None,
+        abort_on_hook_failure: false,
     },
     features: FeaturesConfig {
         enable_file_explorer: true,
@@ -17,19 +61,60 @@ AppConfig {
         dirs_first: true,
         show_duplicates: false,
         recent_first: true,
+        include_path_binaries: false,
+        transliterate_search: false,
+        cache_entries: true,
+        focus_if_running: false,
+        show_missing_favorites: false,
+        search_keywords: true,
+        ignore_show_in: false,
+        exclude: vec![],
+        single_instance: false,
+        file_details: false,
+        file_sort: FileSort::Name,
+        show_hidden_files: false,
+        recent_files_limit: 100,
+        internal_mime_resolution: false,
+        url_open: true,
+        power_menu: false,
+        history_max_entries: 0,
+        history_max_age_days: 0,
+        show_usage_count: false,
+        show_match_count: false,
+        detailed_entries: false,
+        auto_reload_config: false,
+        backspace_path_components: true,
+        max_file_results: 500,
+        recursive_depth: 2,
+        ignore_dirs: vec![String::from(".git"), String::from("node_modules")],
+        expand_env_vars: false,
+        undefined_env_vars: UndefinedEnvVars::Literal,
+        close_on_launch: true,
+        capture_launch_errors: false,
+        run_scripts_in_terminal: true,
     },
-    window: SectionConfig {
-        title: None,
-        fg: vec![],
-        bg: vec![String::from("#000000")],
-        border_color: vec![],
-        border_angle: 90,
-        gradient_angle: 90,
-        full_width_highlight: None,
-        rounded: None,
-        borders: None,
-        visible: Some(false),
-        title_alignment: None,
+    window: WindowConfig {
+        section: SectionConfig {
+            title: None,
+            fg: vec![],
+            bg: vec![String::from("#000000")],
+            border_color: vec![],
+            border_angle: 90,
+            gradient_angle: 90,
+            full_width_highlight: None,
+            rounded: None,
+            borders: None,
+            visible: Some(false),
+            title_alignment: None,
+            bold: None,
+            italic: None,
+            underline: None,
+            dim: None,
+        },
+        width: None,
+        height: None,
+        anchor: Anchor::Center,
+        backdrop: None,
     },
     outer_box: SectionConfig {
         title: Some(String::from(" qst ")),
@@ -43,6 +128,10 @@ AppConfig {
         borders: None,
         visible: Some(false),
         title_alignment: None,
+        bold: None,
+        italic: None,
+        underline: None,
+        dim: None,
     },
     qst_ascii: QstAsciiConfig {
         section: SectionConfig {
@@ -57,6 +146,10 @@ AppConfig {
             borders: None,
             visible: Some(true),
             title_alignment: None,
+            bold: None,
+            italic: None,
+            underline: None,
+            dim: None,
         },
         gradient_colors: vec![String::from("#6464ff"), String::from("#c864ff")],
         gradient_angle: 90,
@@ -69,18 +162,30 @@ AppConfig {
         },
         custom_path: None,
     },
-    input: SectionConfig {
-        title: Some(String::from(" Search ")),
-        fg: vec![],
-        bg: vec![],
-        border_color: vec![String::from("#6464ff")],
-        border_angle: 90,
-        gradient_angle: 90,
-        full_width_highlight: None,
-        rounded: None,
-        borders: None,
-        visible: None,
-        title_alignment: None,
+    input: InputConfig {
+        section: SectionConfig {
+            title: Some(String::from(" Search ")),
+            fg: vec![],
+            bg: vec![],
+            border_color: vec![String::from("#6464ff")],
+            border_angle: 90,
+            gradient_angle: 90,
+            full_width_highlight: None,
+            rounded: None,
+            borders: None,
+            visible: None,
+            title_alignment: None,
+            bold: None,
+            italic: None,
+            underline: None,
+            dim: None,
+        },
+        placeholder: Some(String::from("Type to search, / for files, ~ for home…")),
+        prompt: None,
+        files_prompt: None,
+        scripts_prompt: None,
+        open_with_prompt: None,
+        prompt_fg: None,
     },
     list: ResultsConfig {
         section: SectionConfig {
@@ -95,10 +200,58 @@ AppConfig {
             borders: None,
             visible: None,
             title_alignment: None,
+            bold: None,
+            italic: None,
+            underline: None,
+            dim: None,
         },
         apps_title: None,
         files_title: None,
     },
+    scrollbar: ScrollbarConfig {
+        section: SectionConfig {
+            title: None,
+            fg: vec![String::from("#c864ff")],
+            bg: vec![],
+            border_color: vec![],
+            border_angle: 90,
+            gradient_angle: 90,
+            full_width_highlight: None,
+            rounded: None,
+            borders: None,
+            visible: None,
+            title_alignment: None,
+            bold: None,
+            italic: None,
+            underline: None,
+            dim: None,
+        },
+        track_symbol: Some(String::from("│")),
+        thumb_symbol: Some(String::from("┃")),
+    },
+    preview: PreviewConfig {
+        section: SectionConfig {
+            title: Some(String::from(" Preview ")),
+            fg: vec![],
+            bg: vec![],
+            border_color: vec![String::from("#c864ff")],
+            border_angle: 90,
+            gradient_angle: 90,
+            full_width_highlight: None,
+            rounded: None,
+            borders: None,
+            visible: Some(false),
+            title_alignment: None,
+            bold: None,
+            italic: None,
+            underline: None,
+            dim: None,
+        },
+        width_percent: 40,
+        max_lines: 50,
+        max_entries: 50,
+        max_bytes: 64 * 1024,
+    },
     entry: EntryConfig {
         fg: vec![],
         bg: vec![],
@@ -116,6 +269,10 @@ AppConfig {
         borders: None,
         visible: None,
         title_alignment: None,
+        bold: None,
+        italic: None,
+        underline: None,
+        dim: None,
     },
     text: TextConfig {
         section: SectionConfig {
@@ -130,8 +287,72 @@ AppConfig {
             borders: None,
             visible: None,
             title_alignment: None,
+            bold: None,
+            italic: None,
+            underline: None,
+            dim: None,
         },
         alignment: Some(TextAlignment::Left),
     },
+    usage: SectionConfig {
+        title: None,
+        fg: vec![String::from("#7f849c")],
+        bg: vec![],
+        border_color: vec![],
+        border_angle: 90,
+        gradient_angle: 90,
+        full_width_highlight: None,
+        rounded: None,
+        borders: None,
+        visible: None,
+        title_alignment: None,
+        bold: None,
+        italic: None,
+        underline: None,
+        dim: None,
+    },
+    favorite: SectionConfig {
+        title: None,
+        fg: vec![],
+        bg: vec![],
+        border_color: vec![],
+        border_angle: 90,
+        gradient_angle: 90,
+        full_width_highlight: None,
+        rounded: None,
+        borders: None,
+        visible: None,
+        title_alignment: None,
+        bold: None,
+        italic: None,
+        underline: None,
+        dim: None,
+    },
+    clipboard: ClipboardConfig {
+        prefix: String::from("clip"),
+        list_command: String::from("cliphist list"),
+        select_command: String::from("printf '%s' {selection} | cliphist decode | wl-copy"),
+    },
+    emoji: EmojiConfig {
+        prefix: String::from(":"),
+        type_command: None,
+    },
+    kill: KillConfig {
+        prefix: String::from("kill"),
+    },
+    windows: WindowsConfig {
+        prefix: String::from("win"),
+    },
+    power_menu: PowerMenuConfig {
+        fg: Some(String::from("red")),
+        count_usage: false,
+        shutdown: String::from("systemctl poweroff"),
+        reboot: String::from("systemctl reboot"),
+        suspend: String::from("systemctl suspend"),
+        lock: String::from("loginctl lock-session"),
+        logout: String::from("loginctl terminate-session \"$XDG_SESSION_ID\""),
+    },
+    app_overrides: BTreeMap::new(),
+    bookmarks: BTreeMap::new(),
 }
 }
@@ -0,0 +1,1938 @@
+// Generated from Unicode emoji-test.txt (Emoji 15.1, fully-qualified, no skin tone variants)
+// followed by a few hand-picked symbols. Each row is (glyph, name, keywords).
+&[
+    ("😀", "grinning face", "face smiling"),
+    ("😃", "grinning face with big eyes", "face smiling"),
+    ("😄", "grinning face with smiling eyes", "face smiling"),
+    ("😁", "beaming face with smiling eyes", "face smiling"),
+    ("😆", "grinning squinting face", "face smiling"),
+    ("😅", "grinning face with sweat", "face smiling"),
+    ("🤣", "rolling on the floor laughing", "face smiling"),
+    ("😂", "face with tears of joy", "face smiling"),
+    ("🙂", "slightly smiling face", "face smiling"),
+    ("🙃", "upside-down face", "face smiling"),
+    ("🫠", "melting face", "face smiling"),
+    ("😉", "winking face", "face smiling"),
+    ("😊", "smiling face with smiling eyes", "face smiling"),
+    ("😇", "smiling face with halo", "face smiling"),
+    ("🥰", "smiling face with hearts", "face affection"),
+    ("😍", "smiling face with heart-eyes", "face affection"),
+    ("🤩", "star-struck", "face affection"),
+    ("😘", "face blowing a kiss", "face affection"),
+    ("😗", "kissing face", "face affection"),
+    ("☺\u{FE0F}", "smiling face", "face affection"),
+    ("😚", "kissing face with closed eyes", "face affection"),
+    ("😙", "kissing face with smiling eyes", "face affection"),
+    ("🥲", "smiling face with tear", "face affection"),
+    ("😋", "face savoring food", "face tongue"),
+    ("😛", "face with tongue", "face tongue"),
+    ("😜", "winking face with tongue", "face tongue"),
+    ("🤪", "zany face", "face tongue"),
+    ("😝", "squinting face with tongue", "face tongue"),
+    ("🤑", "money-mouth face", "face tongue"),
+    ("🤗", "smiling face with open hands", "face hand"),
+    ("🤭", "face with hand over mouth", "face hand"),
+    ("🫢", "face with open eyes and hand over mouth", "face hand"),
+    ("🫣", "face with peeking eye", "face hand"),
+    ("🤫", "shushing face", "face hand"),
+    ("🤔", "thinking face", "face hand"),
+    ("🫡", "saluting face", "face hand"),
+    ("🤐", "zipper-mouth face", "face neutral skeptical"),
+    ("🤨", "face with raised eyebrow", "face neutral skeptical"),
+    ("😐", "neutral face", "face neutral skeptical"),
+    ("😑", "expressionless face", "face neutral skeptical"),
+    ("😶", "face without mouth", "face neutral skeptical"),
+    ("🫥", "dotted line face", "face neutral skeptical"),
+    ("😶\u{200D}🌫\u{FE0F}", "face in clouds", "face neutral skeptical"),
+    ("😏", "smirking face", "face neutral skeptical"),
+    ("😒", "unamused face", "face neutral skeptical"),
+    ("🙄", "face with rolling eyes", "face neutral skeptical"),
+    ("😬", "grimacing face", "face neutral skeptical"),
+    ("😮\u{200D}💨", "face exhaling", "face neutral skeptical"),
+    ("🤥", "lying face", "face neutral skeptical"),
+    ("🫨", "shaking face", "face neutral skeptical"),
+    ("🙂\u{200D}↔\u{FE0F}", "head shaking horizontally", "face neutral skeptical"),
+    ("🙂\u{200D}↕\u{FE0F}", "head shaking vertically", "face neutral skeptical"),
+    ("😌", "relieved face", "face sleepy"),
+    ("😔", "pensive face", "face sleepy"),
+    ("😪", "sleepy face", "face sleepy"),
+    ("🤤", "drooling face", "face sleepy"),
+    ("😴", "sleeping face", "face sleepy"),
+    ("😷", "face with medical mask", "face unwell"),
+    ("🤒", "face with thermometer", "face unwell"),
+    ("🤕", "face with head-bandage", "face unwell"),
+    ("🤢", "nauseated face", "face unwell"),
+    ("🤮", "face vomiting", "face unwell"),
+    ("🤧", "sneezing face", "face unwell"),
+    ("🥵", "hot face", "face unwell"),
+    ("🥶", "cold face", "face unwell"),
+    ("🥴", "woozy face", "face unwell"),
+    ("😵", "face with crossed-out eyes", "face unwell"),
+    ("😵\u{200D}💫", "face with spiral eyes", "face unwell"),
+    ("🤯", "exploding head", "face unwell"),
+    ("🤠", "cowboy hat face", "face hat"),
+    ("🥳", "partying face", "face hat"),
+    ("🥸", "disguised face", "face hat"),
+    ("😎", "smiling face with sunglasses", "face glasses"),
+    ("🤓", "nerd face", "face glasses"),
+    ("🧐", "face with monocle", "face glasses"),
+    ("😕", "confused face", "face concerned"),
+    ("🫤", "face with diagonal mouth", "face concerned"),
+    ("😟", "worried face", "face concerned"),
+    ("🙁", "slightly frowning face", "face concerned"),
+    ("☹\u{FE0F}", "frowning face", "face concerned"),
+    ("😮", "face with open mouth", "face concerned"),
+    ("😯", "hushed face", "face concerned"),
+    ("😲", "astonished face", "face concerned"),
+    ("😳", "flushed face", "face concerned"),
+    ("🥺", "pleading face", "face concerned"),
+    ("🥹", "face holding back tears", "face concerned"),
+    ("😦", "frowning face with open mouth", "face concerned"),
+    ("😧", "anguished face", "face concerned"),
+    ("😨", "fearful face", "face concerned"),
+    ("😰", "anxious face with sweat", "face concerned"),
+    ("😥", "sad but relieved face", "face concerned"),
+    ("😢", "crying face", "face concerned"),
+    ("😭", "loudly crying face", "face concerned"),
+    ("😱", "face screaming in fear", "face concerned"),
+    ("😖", "confounded face", "face concerned"),
+    ("😣", "persevering face", "face concerned"),
+    ("😞", "disappointed face", "face concerned"),
+    ("😓", "downcast face with sweat", "face concerned"),
+    ("😩", "weary face", "face concerned"),
+    ("😫", "tired face", "face concerned"),
+    ("🥱", "yawning face", "face concerned"),
+    ("😤", "face with steam from nose", "face negative"),
+    ("😡", "enraged face", "face negative"),
+    ("😠", "angry face", "face negative"),
+    ("🤬", "face with symbols on mouth", "face negative"),
+    ("😈", "smiling face with horns", "face negative"),
+    ("👿", "angry face with horns", "face negative"),
+    ("💀", "skull", "face negative"),
+    ("☠\u{FE0F}", "skull and crossbones", "face negative"),
+    ("💩", "pile of poo", "face costume"),
+    ("🤡", "clown face", "face costume"),
+    ("👹", "ogre", "face costume"),
+    ("👺", "goblin", "face costume"),
+    ("👻", "ghost", "face costume"),
+    ("👽", "alien", "face costume"),
+    ("👾", "alien monster", "face costume"),
+    ("🤖", "robot", "face costume"),
+    ("😺", "grinning cat", "cat face"),
+    ("😸", "grinning cat with smiling eyes", "cat face"),
+    ("😹", "cat with tears of joy", "cat face"),
+    ("😻", "smiling cat with heart-eyes", "cat face"),
+    ("😼", "cat with wry smile", "cat face"),
+    ("😽", "kissing cat", "cat face"),
+    ("🙀", "weary cat", "cat face"),
+    ("😿", "crying cat", "cat face"),
+    ("😾", "pouting cat", "cat face"),
+    ("🙈", "see-no-evil monkey", "monkey face"),
+    ("🙉", "hear-no-evil monkey", "monkey face"),
+    ("🙊", "speak-no-evil monkey", "monkey face"),
+    ("💌", "love letter", "heart"),
+    ("💘", "heart with arrow", "heart"),
+    ("💝", "heart with ribbon", "heart"),
+    ("💖", "sparkling heart", "heart"),
+    ("💗", "growing heart", "heart"),
+    ("💓", "beating heart", "heart"),
+    ("💞", "revolving hearts", "heart"),
+    ("💕", "two hearts", "heart"),
+    ("💟", "heart decoration", "heart"),
+    ("❣\u{FE0F}", "heart exclamation", "heart"),
+    ("💔", "broken heart", "heart"),
+    ("❤\u{FE0F}\u{200D}🔥", "heart on fire", "heart"),
+    ("❤\u{FE0F}\u{200D}🩹", "mending heart", "heart"),
+    ("❤\u{FE0F}", "red heart", "heart"),
+    ("🩷", "pink heart", "heart"),
+    ("🧡", "orange heart", "heart"),
+    ("💛", "yellow heart", "heart"),
+    ("💚", "green heart", "heart"),
+    ("💙", "blue heart", "heart"),
+    ("🩵", "light blue heart", "heart"),
+    ("💜", "purple heart", "heart"),
+    ("🤎", "brown heart", "heart"),
+    ("🖤", "black heart", "heart"),
+    ("🩶", "grey heart", "heart"),
+    ("🤍", "white heart", "heart"),
+    ("💋", "kiss mark", "emotion"),
+    ("💯", "hundred points", "emotion"),
+    ("💢", "anger symbol", "emotion"),
+    ("💥", "collision", "emotion"),
+    ("💫", "dizzy", "emotion"),
+    ("💦", "sweat droplets", "emotion"),
+    ("💨", "dashing away", "emotion"),
+    ("🕳\u{FE0F}", "hole", "emotion"),
+    ("💬", "speech balloon", "emotion"),
+    ("👁\u{FE0F}\u{200D}🗨\u{FE0F}", "eye in speech bubble", "emotion"),
+    ("🗨\u{FE0F}", "left speech bubble", "emotion"),
+    ("🗯\u{FE0F}", "right anger bubble", "emotion"),
+    ("💭", "thought balloon", "emotion"),
+    ("💤", "ZZZ", "emotion"),
+    ("👋", "waving hand", "hand fingers open"),
+    ("🤚", "raised back of hand", "hand fingers open"),
+    ("🖐\u{FE0F}", "hand with fingers splayed", "hand fingers open"),
+    ("✋", "raised hand", "hand fingers open"),
+    ("🖖", "vulcan salute", "hand fingers open"),
+    ("🫱", "rightwards hand", "hand fingers open"),
+    ("🫲", "leftwards hand", "hand fingers open"),
+    ("🫳", "palm down hand", "hand fingers open"),
+    ("🫴", "palm up hand", "hand fingers open"),
+    ("🫷", "leftwards pushing hand", "hand fingers open"),
+    ("🫸", "rightwards pushing hand", "hand fingers open"),
+    ("👌", "OK hand", "hand fingers partial"),
+    ("🤌", "pinched fingers", "hand fingers partial"),
+    ("🤏", "pinching hand", "hand fingers partial"),
+    ("✌\u{FE0F}", "victory hand", "hand fingers partial"),
+    ("🤞", "crossed fingers", "hand fingers partial"),
+    ("🫰", "hand with index finger and thumb crossed", "hand fingers partial"),
+    ("🤟", "love-you gesture", "hand fingers partial"),
+    ("🤘", "sign of the horns", "hand fingers partial"),
+    ("🤙", "call me hand", "hand fingers partial"),
+    ("👈", "backhand index pointing left", "hand single finger"),
+    ("👉", "backhand index pointing right", "hand single finger"),
+    ("👆", "backhand index pointing up", "hand single finger"),
+    ("🖕", "middle finger", "hand single finger"),
+    ("👇", "backhand index pointing down", "hand single finger"),
+    ("☝\u{FE0F}", "index pointing up", "hand single finger"),
+    ("🫵", "index pointing at the viewer", "hand single finger"),
+    ("👍", "thumbs up", "hand fingers closed"),
+    ("👎", "thumbs down", "hand fingers closed"),
+    ("✊", "raised fist", "hand fingers closed"),
+    ("👊", "oncoming fist", "hand fingers closed"),
+    ("🤛", "left-facing fist", "hand fingers closed"),
+    ("🤜", "right-facing fist", "hand fingers closed"),
+    ("👏", "clapping hands", "hands"),
+    ("🙌", "raising hands", "hands"),
+    ("🫶", "heart hands", "hands"),
+    ("👐", "open hands", "hands"),
+    ("🤲", "palms up together", "hands"),
+    ("🤝", "handshake", "hands"),
+    ("🙏", "folded hands", "hands"),
+    ("✍\u{FE0F}", "writing hand", "hand prop"),
+    ("💅", "nail polish", "hand prop"),
+    ("🤳", "selfie", "hand prop"),
+    ("💪", "flexed biceps", "body parts"),
+    ("🦾", "mechanical arm", "body parts"),
+    ("🦿", "mechanical leg", "body parts"),
+    ("🦵", "leg", "body parts"),
+    ("🦶", "foot", "body parts"),
+    ("👂", "ear", "body parts"),
+    ("🦻", "ear with hearing aid", "body parts"),
+    ("👃", "nose", "body parts"),
+    ("🧠", "brain", "body parts"),
+    ("🫀", "anatomical heart", "body parts"),
+    ("🫁", "lungs", "body parts"),
+    ("🦷", "tooth", "body parts"),
+    ("🦴", "bone", "body parts"),
+    ("👀", "eyes", "body parts"),
+    ("👁\u{FE0F}", "eye", "body parts"),
+    ("👅", "tongue", "body parts"),
+    ("👄", "mouth", "body parts"),
+    ("🫦", "biting lip", "body parts"),
+    ("👶", "baby", "person"),
+    ("🧒", "child", "person"),
+    ("👦", "boy", "person"),
+    ("👧", "girl", "person"),
+    ("🧑", "person", "person"),
+    ("👱", "person: blond hair", "person"),
+    ("👨", "man", "person"),
+    ("🧔", "person: beard", "person"),
+    ("🧔\u{200D}♂\u{FE0F}", "man: beard", "person"),
+    ("🧔\u{200D}♀\u{FE0F}", "woman: beard", "person"),
+    ("👨\u{200D}🦰", "man: red hair", "person"),
+    ("👨\u{200D}🦱", "man: curly hair", "person"),
+    ("👨\u{200D}🦳", "man: white hair", "person"),
+    ("👨\u{200D}🦲", "man: bald", "person"),
+    ("👩", "woman", "person"),
+    ("👩\u{200D}🦰", "woman: red hair", "person"),
+    ("🧑\u{200D}🦰", "person: red hair", "person"),
+    ("👩\u{200D}🦱", "woman: curly hair", "person"),
+    ("🧑\u{200D}🦱", "person: curly hair", "person"),
+    ("👩\u{200D}🦳", "woman: white hair", "person"),
+    ("🧑\u{200D}🦳", "person: white hair", "person"),
+    ("👩\u{200D}🦲", "woman: bald", "person"),
+    ("🧑\u{200D}🦲", "person: bald", "person"),
+    ("👱\u{200D}♀\u{FE0F}", "woman: blond hair", "person"),
+    ("👱\u{200D}♂\u{FE0F}", "man: blond hair", "person"),
+    ("🧓", "older person", "person"),
+    ("👴", "old man", "person"),
+    ("👵", "old woman", "person"),
+    ("🙍", "person frowning", "person gesture"),
+    ("🙍\u{200D}♂\u{FE0F}", "man frowning", "person gesture"),
+    ("🙍\u{200D}♀\u{FE0F}", "woman frowning", "person gesture"),
+    ("🙎", "person pouting", "person gesture"),
+    ("🙎\u{200D}♂\u{FE0F}", "man pouting", "person gesture"),
+    ("🙎\u{200D}♀\u{FE0F}", "woman pouting", "person gesture"),
+    ("🙅", "person gesturing NO", "person gesture"),
+    ("🙅\u{200D}♂\u{FE0F}", "man gesturing NO", "person gesture"),
+    ("🙅\u{200D}♀\u{FE0F}", "woman gesturing NO", "person gesture"),
+    ("🙆", "person gesturing OK", "person gesture"),
+    ("🙆\u{200D}♂\u{FE0F}", "man gesturing OK", "person gesture"),
+    ("🙆\u{200D}♀\u{FE0F}", "woman gesturing OK", "person gesture"),
+    ("💁", "person tipping hand", "person gesture"),
+    ("💁\u{200D}♂\u{FE0F}", "man tipping hand", "person gesture"),
+    ("💁\u{200D}♀\u{FE0F}", "woman tipping hand", "person gesture"),
+    ("🙋", "person raising hand", "person gesture"),
+    ("🙋\u{200D}♂\u{FE0F}", "man raising hand", "person gesture"),
+    ("🙋\u{200D}♀\u{FE0F}", "woman raising hand", "person gesture"),
+    ("🧏", "deaf person", "person gesture"),
+    ("🧏\u{200D}♂\u{FE0F}", "deaf man", "person gesture"),
+    ("🧏\u{200D}♀\u{FE0F}", "deaf woman", "person gesture"),
+    ("🙇", "person bowing", "person gesture"),
+    ("🙇\u{200D}♂\u{FE0F}", "man bowing", "person gesture"),
+    ("🙇\u{200D}♀\u{FE0F}", "woman bowing", "person gesture"),
+    ("🤦", "person facepalming", "person gesture"),
+    ("🤦\u{200D}♂\u{FE0F}", "man facepalming", "person gesture"),
+    ("🤦\u{200D}♀\u{FE0F}", "woman facepalming", "person gesture"),
+    ("🤷", "person shrugging", "person gesture"),
+    ("🤷\u{200D}♂\u{FE0F}", "man shrugging", "person gesture"),
+    ("🤷\u{200D}♀\u{FE0F}", "woman shrugging", "person gesture"),
+    ("🧑\u{200D}⚕\u{FE0F}", "health worker", "person role"),
+    ("👨\u{200D}⚕\u{FE0F}", "man health worker", "person role"),
+    ("👩\u{200D}⚕\u{FE0F}", "woman health worker", "person role"),
+    ("🧑\u{200D}🎓", "student", "person role"),
+    ("👨\u{200D}🎓", "man student", "person role"),
+    ("👩\u{200D}🎓", "woman student", "person role"),
+    ("🧑\u{200D}🏫", "teacher", "person role"),
+    ("👨\u{200D}🏫", "man teacher", "person role"),
+    ("👩\u{200D}🏫", "woman teacher", "person role"),
+    ("🧑\u{200D}⚖\u{FE0F}", "judge", "person role"),
+    ("👨\u{200D}⚖\u{FE0F}", "man judge", "person role"),
+    ("👩\u{200D}⚖\u{FE0F}", "woman judge", "person role"),
+    ("🧑\u{200D}🌾", "farmer", "person role"),
+    ("👨\u{200D}🌾", "man farmer", "person role"),
+    ("👩\u{200D}🌾", "woman farmer", "person role"),
+    ("🧑\u{200D}🍳", "cook", "person role"),
+    ("👨\u{200D}🍳", "man cook", "person role"),
+    ("👩\u{200D}🍳", "woman cook", "person role"),
+    ("🧑\u{200D}🔧", "mechanic", "person role"),
+    ("👨\u{200D}🔧", "man mechanic", "person role"),
+    ("👩\u{200D}🔧", "woman mechanic", "person role"),
+    ("🧑\u{200D}🏭", "factory worker", "person role"),
+    ("👨\u{200D}🏭", "man factory worker", "person role"),
+    ("👩\u{200D}🏭", "woman factory worker", "person role"),
+    ("🧑\u{200D}💼", "office worker", "person role"),
+    ("👨\u{200D}💼", "man office worker", "person role"),
+    ("👩\u{200D}💼", "woman office worker", "person role"),
+    ("🧑\u{200D}🔬", "scientist", "person role"),
+    ("👨\u{200D}🔬", "man scientist", "person role"),
+    ("👩\u{200D}🔬", "woman scientist", "person role"),
+    ("🧑\u{200D}💻", "technologist", "person role"),
+    ("👨\u{200D}💻", "man technologist", "person role"),
+    ("👩\u{200D}💻", "woman technologist", "person role"),
+    ("🧑\u{200D}🎤", "singer", "person role"),
+    ("👨\u{200D}🎤", "man singer", "person role"),
+    ("👩\u{200D}🎤", "woman singer", "person role"),
+    ("🧑\u{200D}🎨", "artist", "person role"),
+    ("👨\u{200D}🎨", "man artist", "person role"),
+    ("👩\u{200D}🎨", "woman artist", "person role"),
+    ("🧑\u{200D}✈\u{FE0F}", "pilot", "person role"),
+    ("👨\u{200D}✈\u{FE0F}", "man pilot", "person role"),
+    ("👩\u{200D}✈\u{FE0F}", "woman pilot", "person role"),
+    ("🧑\u{200D}🚀", "astronaut", "person role"),
+    ("👨\u{200D}🚀", "man astronaut", "person role"),
+    ("👩\u{200D}🚀", "woman astronaut", "person role"),
+    ("🧑\u{200D}🚒", "firefighter", "person role"),
+    ("👨\u{200D}🚒", "man firefighter", "person role"),
+    ("👩\u{200D}🚒", "woman firefighter", "person role"),
+    ("👮", "police officer", "person role"),
+    ("👮\u{200D}♂\u{FE0F}", "man police officer", "person role"),
+    ("👮\u{200D}♀\u{FE0F}", "woman police officer", "person role"),
+    ("🕵\u{FE0F}", "detective", "person role"),
+    ("🕵\u{FE0F}\u{200D}♂\u{FE0F}", "man detective", "person role"),
+    ("🕵\u{FE0F}\u{200D}♀\u{FE0F}", "woman detective", "person role"),
+    ("💂", "guard", "person role"),
+    ("💂\u{200D}♂\u{FE0F}", "man guard", "person role"),
+    ("💂\u{200D}♀\u{FE0F}", "woman guard", "person role"),
+    ("🥷", "ninja", "person role"),
+    ("👷", "construction worker", "person role"),
+    ("👷\u{200D}♂\u{FE0F}", "man construction worker", "person role"),
+    ("👷\u{200D}♀\u{FE0F}", "woman construction worker", "person role"),
+    ("🫅", "person with crown", "person role"),
+    ("🤴", "prince", "person role"),
+    ("👸", "princess", "person role"),
+    ("👳", "person wearing turban", "person role"),
+    ("👳\u{200D}♂\u{FE0F}", "man wearing turban", "person role"),
+    ("👳\u{200D}♀\u{FE0F}", "woman wearing turban", "person role"),
+    ("👲", "person with skullcap", "person role"),
+    ("🧕", "woman with headscarf", "person role"),
+    ("🤵", "person in tuxedo", "person role"),
+    ("🤵\u{200D}♂\u{FE0F}", "man in tuxedo", "person role"),
+    ("🤵\u{200D}♀\u{FE0F}", "woman in tuxedo", "person role"),
+    ("👰", "person with veil", "person role"),
+    ("👰\u{200D}♂\u{FE0F}", "man with veil", "person role"),
+    ("👰\u{200D}♀\u{FE0F}", "woman with veil", "person role"),
+    ("🤰", "pregnant woman", "person role"),
+    ("🫃", "pregnant man", "person role"),
+    ("🫄", "pregnant person", "person role"),
+    ("🤱", "breast-feeding", "person role"),
+    ("👩\u{200D}🍼", "woman feeding baby", "person role"),
+    ("👨\u{200D}🍼", "man feeding baby", "person role"),
+    ("🧑\u{200D}🍼", "person feeding baby", "person role"),
+    ("👼", "baby angel", "person fantasy"),
+    ("🎅", "Santa Claus", "person fantasy"),
+    ("🤶", "Mrs. Claus", "person fantasy"),
+    ("🧑\u{200D}🎄", "mx claus", "person fantasy"),
+    ("🦸", "superhero", "person fantasy"),
+    ("🦸\u{200D}♂\u{FE0F}", "man superhero", "person fantasy"),
+    ("🦸\u{200D}♀\u{FE0F}", "woman superhero", "person fantasy"),
+    ("🦹", "supervillain", "person fantasy"),
+    ("🦹\u{200D}♂\u{FE0F}", "man supervillain", "person fantasy"),
+    ("🦹\u{200D}♀\u{FE0F}", "woman supervillain", "person fantasy"),
+    ("🧙", "mage", "person fantasy"),
+    ("🧙\u{200D}♂\u{FE0F}", "man mage", "person fantasy"),
+    ("🧙\u{200D}♀\u{FE0F}", "woman mage", "person fantasy"),
+    ("🧚", "fairy", "person fantasy"),
+    ("🧚\u{200D}♂\u{FE0F}", "man fairy", "person fantasy"),
+    ("🧚\u{200D}♀\u{FE0F}", "woman fairy", "person fantasy"),
+    ("🧛", "vampire", "person fantasy"),
+    ("🧛\u{200D}♂\u{FE0F}", "man vampire", "person fantasy"),
+    ("🧛\u{200D}♀\u{FE0F}", "woman vampire", "person fantasy"),
+    ("🧜", "merperson", "person fantasy"),
+    ("🧜\u{200D}♂\u{FE0F}", "merman", "person fantasy"),
+    ("🧜\u{200D}♀\u{FE0F}", "mermaid", "person fantasy"),
+    ("🧝", "elf", "person fantasy"),
+    ("🧝\u{200D}♂\u{FE0F}", "man elf", "person fantasy"),
+    ("🧝\u{200D}♀\u{FE0F}", "woman elf", "person fantasy"),
+    ("🧞", "genie", "person fantasy"),
+    ("🧞\u{200D}♂\u{FE0F}", "man genie", "person fantasy"),
+    ("🧞\u{200D}♀\u{FE0F}", "woman genie", "person fantasy"),
+    ("🧟", "zombie", "person fantasy"),
+    ("🧟\u{200D}♂\u{FE0F}", "man zombie", "person fantasy"),
+    ("🧟\u{200D}♀\u{FE0F}", "woman zombie", "person fantasy"),
+    ("🧌", "troll", "person fantasy"),
+    ("💆", "person getting massage", "person activity"),
+    ("💆\u{200D}♂\u{FE0F}", "man getting massage", "person activity"),
+    ("💆\u{200D}♀\u{FE0F}", "woman getting massage", "person activity"),
+    ("💇", "person getting haircut", "person activity"),
+    ("💇\u{200D}♂\u{FE0F}", "man getting haircut", "person activity"),
+    ("💇\u{200D}♀\u{FE0F}", "woman getting haircut", "person activity"),
+    ("🚶", "person walking", "person activity"),
+    ("🚶\u{200D}♂\u{FE0F}", "man walking", "person activity"),
+    ("🚶\u{200D}♀\u{FE0F}", "woman walking", "person activity"),
+    ("🚶\u{200D}➡\u{FE0F}", "person walking facing right", "person activity"),
+    ("🚶\u{200D}♀\u{FE0F}\u{200D}➡\u{FE0F}", "woman walking facing right", "person activity"),
+    ("🚶\u{200D}♂\u{FE0F}\u{200D}➡\u{FE0F}", "man walking facing right", "person activity"),
+    ("🧍", "person standing", "person activity"),
+    ("🧍\u{200D}♂\u{FE0F}", "man standing", "person activity"),
+    ("🧍\u{200D}♀\u{FE0F}", "woman standing", "person activity"),
+    ("🧎", "person kneeling", "person activity"),
+    ("🧎\u{200D}♂\u{FE0F}", "man kneeling", "person activity"),
+    ("🧎\u{200D}♀\u{FE0F}", "woman kneeling", "person activity"),
+    ("🧎\u{200D}➡\u{FE0F}", "person kneeling facing right", "person activity"),
+    ("🧎\u{200D}♀\u{FE0F}\u{200D}➡\u{FE0F}", "woman kneeling facing right", "person activity"),
+    ("🧎\u{200D}♂\u{FE0F}\u{200D}➡\u{FE0F}", "man kneeling facing right", "person activity"),
+    ("🧑\u{200D}🦯", "person with white cane", "person activity"),
+    ("🧑\u{200D}🦯\u{200D}➡\u{FE0F}", "person with white cane facing right", "person activity"),
+    ("👨\u{200D}🦯", "man with white cane", "person activity"),
+    ("👨\u{200D}🦯\u{200D}➡\u{FE0F}", "man with white cane facing right", "person activity"),
+    ("👩\u{200D}🦯", "woman with white cane", "person activity"),
+    ("👩\u{200D}🦯\u{200D}➡\u{FE0F}", "woman with white cane facing right", "person activity"),
+    ("🧑\u{200D}🦼", "person in motorized wheelchair", "person activity"),
+    ("🧑\u{200D}🦼\u{200D}➡\u{FE0F}", "person in motorized wheelchair facing right", "person activity"),
+    ("👨\u{200D}🦼", "man in motorized wheelchair", "person activity"),
+    ("👨\u{200D}🦼\u{200D}➡\u{FE0F}", "man in motorized wheelchair facing right", "person activity"),
+    ("👩\u{200D}🦼", "woman in motorized wheelchair", "person activity"),
+    ("👩\u{200D}🦼\u{200D}➡\u{FE0F}", "woman in motorized wheelchair facing right", "person activity"),
+    ("🧑\u{200D}🦽", "person in manual wheelchair", "person activity"),
+    ("🧑\u{200D}🦽\u{200D}➡\u{FE0F}", "person in manual wheelchair facing right", "person activity"),
+    ("👨\u{200D}🦽", "man in manual wheelchair", "person activity"),
+    ("👨\u{200D}🦽\u{200D}➡\u{FE0F}", "man in manual wheelchair facing right", "person activity"),
+    ("👩\u{200D}🦽", "woman in manual wheelchair", "person activity"),
+    ("👩\u{200D}🦽\u{200D}➡\u{FE0F}", "woman in manual wheelchair facing right", "person activity"),
+    ("🏃", "person running", "person activity"),
+    ("🏃\u{200D}♂\u{FE0F}", "man running", "person activity"),
+    ("🏃\u{200D}♀\u{FE0F}", "woman running", "person activity"),
+    ("🏃\u{200D}➡\u{FE0F}", "person running facing right", "person activity"),
+    ("🏃\u{200D}♀\u{FE0F}\u{200D}➡\u{FE0F}", "woman running facing right", "person activity"),
+    ("🏃\u{200D}♂\u{FE0F}\u{200D}➡\u{FE0F}", "man running facing right", "person activity"),
+    ("💃", "woman dancing", "person activity"),
+    ("🕺", "man dancing", "person activity"),
+    ("🕴\u{FE0F}", "person in suit levitating", "person activity"),
+    ("👯", "people with bunny ears", "person activity"),
+    ("👯\u{200D}♂\u{FE0F}", "men with bunny ears", "person activity"),
+    ("👯\u{200D}♀\u{FE0F}", "women with bunny ears", "person activity"),
+    ("🧖", "person in steamy room", "person activity"),
+    ("🧖\u{200D}♂\u{FE0F}", "man in steamy room", "person activity"),
+    ("🧖\u{200D}♀\u{FE0F}", "woman in steamy room", "person activity"),
+    ("🧗", "person climbing", "person activity"),
+    ("🧗\u{200D}♂\u{FE0F}", "man climbing", "person activity"),
+    ("🧗\u{200D}♀\u{FE0F}", "woman climbing", "person activity"),
+    ("🤺", "person fencing", "person sport"),
+    ("🏇", "horse racing", "person sport"),
+    ("⛷\u{FE0F}", "skier", "person sport"),
+    ("🏂", "snowboarder", "person sport"),
+    ("🏌\u{FE0F}", "person golfing", "person sport"),
+    ("🏌\u{FE0F}\u{200D}♂\u{FE0F}", "man golfing", "person sport"),
+    ("🏌\u{FE0F}\u{200D}♀\u{FE0F}", "woman golfing", "person sport"),
+    ("🏄", "person surfing", "person sport"),
+    ("🏄\u{200D}♂\u{FE0F}", "man surfing", "person sport"),
+    ("🏄\u{200D}♀\u{FE0F}", "woman surfing", "person sport"),
+    ("🚣", "person rowing boat", "person sport"),
+    ("🚣\u{200D}♂\u{FE0F}", "man rowing boat", "person sport"),
+    ("🚣\u{200D}♀\u{FE0F}", "woman rowing boat", "person sport"),
+    ("🏊", "person swimming", "person sport"),
+    ("🏊\u{200D}♂\u{FE0F}", "man swimming", "person sport"),
+    ("🏊\u{200D}♀\u{FE0F}", "woman swimming", "person sport"),
+    ("⛹\u{FE0F}", "person bouncing ball", "person sport"),
+    ("⛹\u{FE0F}\u{200D}♂\u{FE0F}", "man bouncing ball", "person sport"),
+    ("⛹\u{FE0F}\u{200D}♀\u{FE0F}", "woman bouncing ball", "person sport"),
+    ("🏋\u{FE0F}", "person lifting weights", "person sport"),
+    ("🏋\u{FE0F}\u{200D}♂\u{FE0F}", "man lifting weights", "person sport"),
+    ("🏋\u{FE0F}\u{200D}♀\u{FE0F}", "woman lifting weights", "person sport"),
+    ("🚴", "person biking", "person sport"),
+    ("🚴\u{200D}♂\u{FE0F}", "man biking", "person sport"),
+    ("🚴\u{200D}♀\u{FE0F}", "woman biking", "person sport"),
+    ("🚵", "person mountain biking", "person sport"),
+    ("🚵\u{200D}♂\u{FE0F}", "man mountain biking", "person sport"),
+    ("🚵\u{200D}♀\u{FE0F}", "woman mountain biking", "person sport"),
+    ("🤸", "person cartwheeling", "person sport"),
+    ("🤸\u{200D}♂\u{FE0F}", "man cartwheeling", "person sport"),
+    ("🤸\u{200D}♀\u{FE0F}", "woman cartwheeling", "person sport"),
+    ("🤼", "people wrestling", "person sport"),
+    ("🤼\u{200D}♂\u{FE0F}", "men wrestling", "person sport"),
+    ("🤼\u{200D}♀\u{FE0F}", "women wrestling", "person sport"),
+    ("🤽", "person playing water polo", "person sport"),
+    ("🤽\u{200D}♂\u{FE0F}", "man playing water polo", "person sport"),
+    ("🤽\u{200D}♀\u{FE0F}", "woman playing water polo", "person sport"),
+    ("🤾", "person playing handball", "person sport"),
+    ("🤾\u{200D}♂\u{FE0F}", "man playing handball", "person sport"),
+    ("🤾\u{200D}♀\u{FE0F}", "woman playing handball", "person sport"),
+    ("🤹", "person juggling", "person sport"),
+    ("🤹\u{200D}♂\u{FE0F}", "man juggling", "person sport"),
+    ("🤹\u{200D}♀\u{FE0F}", "woman juggling", "person sport"),
+    ("🧘", "person in lotus position", "person resting"),
+    ("🧘\u{200D}♂\u{FE0F}", "man in lotus position", "person resting"),
+    ("🧘\u{200D}♀\u{FE0F}", "woman in lotus position", "person resting"),
+    ("🛀", "person taking bath", "person resting"),
+    ("🛌", "person in bed", "person resting"),
+    ("🧑\u{200D}🤝\u{200D}🧑", "people holding hands", "family"),
+    ("👭", "women holding hands", "family"),
+    ("👫", "woman and man holding hands", "family"),
+    ("👬", "men holding hands", "family"),
+    ("💏", "kiss", "family"),
+    ("👩\u{200D}❤\u{FE0F}\u{200D}💋\u{200D}👨", "kiss: woman, man", "family"),
+    ("👨\u{200D}❤\u{FE0F}\u{200D}💋\u{200D}👨", "kiss: man, man", "family"),
+    ("👩\u{200D}❤\u{FE0F}\u{200D}💋\u{200D}👩", "kiss: woman, woman", "family"),
+    ("💑", "couple with heart", "family"),
+    ("👩\u{200D}❤\u{FE0F}\u{200D}👨", "couple with heart: woman, man", "family"),
+    ("👨\u{200D}❤\u{FE0F}\u{200D}👨", "couple with heart: man, man", "family"),
+    ("👩\u{200D}❤\u{FE0F}\u{200D}👩", "couple with heart: woman, woman", "family"),
+    ("👨\u{200D}👩\u{200D}👦", "family: man, woman, boy", "family"),
+    ("👨\u{200D}👩\u{200D}👧", "family: man, woman, girl", "family"),
+    ("👨\u{200D}👩\u{200D}👧\u{200D}👦", "family: man, woman, girl, boy", "family"),
+    ("👨\u{200D}👩\u{200D}👦\u{200D}👦", "family: man, woman, boy, boy", "family"),
+    ("👨\u{200D}👩\u{200D}👧\u{200D}👧", "family: man, woman, girl, girl", "family"),
+    ("👨\u{200D}👨\u{200D}👦", "family: man, man, boy", "family"),
+    ("👨\u{200D}👨\u{200D}👧", "family: man, man, girl", "family"),
+    ("👨\u{200D}👨\u{200D}👧\u{200D}👦", "family: man, man, girl, boy", "family"),
+    ("👨\u{200D}👨\u{200D}👦\u{200D}👦", "family: man, man, boy, boy", "family"),
+    ("👨\u{200D}👨\u{200D}👧\u{200D}👧", "family: man, man, girl, girl", "family"),
+    ("👩\u{200D}👩\u{200D}👦", "family: woman, woman, boy", "family"),
+    ("👩\u{200D}👩\u{200D}👧", "family: woman, woman, girl", "family"),
+    ("👩\u{200D}👩\u{200D}👧\u{200D}👦", "family: woman, woman, girl, boy", "family"),
+    ("👩\u{200D}👩\u{200D}👦\u{200D}👦", "family: woman, woman, boy, boy", "family"),
+    ("👩\u{200D}👩\u{200D}👧\u{200D}👧", "family: woman, woman, girl, girl", "family"),
+    ("👨\u{200D}👦", "family: man, boy", "family"),
+    ("👨\u{200D}👦\u{200D}👦", "family: man, boy, boy", "family"),
+    ("👨\u{200D}👧", "family: man, girl", "family"),
+    ("👨\u{200D}👧\u{200D}👦", "family: man, girl, boy", "family"),
+    ("👨\u{200D}👧\u{200D}👧", "family: man, girl, girl", "family"),
+    ("👩\u{200D}👦", "family: woman, boy", "family"),
+    ("👩\u{200D}👦\u{200D}👦", "family: woman, boy, boy", "family"),
+    ("👩\u{200D}👧", "family: woman, girl", "family"),
+    ("👩\u{200D}👧\u{200D}👦", "family: woman, girl, boy", "family"),
+    ("👩\u{200D}👧\u{200D}👧", "family: woman, girl, girl", "family"),
+    ("🗣\u{FE0F}", "speaking head", "person symbol"),
+    ("👤", "bust in silhouette", "person symbol"),
+    ("👥", "busts in silhouette", "person symbol"),
+    ("🫂", "people hugging", "person symbol"),
+    ("👪", "family", "person symbol"),
+    ("🧑\u{200D}🧑\u{200D}🧒", "family: adult, adult, child", "person symbol"),
+    ("🧑\u{200D}🧑\u{200D}🧒\u{200D}🧒", "family: adult, adult, child, child", "person symbol"),
+    ("🧑\u{200D}🧒", "family: adult, child", "person symbol"),
+    ("🧑\u{200D}🧒\u{200D}🧒", "family: adult, child, child", "person symbol"),
+    ("👣", "footprints", "person symbol"),
+    ("🐵", "monkey face", "animal mammal"),
+    ("🐒", "monkey", "animal mammal"),
+    ("🦍", "gorilla", "animal mammal"),
+    ("🦧", "orangutan", "animal mammal"),
+    ("🐶", "dog face", "animal mammal"),
+    ("🐕", "dog", "animal mammal"),
+    ("🦮", "guide dog", "animal mammal"),
+    ("🐕\u{200D}🦺", "service dog", "animal mammal"),
+    ("🐩", "poodle", "animal mammal"),
+    ("🐺", "wolf", "animal mammal"),
+    ("🦊", "fox", "animal mammal"),
+    ("🦝", "raccoon", "animal mammal"),
+    ("🐱", "cat face", "animal mammal"),
+    ("🐈", "cat", "animal mammal"),
+    ("🐈\u{200D}⬛", "black cat", "animal mammal"),
+    ("🦁", "lion", "animal mammal"),
+    ("🐯", "tiger face", "animal mammal"),
+    ("🐅", "tiger", "animal mammal"),
+    ("🐆", "leopard", "animal mammal"),
+    ("🐴", "horse face", "animal mammal"),
+    ("🫎", "moose", "animal mammal"),
+    ("🫏", "donkey", "animal mammal"),
+    ("🐎", "horse", "animal mammal"),
+    ("🦄", "unicorn", "animal mammal"),
+    ("🦓", "zebra", "animal mammal"),
+    ("🦌", "deer", "animal mammal"),
+    ("🦬", "bison", "animal mammal"),
+    ("🐮", "cow face", "animal mammal"),
+    ("🐂", "ox", "animal mammal"),
+    ("🐃", "water buffalo", "animal mammal"),
+    ("🐄", "cow", "animal mammal"),
+    ("🐷", "pig face", "animal mammal"),
+    ("🐖", "pig", "animal mammal"),
+    ("🐗", "boar", "animal mammal"),
+    ("🐽", "pig nose", "animal mammal"),
+    ("🐏", "ram", "animal mammal"),
+    ("🐑", "ewe", "animal mammal"),
+    ("🐐", "goat", "animal mammal"),
+    ("🐪", "camel", "animal mammal"),
+    ("🐫", "two-hump camel", "animal mammal"),
+    ("🦙", "llama", "animal mammal"),
+    ("🦒", "giraffe", "animal mammal"),
+    ("🐘", "elephant", "animal mammal"),
+    ("🦣", "mammoth", "animal mammal"),
+    ("🦏", "rhinoceros", "animal mammal"),
+    ("🦛", "hippopotamus", "animal mammal"),
+    ("🐭", "mouse face", "animal mammal"),
+    ("🐁", "mouse", "animal mammal"),
+    ("🐀", "rat", "animal mammal"),
+    ("🐹", "hamster", "animal mammal"),
+    ("🐰", "rabbit face", "animal mammal"),
+    ("🐇", "rabbit", "animal mammal"),
+    ("🐿\u{FE0F}", "chipmunk", "animal mammal"),
+    ("🦫", "beaver", "animal mammal"),
+    ("🦔", "hedgehog", "animal mammal"),
+    ("🦇", "bat", "animal mammal"),
+    ("🐻", "bear", "animal mammal"),
+    ("🐻\u{200D}❄\u{FE0F}", "polar bear", "animal mammal"),
+    ("🐨", "koala", "animal mammal"),
+    ("🐼", "panda", "animal mammal"),
+    ("🦥", "sloth", "animal mammal"),
+    ("🦦", "otter", "animal mammal"),
+    ("🦨", "skunk", "animal mammal"),
+    ("🦘", "kangaroo", "animal mammal"),
+    ("🦡", "badger", "animal mammal"),
+    ("🐾", "paw prints", "animal mammal"),
+    ("🦃", "turkey", "animal bird"),
+    ("🐔", "chicken", "animal bird"),
+    ("🐓", "rooster", "animal bird"),
+    ("🐣", "hatching chick", "animal bird"),
+    ("🐤", "baby chick", "animal bird"),
+    ("🐥", "front-facing baby chick", "animal bird"),
+    ("🐦", "bird", "animal bird"),
+    ("🐧", "penguin", "animal bird"),
+    ("🕊\u{FE0F}", "dove", "animal bird"),
+    ("🦅", "eagle", "animal bird"),
+    ("🦆", "duck", "animal bird"),
+    ("🦢", "swan", "animal bird"),
+    ("🦉", "owl", "animal bird"),
+    ("🦤", "dodo", "animal bird"),
+    ("🪶", "feather", "animal bird"),
+    ("🦩", "flamingo", "animal bird"),
+    ("🦚", "peacock", "animal bird"),
+    ("🦜", "parrot", "animal bird"),
+    ("🪽", "wing", "animal bird"),
+    ("🐦\u{200D}⬛", "black bird", "animal bird"),
+    ("🪿", "goose", "animal bird"),
+    ("🐦\u{200D}🔥", "phoenix", "animal bird"),
+    ("🐸", "frog", "animal amphibian"),
+    ("🐊", "crocodile", "animal reptile"),
+    ("🐢", "turtle", "animal reptile"),
+    ("🦎", "lizard", "animal reptile"),
+    ("🐍", "snake", "animal reptile"),
+    ("🐲", "dragon face", "animal reptile"),
+    ("🐉", "dragon", "animal reptile"),
+    ("🦕", "sauropod", "animal reptile"),
+    ("🦖", "T-Rex", "animal reptile"),
+    ("🐳", "spouting whale", "animal marine"),
+    ("🐋", "whale", "animal marine"),
+    ("🐬", "dolphin", "animal marine"),
+    ("🦭", "seal", "animal marine"),
+    ("🐟", "fish", "animal marine"),
+    ("🐠", "tropical fish", "animal marine"),
+    ("🐡", "blowfish", "animal marine"),
+    ("🦈", "shark", "animal marine"),
+    ("🐙", "octopus", "animal marine"),
+    ("🐚", "spiral shell", "animal marine"),
+    ("🪸", "coral", "animal marine"),
+    ("🪼", "jellyfish", "animal marine"),
+    ("🐌", "snail", "animal bug"),
+    ("🦋", "butterfly", "animal bug"),
+    ("🐛", "bug", "animal bug"),
+    ("🐜", "ant", "animal bug"),
+    ("🐝", "honeybee", "animal bug"),
+    ("🪲", "beetle", "animal bug"),
+    ("🐞", "lady beetle", "animal bug"),
+    ("🦗", "cricket", "animal bug"),
+    ("🪳", "cockroach", "animal bug"),
+    ("🕷\u{FE0F}", "spider", "animal bug"),
+    ("🕸\u{FE0F}", "spider web", "animal bug"),
+    ("🦂", "scorpion", "animal bug"),
+    ("🦟", "mosquito", "animal bug"),
+    ("🪰", "fly", "animal bug"),
+    ("🪱", "worm", "animal bug"),
+    ("🦠", "microbe", "animal bug"),
+    ("💐", "bouquet", "plant flower"),
+    ("🌸", "cherry blossom", "plant flower"),
+    ("💮", "white flower", "plant flower"),
+    ("🪷", "lotus", "plant flower"),
+    ("🏵\u{FE0F}", "rosette", "plant flower"),
+    ("🌹", "rose", "plant flower"),
+    ("🥀", "wilted flower", "plant flower"),
+    ("🌺", "hibiscus", "plant flower"),
+    ("🌻", "sunflower", "plant flower"),
+    ("🌼", "blossom", "plant flower"),
+    ("🌷", "tulip", "plant flower"),
+    ("🪻", "hyacinth", "plant flower"),
+    ("🌱", "seedling", "plant other"),
+    ("🪴", "potted plant", "plant other"),
+    ("🌲", "evergreen tree", "plant other"),
+    ("🌳", "deciduous tree", "plant other"),
+    ("🌴", "palm tree", "plant other"),
+    ("🌵", "cactus", "plant other"),
+    ("🌾", "sheaf of rice", "plant other"),
+    ("🌿", "herb", "plant other"),
+    ("☘\u{FE0F}", "shamrock", "plant other"),
+    ("🍀", "four leaf clover", "plant other"),
+    ("🍁", "maple leaf", "plant other"),
+    ("🍂", "fallen leaf", "plant other"),
+    ("🍃", "leaf fluttering in wind", "plant other"),
+    ("🪹", "empty nest", "plant other"),
+    ("🪺", "nest with eggs", "plant other"),
+    ("🍄", "mushroom", "plant other"),
+    ("🍇", "grapes", "food fruit"),
+    ("🍈", "melon", "food fruit"),
+    ("🍉", "watermelon", "food fruit"),
+    ("🍊", "tangerine", "food fruit"),
+    ("🍋", "lemon", "food fruit"),
+    ("🍋\u{200D}🟩", "lime", "food fruit"),
+    ("🍌", "banana", "food fruit"),
+    ("🍍", "pineapple", "food fruit"),
+    ("🥭", "mango", "food fruit"),
+    ("🍎", "red apple", "food fruit"),
+    ("🍏", "green apple", "food fruit"),
+    ("🍐", "pear", "food fruit"),
+    ("🍑", "peach", "food fruit"),
+    ("🍒", "cherries", "food fruit"),
+    ("🍓", "strawberry", "food fruit"),
+    ("🫐", "blueberries", "food fruit"),
+    ("🥝", "kiwi fruit", "food fruit"),
+    ("🍅", "tomato", "food fruit"),
+    ("🫒", "olive", "food fruit"),
+    ("🥥", "coconut", "food fruit"),
+    ("🥑", "avocado", "food vegetable"),
+    ("🍆", "eggplant", "food vegetable"),
+    ("🥔", "potato", "food vegetable"),
+    ("🥕", "carrot", "food vegetable"),
+    ("🌽", "ear of corn", "food vegetable"),
+    ("🌶\u{FE0F}", "hot pepper", "food vegetable"),
+    ("🫑", "bell pepper", "food vegetable"),
+    ("🥒", "cucumber", "food vegetable"),
+    ("🥬", "leafy green", "food vegetable"),
+    ("🥦", "broccoli", "food vegetable"),
+    ("🧄", "garlic", "food vegetable"),
+    ("🧅", "onion", "food vegetable"),
+    ("🥜", "peanuts", "food vegetable"),
+    ("🫘", "beans", "food vegetable"),
+    ("🌰", "chestnut", "food vegetable"),
+    ("🫚", "ginger root", "food vegetable"),
+    ("🫛", "pea pod", "food vegetable"),
+    ("🍄\u{200D}🟫", "brown mushroom", "food vegetable"),
+    ("🍞", "bread", "food prepared"),
+    ("🥐", "croissant", "food prepared"),
+    ("🥖", "baguette bread", "food prepared"),
+    ("🫓", "flatbread", "food prepared"),
+    ("🥨", "pretzel", "food prepared"),
+    ("🥯", "bagel", "food prepared"),
+    ("🥞", "pancakes", "food prepared"),
+    ("🧇", "waffle", "food prepared"),
+    ("🧀", "cheese wedge", "food prepared"),
+    ("🍖", "meat on bone", "food prepared"),
+    ("🍗", "poultry leg", "food prepared"),
+    ("🥩", "cut of meat", "food prepared"),
+    ("🥓", "bacon", "food prepared"),
+    ("🍔", "hamburger", "food prepared"),
+    ("🍟", "french fries", "food prepared"),
+    ("🍕", "pizza", "food prepared"),
+    ("🌭", "hot dog", "food prepared"),
+    ("🥪", "sandwich", "food prepared"),
+    ("🌮", "taco", "food prepared"),
+    ("🌯", "burrito", "food prepared"),
+    ("🫔", "tamale", "food prepared"),
+    ("🥙", "stuffed flatbread", "food prepared"),
+    ("🧆", "falafel", "food prepared"),
+    ("🥚", "egg", "food prepared"),
+    ("🍳", "cooking", "food prepared"),
+    ("🥘", "shallow pan of food", "food prepared"),
+    ("🍲", "pot of food", "food prepared"),
+    ("🫕", "fondue", "food prepared"),
+    ("🥣", "bowl with spoon", "food prepared"),
+    ("🥗", "green salad", "food prepared"),
+    ("🍿", "popcorn", "food prepared"),
+    ("🧈", "butter", "food prepared"),
+    ("🧂", "salt", "food prepared"),
+    ("🥫", "canned food", "food prepared"),
+    ("🍱", "bento box", "food asian"),
+    ("🍘", "rice cracker", "food asian"),
+    ("🍙", "rice ball", "food asian"),
+    ("🍚", "cooked rice", "food asian"),
+    ("🍛", "curry rice", "food asian"),
+    ("🍜", "steaming bowl", "food asian"),
+    ("🍝", "spaghetti", "food asian"),
+    ("🍠", "roasted sweet potato", "food asian"),
+    ("🍢", "oden", "food asian"),
+    ("🍣", "sushi", "food asian"),
+    ("🍤", "fried shrimp", "food asian"),
+    ("🍥", "fish cake with swirl", "food asian"),
+    ("🥮", "moon cake", "food asian"),
+    ("🍡", "dango", "food asian"),
+    ("🥟", "dumpling", "food asian"),
+    ("🥠", "fortune cookie", "food asian"),
+    ("🥡", "takeout box", "food asian"),
+    ("🦀", "crab", "food marine"),
+    ("🦞", "lobster", "food marine"),
+    ("🦐", "shrimp", "food marine"),
+    ("🦑", "squid", "food marine"),
+    ("🦪", "oyster", "food marine"),
+    ("🍦", "soft ice cream", "food sweet"),
+    ("🍧", "shaved ice", "food sweet"),
+    ("🍨", "ice cream", "food sweet"),
+    ("🍩", "doughnut", "food sweet"),
+    ("🍪", "cookie", "food sweet"),
+    ("🎂", "birthday cake", "food sweet"),
+    ("🍰", "shortcake", "food sweet"),
+    ("🧁", "cupcake", "food sweet"),
+    ("🥧", "pie", "food sweet"),
+    ("🍫", "chocolate bar", "food sweet"),
+    ("🍬", "candy", "food sweet"),
+    ("🍭", "lollipop", "food sweet"),
+    ("🍮", "custard", "food sweet"),
+    ("🍯", "honey pot", "food sweet"),
+    ("🍼", "baby bottle", "drink"),
+    ("🥛", "glass of milk", "drink"),
+    ("☕", "hot beverage", "drink"),
+    ("🫖", "teapot", "drink"),
+    ("🍵", "teacup without handle", "drink"),
+    ("🍶", "sake", "drink"),
+    ("🍾", "bottle with popping cork", "drink"),
+    ("🍷", "wine glass", "drink"),
+    ("🍸", "cocktail glass", "drink"),
+    ("🍹", "tropical drink", "drink"),
+    ("🍺", "beer mug", "drink"),
+    ("🍻", "clinking beer mugs", "drink"),
+    ("🥂", "clinking glasses", "drink"),
+    ("🥃", "tumbler glass", "drink"),
+    ("🫗", "pouring liquid", "drink"),
+    ("🥤", "cup with straw", "drink"),
+    ("🧋", "bubble tea", "drink"),
+    ("🧃", "beverage box", "drink"),
+    ("🧉", "mate", "drink"),
+    ("🧊", "ice", "drink"),
+    ("🥢", "chopsticks", "dishware"),
+    ("🍽\u{FE0F}", "fork and knife with plate", "dishware"),
+    ("🍴", "fork and knife", "dishware"),
+    ("🥄", "spoon", "dishware"),
+    ("🔪", "kitchen knife", "dishware"),
+    ("🫙", "jar", "dishware"),
+    ("🏺", "amphora", "dishware"),
+    ("🌍", "globe showing Europe-Africa", "place map"),
+    ("🌎", "globe showing Americas", "place map"),
+    ("🌏", "globe showing Asia-Australia", "place map"),
+    ("🌐", "globe with meridians", "place map"),
+    ("🗺\u{FE0F}", "world map", "place map"),
+    ("🗾", "map of Japan", "place map"),
+    ("🧭", "compass", "place map"),
+    ("🏔\u{FE0F}", "snow-capped mountain", "place geographic"),
+    ("⛰\u{FE0F}", "mountain", "place geographic"),
+    ("🌋", "volcano", "place geographic"),
+    ("🗻", "mount fuji", "place geographic"),
+    ("🏕\u{FE0F}", "camping", "place geographic"),
+    ("🏖\u{FE0F}", "beach with umbrella", "place geographic"),
+    ("🏜\u{FE0F}", "desert", "place geographic"),
+    ("🏝\u{FE0F}", "desert island", "place geographic"),
+    ("🏞\u{FE0F}", "national park", "place geographic"),
+    ("🏟\u{FE0F}", "stadium", "place building"),
+    ("🏛\u{FE0F}", "classical building", "place building"),
+    ("🏗\u{FE0F}", "building construction", "place building"),
+    ("🧱", "brick", "place building"),
+    ("🪨", "rock", "place building"),
+    ("🪵", "wood", "place building"),
+    ("🛖", "hut", "place building"),
+    ("🏘\u{FE0F}", "houses", "place building"),
+    ("🏚\u{FE0F}", "derelict house", "place building"),
+    ("🏠", "house", "place building"),
+    ("🏡", "house with garden", "place building"),
+    ("🏢", "office building", "place building"),
+    ("🏣", "Japanese post office", "place building"),
+    ("🏤", "post office", "place building"),
+    ("🏥", "hospital", "place building"),
+    ("🏦", "bank", "place building"),
+    ("🏨", "hotel", "place building"),
+    ("🏩", "love hotel", "place building"),
+    ("🏪", "convenience store", "place building"),
+    ("🏫", "school", "place building"),
+    ("🏬", "department store", "place building"),
+    ("🏭", "factory", "place building"),
+    ("🏯", "Japanese castle", "place building"),
+    ("🏰", "castle", "place building"),
+    ("💒", "wedding", "place building"),
+    ("🗼", "Tokyo tower", "place building"),
+    ("🗽", "Statue of Liberty", "place building"),
+    ("⛪", "church", "place religious"),
+    ("🕌", "mosque", "place religious"),
+    ("🛕", "hindu temple", "place religious"),
+    ("🕍", "synagogue", "place religious"),
+    ("⛩\u{FE0F}", "shinto shrine", "place religious"),
+    ("🕋", "kaaba", "place religious"),
+    ("⛲", "fountain", "place other"),
+    ("⛺", "tent", "place other"),
+    ("🌁", "foggy", "place other"),
+    ("🌃", "night with stars", "place other"),
+    ("🏙\u{FE0F}", "cityscape", "place other"),
+    ("🌄", "sunrise over mountains", "place other"),
+    ("🌅", "sunrise", "place other"),
+    ("🌆", "cityscape at dusk", "place other"),
+    ("🌇", "sunset", "place other"),
+    ("🌉", "bridge at night", "place other"),
+    ("♨\u{FE0F}", "hot springs", "place other"),
+    ("🎠", "carousel horse", "place other"),
+    ("🛝", "playground slide", "place other"),
+    ("🎡", "ferris wheel", "place other"),
+    ("🎢", "roller coaster", "place other"),
+    ("💈", "barber pole", "place other"),
+    ("🎪", "circus tent", "place other"),
+    ("🚂", "locomotive", "transport ground"),
+    ("🚃", "railway car", "transport ground"),
+    ("🚄", "high-speed train", "transport ground"),
+    ("🚅", "bullet train", "transport ground"),
+    ("🚆", "train", "transport ground"),
+    ("🚇", "metro", "transport ground"),
+    ("🚈", "light rail", "transport ground"),
+    ("🚉", "station", "transport ground"),
+    ("🚊", "tram", "transport ground"),
+    ("🚝", "monorail", "transport ground"),
+    ("🚞", "mountain railway", "transport ground"),
+    ("🚋", "tram car", "transport ground"),
+    ("🚌", "bus", "transport ground"),
+    ("🚍", "oncoming bus", "transport ground"),
+    ("🚎", "trolleybus", "transport ground"),
+    ("🚐", "minibus", "transport ground"),
+    ("🚑", "ambulance", "transport ground"),
+    ("🚒", "fire engine", "transport ground"),
+    ("🚓", "police car", "transport ground"),
+    ("🚔", "oncoming police car", "transport ground"),
+    ("🚕", "taxi", "transport ground"),
+    ("🚖", "oncoming taxi", "transport ground"),
+    ("🚗", "automobile", "transport ground"),
+    ("🚘", "oncoming automobile", "transport ground"),
+    ("🚙", "sport utility vehicle", "transport ground"),
+    ("🛻", "pickup truck", "transport ground"),
+    ("🚚", "delivery truck", "transport ground"),
+    ("🚛", "articulated lorry", "transport ground"),
+    ("🚜", "tractor", "transport ground"),
+    ("🏎\u{FE0F}", "racing car", "transport ground"),
+    ("🏍\u{FE0F}", "motorcycle", "transport ground"),
+    ("🛵", "motor scooter", "transport ground"),
+    ("🦽", "manual wheelchair", "transport ground"),
+    ("🦼", "motorized wheelchair", "transport ground"),
+    ("🛺", "auto rickshaw", "transport ground"),
+    ("🚲", "bicycle", "transport ground"),
+    ("🛴", "kick scooter", "transport ground"),
+    ("🛹", "skateboard", "transport ground"),
+    ("🛼", "roller skate", "transport ground"),
+    ("🚏", "bus stop", "transport ground"),
+    ("🛣\u{FE0F}", "motorway", "transport ground"),
+    ("🛤\u{FE0F}", "railway track", "transport ground"),
+    ("🛢\u{FE0F}", "oil drum", "transport ground"),
+    ("⛽", "fuel pump", "transport ground"),
+    ("🛞", "wheel", "transport ground"),
+    ("🚨", "police car light", "transport ground"),
+    ("🚥", "horizontal traffic light", "transport ground"),
+    ("🚦", "vertical traffic light", "transport ground"),
+    ("🛑", "stop sign", "transport ground"),
+    ("🚧", "construction", "transport ground"),
+    ("⚓", "anchor", "transport water"),
+    ("🛟", "ring buoy", "transport water"),
+    ("⛵", "sailboat", "transport water"),
+    ("🛶", "canoe", "transport water"),
+    ("🚤", "speedboat", "transport water"),
+    ("🛳\u{FE0F}", "passenger ship", "transport water"),
+    ("⛴\u{FE0F}", "ferry", "transport water"),
+    ("🛥\u{FE0F}", "motor boat", "transport water"),
+    ("🚢", "ship", "transport water"),
+    ("✈\u{FE0F}", "airplane", "transport air"),
+    ("🛩\u{FE0F}", "small airplane", "transport air"),
+    ("🛫", "airplane departure", "transport air"),
+    ("🛬", "airplane arrival", "transport air"),
+    ("🪂", "parachute", "transport air"),
+    ("💺", "seat", "transport air"),
+    ("🚁", "helicopter", "transport air"),
+    ("🚟", "suspension railway", "transport air"),
+    ("🚠", "mountain cableway", "transport air"),
+    ("🚡", "aerial tramway", "transport air"),
+    ("🛰\u{FE0F}", "satellite", "transport air"),
+    ("🚀", "rocket", "transport air"),
+    ("🛸", "flying saucer", "transport air"),
+    ("🛎\u{FE0F}", "bellhop bell", "hotel"),
+    ("🧳", "luggage", "hotel"),
+    ("⌛", "hourglass done", "time"),
+    ("⏳", "hourglass not done", "time"),
+    ("⌚", "watch", "time"),
+    ("⏰", "alarm clock", "time"),
+    ("⏱\u{FE0F}", "stopwatch", "time"),
+    ("⏲\u{FE0F}", "timer clock", "time"),
+    ("🕰\u{FE0F}", "mantelpiece clock", "time"),
+    ("🕛", "twelve o’clock", "time"),
+    ("🕧", "twelve-thirty", "time"),
+    ("🕐", "one o’clock", "time"),
+    ("🕜", "one-thirty", "time"),
+    ("🕑", "two o’clock", "time"),
+    ("🕝", "two-thirty", "time"),
+    ("🕒", "three o’clock", "time"),
+    ("🕞", "three-thirty", "time"),
+    ("🕓", "four o’clock", "time"),
+    ("🕟", "four-thirty", "time"),
+    ("🕔", "five o’clock", "time"),
+    ("🕠", "five-thirty", "time"),
+    ("🕕", "six o’clock", "time"),
+    ("🕡", "six-thirty", "time"),
+    ("🕖", "seven o’clock", "time"),
+    ("🕢", "seven-thirty", "time"),
+    ("🕗", "eight o’clock", "time"),
+    ("🕣", "eight-thirty", "time"),
+    ("🕘", "nine o’clock", "time"),
+    ("🕤", "nine-thirty", "time"),
+    ("🕙", "ten o’clock", "time"),
+    ("🕥", "ten-thirty", "time"),
+    ("🕚", "eleven o’clock", "time"),
+    ("🕦", "eleven-thirty", "time"),
+    ("🌑", "new moon", "sky & weather"),
+    ("🌒", "waxing crescent moon", "sky & weather"),
+    ("🌓", "first quarter moon", "sky & weather"),
+    ("🌔", "waxing gibbous moon", "sky & weather"),
+    ("🌕", "full moon", "sky & weather"),
+    ("🌖", "waning gibbous moon", "sky & weather"),
+    ("🌗", "last quarter moon", "sky & weather"),
+    ("🌘", "waning crescent moon", "sky & weather"),
+    ("🌙", "crescent moon", "sky & weather"),
+    ("🌚", "new moon face", "sky & weather"),
+    ("🌛", "first quarter moon face", "sky & weather"),
+    ("🌜", "last quarter moon face", "sky & weather"),
+    ("🌡\u{FE0F}", "thermometer", "sky & weather"),
+    ("☀\u{FE0F}", "sun", "sky & weather"),
+    ("🌝", "full moon face", "sky & weather"),
+    ("🌞", "sun with face", "sky & weather"),
+    ("🪐", "ringed planet", "sky & weather"),
+    ("⭐", "star", "sky & weather"),
+    ("🌟", "glowing star", "sky & weather"),
+    ("🌠", "shooting star", "sky & weather"),
+    ("🌌", "milky way", "sky & weather"),
+    ("☁\u{FE0F}", "cloud", "sky & weather"),
+    ("⛅", "sun behind cloud", "sky & weather"),
+    ("⛈\u{FE0F}", "cloud with lightning and rain", "sky & weather"),
+    ("🌤\u{FE0F}", "sun behind small cloud", "sky & weather"),
+    ("🌥\u{FE0F}", "sun behind large cloud", "sky & weather"),
+    ("🌦\u{FE0F}", "sun behind rain cloud", "sky & weather"),
+    ("🌧\u{FE0F}", "cloud with rain", "sky & weather"),
+    ("🌨\u{FE0F}", "cloud with snow", "sky & weather"),
+    ("🌩\u{FE0F}", "cloud with lightning", "sky & weather"),
+    ("🌪\u{FE0F}", "tornado", "sky & weather"),
+    ("🌫\u{FE0F}", "fog", "sky & weather"),
+    ("🌬\u{FE0F}", "wind face", "sky & weather"),
+    ("🌀", "cyclone", "sky & weather"),
+    ("🌈", "rainbow", "sky & weather"),
+    ("🌂", "closed umbrella", "sky & weather"),
+    ("☂\u{FE0F}", "umbrella", "sky & weather"),
+    ("☔", "umbrella with rain drops", "sky & weather"),
+    ("⛱\u{FE0F}", "umbrella on ground", "sky & weather"),
+    ("⚡", "high voltage", "sky & weather"),
+    ("❄\u{FE0F}", "snowflake", "sky & weather"),
+    ("☃\u{FE0F}", "snowman", "sky & weather"),
+    ("⛄", "snowman without snow", "sky & weather"),
+    ("☄\u{FE0F}", "comet", "sky & weather"),
+    ("🔥", "fire", "sky & weather"),
+    ("💧", "droplet", "sky & weather"),
+    ("🌊", "water wave", "sky & weather"),
+    ("🎃", "jack-o-lantern", "event"),
+    ("🎄", "Christmas tree", "event"),
+    ("🎆", "fireworks", "event"),
+    ("🎇", "sparkler", "event"),
+    ("🧨", "firecracker", "event"),
+    ("✨", "sparkles", "event"),
+    ("🎈", "balloon", "event"),
+    ("🎉", "party popper", "event"),
+    ("🎊", "confetti ball", "event"),
+    ("🎋", "tanabata tree", "event"),
+    ("🎍", "pine decoration", "event"),
+    ("🎎", "Japanese dolls", "event"),
+    ("🎏", "carp streamer", "event"),
+    ("🎐", "wind chime", "event"),
+    ("🎑", "moon viewing ceremony", "event"),
+    ("🧧", "red envelope", "event"),
+    ("🎀", "ribbon", "event"),
+    ("🎁", "wrapped gift", "event"),
+    ("🎗\u{FE0F}", "reminder ribbon", "event"),
+    ("🎟\u{FE0F}", "admission tickets", "event"),
+    ("🎫", "ticket", "event"),
+    ("🎖\u{FE0F}", "military medal", "award medal"),
+    ("🏆", "trophy", "award medal"),
+    ("🏅", "sports medal", "award medal"),
+    ("🥇", "1st place medal", "award medal"),
+    ("🥈", "2nd place medal", "award medal"),
+    ("🥉", "3rd place medal", "award medal"),
+    ("⚽", "soccer ball", "sport"),
+    ("⚾", "baseball", "sport"),
+    ("🥎", "softball", "sport"),
+    ("🏀", "basketball", "sport"),
+    ("🏐", "volleyball", "sport"),
+    ("🏈", "american football", "sport"),
+    ("🏉", "rugby football", "sport"),
+    ("🎾", "tennis", "sport"),
+    ("🥏", "flying disc", "sport"),
+    ("🎳", "bowling", "sport"),
+    ("🏏", "cricket game", "sport"),
+    ("🏑", "field hockey", "sport"),
+    ("🏒", "ice hockey", "sport"),
+    ("🥍", "lacrosse", "sport"),
+    ("🏓", "ping pong", "sport"),
+    ("🏸", "badminton", "sport"),
+    ("🥊", "boxing glove", "sport"),
+    ("🥋", "martial arts uniform", "sport"),
+    ("🥅", "goal net", "sport"),
+    ("⛳", "flag in hole", "sport"),
+    ("⛸\u{FE0F}", "ice skate", "sport"),
+    ("🎣", "fishing pole", "sport"),
+    ("🤿", "diving mask", "sport"),
+    ("🎽", "running shirt", "sport"),
+    ("🎿", "skis", "sport"),
+    ("🛷", "sled", "sport"),
+    ("🥌", "curling stone", "sport"),
+    ("🎯", "bullseye", "game"),
+    ("🪀", "yo-yo", "game"),
+    ("🪁", "kite", "game"),
+    ("🔫", "water pistol", "game"),
+    ("🎱", "pool 8 ball", "game"),
+    ("🔮", "crystal ball", "game"),
+    ("🪄", "magic wand", "game"),
+    ("🎮", "video game", "game"),
+    ("🕹\u{FE0F}", "joystick", "game"),
+    ("🎰", "slot machine", "game"),
+    ("🎲", "game die", "game"),
+    ("🧩", "puzzle piece", "game"),
+    ("🧸", "teddy bear", "game"),
+    ("🪅", "piñata", "game"),
+    ("🪩", "mirror ball", "game"),
+    ("🪆", "nesting dolls", "game"),
+    ("♠\u{FE0F}", "spade suit", "game"),
+    ("♥\u{FE0F}", "heart suit", "game"),
+    ("♦\u{FE0F}", "diamond suit", "game"),
+    ("♣\u{FE0F}", "club suit", "game"),
+    ("♟\u{FE0F}", "chess pawn", "game"),
+    ("🃏", "joker", "game"),
+    ("🀄", "mahjong red dragon", "game"),
+    ("🎴", "flower playing cards", "game"),
+    ("🎭", "performing arts", "arts & crafts"),
+    ("🖼\u{FE0F}", "framed picture", "arts & crafts"),
+    ("🎨", "artist palette", "arts & crafts"),
+    ("🧵", "thread", "arts & crafts"),
+    ("🪡", "sewing needle", "arts & crafts"),
+    ("🧶", "yarn", "arts & crafts"),
+    ("🪢", "knot", "arts & crafts"),
+    ("👓", "glasses", "clothing"),
+    ("🕶\u{FE0F}", "sunglasses", "clothing"),
+    ("🥽", "goggles", "clothing"),
+    ("🥼", "lab coat", "clothing"),
+    ("🦺", "safety vest", "clothing"),
+    ("👔", "necktie", "clothing"),
+    ("👕", "t-shirt", "clothing"),
+    ("👖", "jeans", "clothing"),
+    ("🧣", "scarf", "clothing"),
+    ("🧤", "gloves", "clothing"),
+    ("🧥", "coat", "clothing"),
+    ("🧦", "socks", "clothing"),
+    ("👗", "dress", "clothing"),
+    ("👘", "kimono", "clothing"),
+    ("🥻", "sari", "clothing"),
+    ("🩱", "one-piece swimsuit", "clothing"),
+    ("🩲", "briefs", "clothing"),
+    ("🩳", "shorts", "clothing"),
+    ("👙", "bikini", "clothing"),
+    ("👚", "woman’s clothes", "clothing"),
+    ("🪭", "folding hand fan", "clothing"),
+    ("👛", "purse", "clothing"),
+    ("👜", "handbag", "clothing"),
+    ("👝", "clutch bag", "clothing"),
+    ("🛍\u{FE0F}", "shopping bags", "clothing"),
+    ("🎒", "backpack", "clothing"),
+    ("🩴", "thong sandal", "clothing"),
+    ("👞", "man’s shoe", "clothing"),
+    ("👟", "running shoe", "clothing"),
+    ("🥾", "hiking boot", "clothing"),
+    ("🥿", "flat shoe", "clothing"),
+    ("👠", "high-heeled shoe", "clothing"),
+    ("👡", "woman’s sandal", "clothing"),
+    ("🩰", "ballet shoes", "clothing"),
+    ("👢", "woman’s boot", "clothing"),
+    ("🪮", "hair pick", "clothing"),
+    ("👑", "crown", "clothing"),
+    ("👒", "woman’s hat", "clothing"),
+    ("🎩", "top hat", "clothing"),
+    ("🎓", "graduation cap", "clothing"),
+    ("🧢", "billed cap", "clothing"),
+    ("🪖", "military helmet", "clothing"),
+    ("⛑\u{FE0F}", "rescue worker’s helmet", "clothing"),
+    ("📿", "prayer beads", "clothing"),
+    ("💄", "lipstick", "clothing"),
+    ("💍", "ring", "clothing"),
+    ("💎", "gem stone", "clothing"),
+    ("🔇", "muted speaker", "sound"),
+    ("🔈", "speaker low volume", "sound"),
+    ("🔉", "speaker medium volume", "sound"),
+    ("🔊", "speaker high volume", "sound"),
+    ("📢", "loudspeaker", "sound"),
+    ("📣", "megaphone", "sound"),
+    ("📯", "postal horn", "sound"),
+    ("🔔", "bell", "sound"),
+    ("🔕", "bell with slash", "sound"),
+    ("🎼", "musical score", "music"),
+    ("🎵", "musical note", "music"),
+    ("🎶", "musical notes", "music"),
+    ("🎙\u{FE0F}", "studio microphone", "music"),
+    ("🎚\u{FE0F}", "level slider", "music"),
+    ("🎛\u{FE0F}", "control knobs", "music"),
+    ("🎤", "microphone", "music"),
+    ("🎧", "headphone", "music"),
+    ("📻", "radio", "music"),
+    ("🎷", "saxophone", "musical instrument"),
+    ("🪗", "accordion", "musical instrument"),
+    ("🎸", "guitar", "musical instrument"),
+    ("🎹", "musical keyboard", "musical instrument"),
+    ("🎺", "trumpet", "musical instrument"),
+    ("🎻", "violin", "musical instrument"),
+    ("🪕", "banjo", "musical instrument"),
+    ("🥁", "drum", "musical instrument"),
+    ("🪘", "long drum", "musical instrument"),
+    ("🪇", "maracas", "musical instrument"),
+    ("🪈", "flute", "musical instrument"),
+    ("📱", "mobile phone", "phone"),
+    ("📲", "mobile phone with arrow", "phone"),
+    ("☎\u{FE0F}", "telephone", "phone"),
+    ("📞", "telephone receiver", "phone"),
+    ("📟", "pager", "phone"),
+    ("📠", "fax machine", "phone"),
+    ("🔋", "battery", "computer"),
+    ("🪫", "low battery", "computer"),
+    ("🔌", "electric plug", "computer"),
+    ("💻", "laptop", "computer"),
+    ("🖥\u{FE0F}", "desktop computer", "computer"),
+    ("🖨\u{FE0F}", "printer", "computer"),
+    ("⌨\u{FE0F}", "keyboard", "computer"),
+    ("🖱\u{FE0F}", "computer mouse", "computer"),
+    ("🖲\u{FE0F}", "trackball", "computer"),
+    ("💽", "computer disk", "computer"),
+    ("💾", "floppy disk", "computer"),
+    ("💿", "optical disk", "computer"),
+    ("📀", "dvd", "computer"),
+    ("🧮", "abacus", "computer"),
+    ("🎥", "movie camera", "light & video"),
+    ("🎞\u{FE0F}", "film frames", "light & video"),
+    ("📽\u{FE0F}", "film projector", "light & video"),
+    ("🎬", "clapper board", "light & video"),
+    ("📺", "television", "light & video"),
+    ("📷", "camera", "light & video"),
+    ("📸", "camera with flash", "light & video"),
+    ("📹", "video camera", "light & video"),
+    ("📼", "videocassette", "light & video"),
+    ("🔍", "magnifying glass tilted left", "light & video"),
+    ("🔎", "magnifying glass tilted right", "light & video"),
+    ("🕯\u{FE0F}", "candle", "light & video"),
+    ("💡", "light bulb", "light & video"),
+    ("🔦", "flashlight", "light & video"),
+    ("🏮", "red paper lantern", "light & video"),
+    ("🪔", "diya lamp", "light & video"),
+    ("📔", "notebook with decorative cover", "book paper"),
+    ("📕", "closed book", "book paper"),
+    ("📖", "open book", "book paper"),
+    ("📗", "green book", "book paper"),
+    ("📘", "blue book", "book paper"),
+    ("📙", "orange book", "book paper"),
+    ("📚", "books", "book paper"),
+    ("📓", "notebook", "book paper"),
+    ("📒", "ledger", "book paper"),
+    ("📃", "page with curl", "book paper"),
+    ("📜", "scroll", "book paper"),
+    ("📄", "page facing up", "book paper"),
+    ("📰", "newspaper", "book paper"),
+    ("🗞\u{FE0F}", "rolled-up newspaper", "book paper"),
+    ("📑", "bookmark tabs", "book paper"),
+    ("🔖", "bookmark", "book paper"),
+    ("🏷\u{FE0F}", "label", "book paper"),
+    ("💰", "money bag", "money"),
+    ("🪙", "coin", "money"),
+    ("💴", "yen banknote", "money"),
+    ("💵", "dollar banknote", "money"),
+    ("💶", "euro banknote", "money"),
+    ("💷", "pound banknote", "money"),
+    ("💸", "money with wings", "money"),
+    ("💳", "credit card", "money"),
+    ("🧾", "receipt", "money"),
+    ("💹", "chart increasing with yen", "money"),
+    ("✉\u{FE0F}", "envelope", "mail"),
+    ("📧", "e-mail", "mail"),
+    ("📨", "incoming envelope", "mail"),
+    ("📩", "envelope with arrow", "mail"),
+    ("📤", "outbox tray", "mail"),
+    ("📥", "inbox tray", "mail"),
+    ("📦", "package", "mail"),
+    ("📫", "closed mailbox with raised flag", "mail"),
+    ("📪", "closed mailbox with lowered flag", "mail"),
+    ("📬", "open mailbox with raised flag", "mail"),
+    ("📭", "open mailbox with lowered flag", "mail"),
+    ("📮", "postbox", "mail"),
+    ("🗳\u{FE0F}", "ballot box with ballot", "mail"),
+    ("✏\u{FE0F}", "pencil", "writing"),
+    ("✒\u{FE0F}", "black nib", "writing"),
+    ("🖋\u{FE0F}", "fountain pen", "writing"),
+    ("🖊\u{FE0F}", "pen", "writing"),
+    ("🖌\u{FE0F}", "paintbrush", "writing"),
+    ("🖍\u{FE0F}", "crayon", "writing"),
+    ("📝", "memo", "writing"),
+    ("💼", "briefcase", "office"),
+    ("📁", "file folder", "office"),
+    ("📂", "open file folder", "office"),
+    ("🗂\u{FE0F}", "card index dividers", "office"),
+    ("📅", "calendar", "office"),
+    ("📆", "tear-off calendar", "office"),
+    ("🗒\u{FE0F}", "spiral notepad", "office"),
+    ("🗓\u{FE0F}", "spiral calendar", "office"),
+    ("📇", "card index", "office"),
+    ("📈", "chart increasing", "office"),
+    ("📉", "chart decreasing", "office"),
+    ("📊", "bar chart", "office"),
+    ("📋", "clipboard", "office"),
+    ("📌", "pushpin", "office"),
+    ("📍", "round pushpin", "office"),
+    ("📎", "paperclip", "office"),
+    ("🖇\u{FE0F}", "linked paperclips", "office"),
+    ("📏", "straight ruler", "office"),
+    ("📐", "triangular ruler", "office"),
+    ("✂\u{FE0F}", "scissors", "office"),
+    ("🗃\u{FE0F}", "card file box", "office"),
+    ("🗄\u{FE0F}", "file cabinet", "office"),
+    ("🗑\u{FE0F}", "wastebasket", "office"),
+    ("🔒", "locked", "lock"),
+    ("🔓", "unlocked", "lock"),
+    ("🔏", "locked with pen", "lock"),
+    ("🔐", "locked with key", "lock"),
+    ("🔑", "key", "lock"),
+    ("🗝\u{FE0F}", "old key", "lock"),
+    ("🔨", "hammer", "tool"),
+    ("🪓", "axe", "tool"),
+    ("⛏\u{FE0F}", "pick", "tool"),
+    ("⚒\u{FE0F}", "hammer and pick", "tool"),
+    ("🛠\u{FE0F}", "hammer and wrench", "tool"),
+    ("🗡\u{FE0F}", "dagger", "tool"),
+    ("⚔\u{FE0F}", "crossed swords", "tool"),
+    ("💣", "bomb", "tool"),
+    ("🪃", "boomerang", "tool"),
+    ("🏹", "bow and arrow", "tool"),
+    ("🛡\u{FE0F}", "shield", "tool"),
+    ("🪚", "carpentry saw", "tool"),
+    ("🔧", "wrench", "tool"),
+    ("🪛", "screwdriver", "tool"),
+    ("🔩", "nut and bolt", "tool"),
+    ("⚙\u{FE0F}", "gear", "tool"),
+    ("🗜\u{FE0F}", "clamp", "tool"),
+    ("⚖\u{FE0F}", "balance scale", "tool"),
+    ("🦯", "white cane", "tool"),
+    ("🔗", "link", "tool"),
+    ("⛓\u{FE0F}\u{200D}💥", "broken chain", "tool"),
+    ("⛓\u{FE0F}", "chains", "tool"),
+    ("🪝", "hook", "tool"),
+    ("🧰", "toolbox", "tool"),
+    ("🧲", "magnet", "tool"),
+    ("🪜", "ladder", "tool"),
+    ("⚗\u{FE0F}", "alembic", "science"),
+    ("🧪", "test tube", "science"),
+    ("🧫", "petri dish", "science"),
+    ("🧬", "dna", "science"),
+    ("🔬", "microscope", "science"),
+    ("🔭", "telescope", "science"),
+    ("📡", "satellite antenna", "science"),
+    ("💉", "syringe", "medical"),
+    ("🩸", "drop of blood", "medical"),
+    ("💊", "pill", "medical"),
+    ("🩹", "adhesive bandage", "medical"),
+    ("🩼", "crutch", "medical"),
+    ("🩺", "stethoscope", "medical"),
+    ("🩻", "x-ray", "medical"),
+    ("🚪", "door", "household"),
+    ("🛗", "elevator", "household"),
+    ("🪞", "mirror", "household"),
+    ("🪟", "window", "household"),
+    ("🛏\u{FE0F}", "bed", "household"),
+    ("🛋\u{FE0F}", "couch and lamp", "household"),
+    ("🪑", "chair", "household"),
+    ("🚽", "toilet", "household"),
+    ("🪠", "plunger", "household"),
+    ("🚿", "shower", "household"),
+    ("🛁", "bathtub", "household"),
+    ("🪤", "mouse trap", "household"),
+    ("🪒", "razor", "household"),
+    ("🧴", "lotion bottle", "household"),
+    ("🧷", "safety pin", "household"),
+    ("🧹", "broom", "household"),
+    ("🧺", "basket", "household"),
+    ("🧻", "roll of paper", "household"),
+    ("🪣", "bucket", "household"),
+    ("🧼", "soap", "household"),
+    ("🫧", "bubbles", "household"),
+    ("🪥", "toothbrush", "household"),
+    ("🧽", "sponge", "household"),
+    ("🧯", "fire extinguisher", "household"),
+    ("🛒", "shopping cart", "household"),
+    ("🚬", "cigarette", "other object"),
+    ("⚰\u{FE0F}", "coffin", "other object"),
+    ("🪦", "headstone", "other object"),
+    ("⚱\u{FE0F}", "funeral urn", "other object"),
+    ("🧿", "nazar amulet", "other object"),
+    ("🪬", "hamsa", "other object"),
+    ("🗿", "moai", "other object"),
+    ("🪧", "placard", "other object"),
+    ("🪪", "identification card", "other object"),
+    ("🏧", "ATM sign", "transport sign"),
+    ("🚮", "litter in bin sign", "transport sign"),
+    ("🚰", "potable water", "transport sign"),
+    ("♿", "wheelchair symbol", "transport sign"),
+    ("🚹", "men’s room", "transport sign"),
+    ("🚺", "women’s room", "transport sign"),
+    ("🚻", "restroom", "transport sign"),
+    ("🚼", "baby symbol", "transport sign"),
+    ("🚾", "water closet", "transport sign"),
+    ("🛂", "passport control", "transport sign"),
+    ("🛃", "customs", "transport sign"),
+    ("🛄", "baggage claim", "transport sign"),
+    ("🛅", "left luggage", "transport sign"),
+    ("⚠\u{FE0F}", "warning", "warning"),
+    ("🚸", "children crossing", "warning"),
+    ("⛔", "no entry", "warning"),
+    ("🚫", "prohibited", "warning"),
+    ("🚳", "no bicycles", "warning"),
+    ("🚭", "no smoking", "warning"),
+    ("🚯", "no littering", "warning"),
+    ("🚱", "non-potable water", "warning"),
+    ("🚷", "no pedestrians", "warning"),
+    ("📵", "no mobile phones", "warning"),
+    ("🔞", "no one under eighteen", "warning"),
+    ("☢\u{FE0F}", "radioactive", "warning"),
+    ("☣\u{FE0F}", "biohazard", "warning"),
+    ("⬆\u{FE0F}", "up arrow", "arrow"),
+    ("↗\u{FE0F}", "up-right arrow", "arrow"),
+    ("➡\u{FE0F}", "right arrow", "arrow"),
+    ("↘\u{FE0F}", "down-right arrow", "arrow"),
+    ("⬇\u{FE0F}", "down arrow", "arrow"),
+    ("↙\u{FE0F}", "down-left arrow", "arrow"),
+    ("⬅\u{FE0F}", "left arrow", "arrow"),
+    ("↖\u{FE0F}", "up-left arrow", "arrow"),
+    ("↕\u{FE0F}", "up-down arrow", "arrow"),
+    ("↔\u{FE0F}", "left-right arrow", "arrow"),
+    ("↩\u{FE0F}", "right arrow curving left", "arrow"),
+    ("↪\u{FE0F}", "left arrow curving right", "arrow"),
+    ("⤴\u{FE0F}", "right arrow curving up", "arrow"),
+    ("⤵\u{FE0F}", "right arrow curving down", "arrow"),
+    ("🔃", "clockwise vertical arrows", "arrow"),
+    ("🔄", "counterclockwise arrows button", "arrow"),
+    ("🔙", "BACK arrow", "arrow"),
+    ("🔚", "END arrow", "arrow"),
+    ("🔛", "ON! arrow", "arrow"),
+    ("🔜", "SOON arrow", "arrow"),
+    ("🔝", "TOP arrow", "arrow"),
+    ("🛐", "place of worship", "religion"),
+    ("⚛\u{FE0F}", "atom symbol", "religion"),
+    ("🕉\u{FE0F}", "om", "religion"),
+    ("✡\u{FE0F}", "star of David", "religion"),
+    ("☸\u{FE0F}", "wheel of dharma", "religion"),
+    ("☯\u{FE0F}", "yin yang", "religion"),
+    ("✝\u{FE0F}", "latin cross", "religion"),
+    ("☦\u{FE0F}", "orthodox cross", "religion"),
+    ("☪\u{FE0F}", "star and crescent", "religion"),
+    ("☮\u{FE0F}", "peace symbol", "religion"),
+    ("🕎", "menorah", "religion"),
+    ("🔯", "dotted six-pointed star", "religion"),
+    ("🪯", "khanda", "religion"),
+    ("♈", "Aries", "zodiac"),
+    ("♉", "Taurus", "zodiac"),
+    ("♊", "Gemini", "zodiac"),
+    ("♋", "Cancer", "zodiac"),
+    ("♌", "Leo", "zodiac"),
+    ("♍", "Virgo", "zodiac"),
+    ("♎", "Libra", "zodiac"),
+    ("♏", "Scorpio", "zodiac"),
+    ("♐", "Sagittarius", "zodiac"),
+    ("♑", "Capricorn", "zodiac"),
+    ("♒", "Aquarius", "zodiac"),
+    ("♓", "Pisces", "zodiac"),
+    ("⛎", "Ophiuchus", "zodiac"),
+    ("🔀", "shuffle tracks button", "av symbol"),
+    ("🔁", "repeat button", "av symbol"),
+    ("🔂", "repeat single button", "av symbol"),
+    ("▶\u{FE0F}", "play button", "av symbol"),
+    ("⏩", "fast-forward button", "av symbol"),
+    ("⏭\u{FE0F}", "next track button", "av symbol"),
+    ("⏯\u{FE0F}", "play or pause button", "av symbol"),
+    ("◀\u{FE0F}", "reverse button", "av symbol"),
+    ("⏪", "fast reverse button", "av symbol"),
+    ("⏮\u{FE0F}", "last track button", "av symbol"),
+    ("🔼", "upwards button", "av symbol"),
+    ("⏫", "fast up button", "av symbol"),
+    ("🔽", "downwards button", "av symbol"),
+    ("⏬", "fast down button", "av symbol"),
+    ("⏸\u{FE0F}", "pause button", "av symbol"),
+    ("⏹\u{FE0F}", "stop button", "av symbol"),
+    ("⏺\u{FE0F}", "record button", "av symbol"),
+    ("⏏\u{FE0F}", "eject button", "av symbol"),
+    ("🎦", "cinema", "av symbol"),
+    ("🔅", "dim button", "av symbol"),
+    ("🔆", "bright button", "av symbol"),
+    ("📶", "antenna bars", "av symbol"),
+    ("🛜", "wireless", "av symbol"),
+    ("📳", "vibration mode", "av symbol"),
+    ("📴", "mobile phone off", "av symbol"),
+    ("♀\u{FE0F}", "female sign", "gender"),
+    ("♂\u{FE0F}", "male sign", "gender"),
+    ("⚧\u{FE0F}", "transgender symbol", "gender"),
+    ("✖\u{FE0F}", "multiply", "math"),
+    ("➕", "plus", "math"),
+    ("➖", "minus", "math"),
+    ("➗", "divide", "math"),
+    ("🟰", "heavy equals sign", "math"),
+    ("♾\u{FE0F}", "infinity", "math"),
+    ("‼\u{FE0F}", "double exclamation mark", "punctuation"),
+    ("⁉\u{FE0F}", "exclamation question mark", "punctuation"),
+    ("❓", "red question mark", "punctuation"),
+    ("❔", "white question mark", "punctuation"),
+    ("❕", "white exclamation mark", "punctuation"),
+    ("❗", "red exclamation mark", "punctuation"),
+    ("〰\u{FE0F}", "wavy dash", "punctuation"),
+    ("💱", "currency exchange", "currency"),
+    ("💲", "heavy dollar sign", "currency"),
+    ("⚕\u{FE0F}", "medical symbol", "other symbol"),
+    ("♻\u{FE0F}", "recycling symbol", "other symbol"),
+    ("⚜\u{FE0F}", "fleur-de-lis", "other symbol"),
+    ("🔱", "trident emblem", "other symbol"),
+    ("📛", "name badge", "other symbol"),
+    ("🔰", "Japanese symbol for beginner", "other symbol"),
+    ("⭕", "hollow red circle", "other symbol"),
+    ("✅", "check mark button", "other symbol"),
+    ("☑\u{FE0F}", "check box with check", "other symbol"),
+    ("✔\u{FE0F}", "check mark", "other symbol"),
+    ("❌", "cross mark", "other symbol"),
+    ("❎", "cross mark button", "other symbol"),
+    ("➰", "curly loop", "other symbol"),
+    ("➿", "double curly loop", "other symbol"),
+    ("〽\u{FE0F}", "part alternation mark", "other symbol"),
+    ("✳\u{FE0F}", "eight-spoked asterisk", "other symbol"),
+    ("✴\u{FE0F}", "eight-pointed star", "other symbol"),
+    ("❇\u{FE0F}", "sparkle", "other symbol"),
+    ("©\u{FE0F}", "copyright", "other symbol"),
+    ("®\u{FE0F}", "registered", "other symbol"),
+    ("™\u{FE0F}", "trade mark", "other symbol"),
+    ("#\u{FE0F}\u{20E3}", "keycap: #", "keycap"),
+    ("*\u{FE0F}\u{20E3}", "keycap: *", "keycap"),
+    ("0\u{FE0F}\u{20E3}", "keycap: 0", "keycap"),
+    ("1\u{FE0F}\u{20E3}", "keycap: 1", "keycap"),
+    ("2\u{FE0F}\u{20E3}", "keycap: 2", "keycap"),
+    ("3\u{FE0F}\u{20E3}", "keycap: 3", "keycap"),
+    ("4\u{FE0F}\u{20E3}", "keycap: 4", "keycap"),
+    ("5\u{FE0F}\u{20E3}", "keycap: 5", "keycap"),
+    ("6\u{FE0F}\u{20E3}", "keycap: 6", "keycap"),
+    ("7\u{FE0F}\u{20E3}", "keycap: 7", "keycap"),
+    ("8\u{FE0F}\u{20E3}", "keycap: 8", "keycap"),
+    ("9\u{FE0F}\u{20E3}", "keycap: 9", "keycap"),
+    ("🔟", "keycap: 10", "keycap"),
+    ("🔠", "input latin uppercase", "alphanum"),
+    ("🔡", "input latin lowercase", "alphanum"),
+    ("🔢", "input numbers", "alphanum"),
+    ("🔣", "input symbols", "alphanum"),
+    ("🔤", "input latin letters", "alphanum"),
+    ("🅰\u{FE0F}", "A button (blood type)", "alphanum"),
+    ("🆎", "AB button (blood type)", "alphanum"),
+    ("🅱\u{FE0F}", "B button (blood type)", "alphanum"),
+    ("🆑", "CL button", "alphanum"),
+    ("🆒", "COOL button", "alphanum"),
+    ("🆓", "FREE button", "alphanum"),
+    ("ℹ\u{FE0F}", "information", "alphanum"),
+    ("🆔", "ID button", "alphanum"),
+    ("Ⓜ\u{FE0F}", "circled M", "alphanum"),
+    ("🆕", "NEW button", "alphanum"),
+    ("🆖", "NG button", "alphanum"),
+    ("🅾\u{FE0F}", "O button (blood type)", "alphanum"),
+    ("🆗", "OK button", "alphanum"),
+    ("🅿\u{FE0F}", "P button", "alphanum"),
+    ("🆘", "SOS button", "alphanum"),
+    ("🆙", "UP! button", "alphanum"),
+    ("🆚", "VS button", "alphanum"),
+    ("🈁", "Japanese “here” button", "alphanum"),
+    ("🈂\u{FE0F}", "Japanese “service charge” button", "alphanum"),
+    ("🈷\u{FE0F}", "Japanese “monthly amount” button", "alphanum"),
+    ("🈶", "Japanese “not free of charge” button", "alphanum"),
+    ("🈯", "Japanese “reserved” button", "alphanum"),
+    ("🉐", "Japanese “bargain” button", "alphanum"),
+    ("🈹", "Japanese “discount” button", "alphanum"),
+    ("🈚", "Japanese “free of charge” button", "alphanum"),
+    ("🈲", "Japanese “prohibited” button", "alphanum"),
+    ("🉑", "Japanese “acceptable” button", "alphanum"),
+    ("🈸", "Japanese “application” button", "alphanum"),
+    ("🈴", "Japanese “passing grade” button", "alphanum"),
+    ("🈳", "Japanese “vacancy” button", "alphanum"),
+    ("㊗\u{FE0F}", "Japanese “congratulations” button", "alphanum"),
+    ("㊙\u{FE0F}", "Japanese “secret” button", "alphanum"),
+    ("🈺", "Japanese “open for business” button", "alphanum"),
+    ("🈵", "Japanese “no vacancy” button", "alphanum"),
+    ("🔴", "red circle", "geometric"),
+    ("🟠", "orange circle", "geometric"),
+    ("🟡", "yellow circle", "geometric"),
+    ("🟢", "green circle", "geometric"),
+    ("🔵", "blue circle", "geometric"),
+    ("🟣", "purple circle", "geometric"),
+    ("🟤", "brown circle", "geometric"),
+    ("⚫", "black circle", "geometric"),
+    ("⚪", "white circle", "geometric"),
+    ("🟥", "red square", "geometric"),
+    ("🟧", "orange square", "geometric"),
+    ("🟨", "yellow square", "geometric"),
+    ("🟩", "green square", "geometric"),
+    ("🟦", "blue square", "geometric"),
+    ("🟪", "purple square", "geometric"),
+    ("🟫", "brown square", "geometric"),
+    ("⬛", "black large square", "geometric"),
+    ("⬜", "white large square", "geometric"),
+    ("◼\u{FE0F}", "black medium square", "geometric"),
+    ("◻\u{FE0F}", "white medium square", "geometric"),
+    ("◾", "black medium-small square", "geometric"),
+    ("◽", "white medium-small square", "geometric"),
+    ("▪\u{FE0F}", "black small square", "geometric"),
+    ("▫\u{FE0F}", "white small square", "geometric"),
+    ("🔶", "large orange diamond", "geometric"),
+    ("🔷", "large blue diamond", "geometric"),
+    ("🔸", "small orange diamond", "geometric"),
+    ("🔹", "small blue diamond", "geometric"),
+    ("🔺", "red triangle pointed up", "geometric"),
+    ("🔻", "red triangle pointed down", "geometric"),
+    ("💠", "diamond with a dot", "geometric"),
+    ("🔘", "radio button", "geometric"),
+    ("🔳", "white square button", "geometric"),
+    ("🔲", "black square button", "geometric"),
+    ("🏁", "chequered flag", "flag"),
+    ("🚩", "triangular flag", "flag"),
+    ("🎌", "crossed flags", "flag"),
+    ("🏴", "black flag", "flag"),
+    ("🏳\u{FE0F}", "white flag", "flag"),
+    ("🏳\u{FE0F}\u{200D}🌈", "rainbow flag", "flag"),
+    ("🏳\u{FE0F}\u{200D}⚧\u{FE0F}", "transgender flag", "flag"),
+    ("🏴\u{200D}☠\u{FE0F}", "pirate flag", "flag"),
+    ("🇦🇨", "flag: Ascension Island", "country flag"),
+    ("🇦🇩", "flag: Andorra", "country flag"),
+    ("🇦🇪", "flag: United Arab Emirates", "country flag"),
+    ("🇦🇫", "flag: Afghanistan", "country flag"),
+    ("🇦🇬", "flag: Antigua & Barbuda", "country flag"),
+    ("🇦🇮", "flag: Anguilla", "country flag"),
+    ("🇦🇱", "flag: Albania", "country flag"),
+    ("🇦🇲", "flag: Armenia", "country flag"),
+    ("🇦🇴", "flag: Angola", "country flag"),
+    ("🇦🇶", "flag: Antarctica", "country flag"),
+    ("🇦🇷", "flag: Argentina", "country flag"),
+    ("🇦🇸", "flag: American Samoa", "country flag"),
+    ("🇦🇹", "flag: Austria", "country flag"),
+    ("🇦🇺", "flag: Australia", "country flag"),
+    ("🇦🇼", "flag: Aruba", "country flag"),
+    ("🇦🇽", "flag: Åland Islands", "country flag"),
+    ("🇦🇿", "flag: Azerbaijan", "country flag"),
+    ("🇧🇦", "flag: Bosnia & Herzegovina", "country flag"),
+    ("🇧🇧", "flag: Barbados", "country flag"),
+    ("🇧🇩", "flag: Bangladesh", "country flag"),
+    ("🇧🇪", "flag: Belgium", "country flag"),
+    ("🇧🇫", "flag: Burkina Faso", "country flag"),
+    ("🇧🇬", "flag: Bulgaria", "country flag"),
+    ("🇧🇭", "flag: Bahrain", "country flag"),
+    ("🇧🇮", "flag: Burundi", "country flag"),
+    ("🇧🇯", "flag: Benin", "country flag"),
+    ("🇧🇱", "flag: St. Barthélemy", "country flag"),
+    ("🇧🇲", "flag: Bermuda", "country flag"),
+    ("🇧🇳", "flag: Brunei", "country flag"),
+    ("🇧🇴", "flag: Bolivia", "country flag"),
+    ("🇧🇶", "flag: Caribbean Netherlands", "country flag"),
+    ("🇧🇷", "flag: Brazil", "country flag"),
+    ("🇧🇸", "flag: Bahamas", "country flag"),
+    ("🇧🇹", "flag: Bhutan", "country flag"),
+    ("🇧🇻", "flag: Bouvet Island", "country flag"),
+    ("🇧🇼", "flag: Botswana", "country flag"),
+    ("🇧🇾", "flag: Belarus", "country flag"),
+    ("🇧🇿", "flag: Belize", "country flag"),
+    ("🇨🇦", "flag: Canada", "country flag"),
+    ("🇨🇨", "flag: Cocos (Keeling) Islands", "country flag"),
+    ("🇨🇩", "flag: Congo - Kinshasa", "country flag"),
+    ("🇨🇫", "flag: Central African Republic", "country flag"),
+    ("🇨🇬", "flag: Congo - Brazzaville", "country flag"),
+    ("🇨🇭", "flag: Switzerland", "country flag"),
+    ("🇨🇮", "flag: Côte d’Ivoire", "country flag"),
+    ("🇨🇰", "flag: Cook Islands", "country flag"),
+    ("🇨🇱", "flag: Chile", "country flag"),
+    ("🇨🇲", "flag: Cameroon", "country flag"),
+    ("🇨🇳", "flag: China", "country flag"),
+    ("🇨🇴", "flag: Colombia", "country flag"),
+    ("🇨🇵", "flag: Clipperton Island", "country flag"),
+    ("🇨🇷", "flag: Costa Rica", "country flag"),
+    ("🇨🇺", "flag: Cuba", "country flag"),
+    ("🇨🇻", "flag: Cape Verde", "country flag"),
+    ("🇨🇼", "flag: Curaçao", "country flag"),
+    ("🇨🇽", "flag: Christmas Island", "country flag"),
+    ("🇨🇾", "flag: Cyprus", "country flag"),
+    ("🇨🇿", "flag: Czechia", "country flag"),
+    ("🇩🇪", "flag: Germany", "country flag"),
+    ("🇩🇬", "flag: Diego Garcia", "country flag"),
+    ("🇩🇯", "flag: Djibouti", "country flag"),
+    ("🇩🇰", "flag: Denmark", "country flag"),
+    ("🇩🇲", "flag: Dominica", "country flag"),
+    ("🇩🇴", "flag: Dominican Republic", "country flag"),
+    ("🇩🇿", "flag: Algeria", "country flag"),
+    ("🇪🇦", "flag: Ceuta & Melilla", "country flag"),
+    ("🇪🇨", "flag: Ecuador", "country flag"),
+    ("🇪🇪", "flag: Estonia", "country flag"),
+    ("🇪🇬", "flag: Egypt", "country flag"),
+    ("🇪🇭", "flag: Western Sahara", "country flag"),
+    ("🇪🇷", "flag: Eritrea", "country flag"),
+    ("🇪🇸", "flag: Spain", "country flag"),
+    ("🇪🇹", "flag: Ethiopia", "country flag"),
+    ("🇪🇺", "flag: European Union", "country flag"),
+    ("🇫🇮", "flag: Finland", "country flag"),
+    ("🇫🇯", "flag: Fiji", "country flag"),
+    ("🇫🇰", "flag: Falkland Islands", "country flag"),
+    ("🇫🇲", "flag: Micronesia", "country flag"),
+    ("🇫🇴", "flag: Faroe Islands", "country flag"),
+    ("🇫🇷", "flag: France", "country flag"),
+    ("🇬🇦", "flag: Gabon", "country flag"),
+    ("🇬🇧", "flag: United Kingdom", "country flag"),
+    ("🇬🇩", "flag: Grenada", "country flag"),
+    ("🇬🇪", "flag: Georgia", "country flag"),
+    ("🇬🇫", "flag: French Guiana", "country flag"),
+    ("🇬🇬", "flag: Guernsey", "country flag"),
+    ("🇬🇭", "flag: Ghana", "country flag"),
+    ("🇬🇮", "flag: Gibraltar", "country flag"),
+    ("🇬🇱", "flag: Greenland", "country flag"),
+    ("🇬🇲", "flag: Gambia", "country flag"),
+    ("🇬🇳", "flag: Guinea", "country flag"),
+    ("🇬🇵", "flag: Guadeloupe", "country flag"),
+    ("🇬🇶", "flag: Equatorial Guinea", "country flag"),
+    ("🇬🇷", "flag: Greece", "country flag"),
+    ("🇬🇸", "flag: South Georgia & South Sandwich Islands", "country flag"),
+    ("🇬🇹", "flag: Guatemala", "country flag"),
+    ("🇬🇺", "flag: Guam", "country flag"),
+    ("🇬🇼", "flag: Guinea-Bissau", "country flag"),
+    ("🇬🇾", "flag: Guyana", "country flag"),
+    ("🇭🇰", "flag: Hong Kong SAR China", "country flag"),
+    ("🇭🇲", "flag: Heard & McDonald Islands", "country flag"),
+    ("🇭🇳", "flag: Honduras", "country flag"),
+    ("🇭🇷", "flag: Croatia", "country flag"),
+    ("🇭🇹", "flag: Haiti", "country flag"),
+    ("🇭🇺", "flag: Hungary", "country flag"),
+    ("🇮🇨", "flag: Canary Islands", "country flag"),
+    ("🇮🇩", "flag: Indonesia", "country flag"),
+    ("🇮🇪", "flag: Ireland", "country flag"),
+    ("🇮🇱", "flag: Israel", "country flag"),
+    ("🇮🇲", "flag: Isle of Man", "country flag"),
+    ("🇮🇳", "flag: India", "country flag"),
+    ("🇮🇴", "flag: British Indian Ocean Territory", "country flag"),
+    ("🇮🇶", "flag: Iraq", "country flag"),
+    ("🇮🇷", "flag: Iran", "country flag"),
+    ("🇮🇸", "flag: Iceland", "country flag"),
+    ("🇮🇹", "flag: Italy", "country flag"),
+    ("🇯🇪", "flag: Jersey", "country flag"),
+    ("🇯🇲", "flag: Jamaica", "country flag"),
+    ("🇯🇴", "flag: Jordan", "country flag"),
+    ("🇯🇵", "flag: Japan", "country flag"),
+    ("🇰🇪", "flag: Kenya", "country flag"),
+    ("🇰🇬", "flag: Kyrgyzstan", "country flag"),
+    ("🇰🇭", "flag: Cambodia", "country flag"),
+    ("🇰🇮", "flag: Kiribati", "country flag"),
+    ("🇰🇲", "flag: Comoros", "country flag"),
+    ("🇰🇳", "flag: St. Kitts & Nevis", "country flag"),
+    ("🇰🇵", "flag: North Korea", "country flag"),
+    ("🇰🇷", "flag: South Korea", "country flag"),
+    ("🇰🇼", "flag: Kuwait", "country flag"),
+    ("🇰🇾", "flag: Cayman Islands", "country flag"),
+    ("🇰🇿", "flag: Kazakhstan", "country flag"),
+    ("🇱🇦", "flag: Laos", "country flag"),
+    ("🇱🇧", "flag: Lebanon", "country flag"),
+    ("🇱🇨", "flag: St. Lucia", "country flag"),
+    ("🇱🇮", "flag: Liechtenstein", "country flag"),
+    ("🇱🇰", "flag: Sri Lanka", "country flag"),
+    ("🇱🇷", "flag: Liberia", "country flag"),
+    ("🇱🇸", "flag: Lesotho", "country flag"),
+    ("🇱🇹", "flag: Lithuania", "country flag"),
+    ("🇱🇺", "flag: Luxembourg", "country flag"),
+    ("🇱🇻", "flag: Latvia", "country flag"),
+    ("🇱🇾", "flag: Libya", "country flag"),
+    ("🇲🇦", "flag: Morocco", "country flag"),
+    ("🇲🇨", "flag: Monaco", "country flag"),
+    ("🇲🇩", "flag: Moldova", "country flag"),
+    ("🇲🇪", "flag: Montenegro", "country flag"),
+    ("🇲🇫", "flag: St. Martin", "country flag"),
+    ("🇲🇬", "flag: Madagascar", "country flag"),
+    ("🇲🇭", "flag: Marshall Islands", "country flag"),
+    ("🇲🇰", "flag: North Macedonia", "country flag"),
+    ("🇲🇱", "flag: Mali", "country flag"),
+    ("🇲🇲", "flag: Myanmar (Burma)", "country flag"),
+    ("🇲🇳", "flag: Mongolia", "country flag"),
+    ("🇲🇴", "flag: Macao SAR China", "country flag"),
+    ("🇲🇵", "flag: Northern Mariana Islands", "country flag"),
+    ("🇲🇶", "flag: Martinique", "country flag"),
+    ("🇲🇷", "flag: Mauritania", "country flag"),
+    ("🇲🇸", "flag: Montserrat", "country flag"),
+    ("🇲🇹", "flag: Malta", "country flag"),
+    ("🇲🇺", "flag: Mauritius", "country flag"),
+    ("🇲🇻", "flag: Maldives", "country flag"),
+    ("🇲🇼", "flag: Malawi", "country flag"),
+    ("🇲🇽", "flag: Mexico", "country flag"),
+    ("🇲🇾", "flag: Malaysia", "country flag"),
+    ("🇲🇿", "flag: Mozambique", "country flag"),
+    ("🇳🇦", "flag: Namibia", "country flag"),
+    ("🇳🇨", "flag: New Caledonia", "country flag"),
+    ("🇳🇪", "flag: Niger", "country flag"),
+    ("🇳🇫", "flag: Norfolk Island", "country flag"),
+    ("🇳🇬", "flag: Nigeria", "country flag"),
+    ("🇳🇮", "flag: Nicaragua", "country flag"),
+    ("🇳🇱", "flag: Netherlands", "country flag"),
+    ("🇳🇴", "flag: Norway", "country flag"),
+    ("🇳🇵", "flag: Nepal", "country flag"),
+    ("🇳🇷", "flag: Nauru", "country flag"),
+    ("🇳🇺", "flag: Niue", "country flag"),
+    ("🇳🇿", "flag: New Zealand", "country flag"),
+    ("🇴🇲", "flag: Oman", "country flag"),
+    ("🇵🇦", "flag: Panama", "country flag"),
+    ("🇵🇪", "flag: Peru", "country flag"),
+    ("🇵🇫", "flag: French Polynesia", "country flag"),
+    ("🇵🇬", "flag: Papua New Guinea", "country flag"),
+    ("🇵🇭", "flag: Philippines", "country flag"),
+    ("🇵🇰", "flag: Pakistan", "country flag"),
+    ("🇵🇱", "flag: Poland", "country flag"),
+    ("🇵🇲", "flag: St. Pierre & Miquelon", "country flag"),
+    ("🇵🇳", "flag: Pitcairn Islands", "country flag"),
+    ("🇵🇷", "flag: Puerto Rico", "country flag"),
+    ("🇵🇸", "flag: Palestinian Territories", "country flag"),
+    ("🇵🇹", "flag: Portugal", "country flag"),
+    ("🇵🇼", "flag: Palau", "country flag"),
+    ("🇵🇾", "flag: Paraguay", "country flag"),
+    ("🇶🇦", "flag: Qatar", "country flag"),
+    ("🇷🇪", "flag: Réunion", "country flag"),
+    ("🇷🇴", "flag: Romania", "country flag"),
+    ("🇷🇸", "flag: Serbia", "country flag"),
+    ("🇷🇺", "flag: Russia", "country flag"),
+    ("🇷🇼", "flag: Rwanda", "country flag"),
+    ("🇸🇦", "flag: Saudi Arabia", "country flag"),
+    ("🇸🇧", "flag: Solomon Islands", "country flag"),
+    ("🇸🇨", "flag: Seychelles", "country flag"),
+    ("🇸🇩", "flag: Sudan", "country flag"),
+    ("🇸🇪", "flag: Sweden", "country flag"),
+    ("🇸🇬", "flag: Singapore", "country flag"),
+    ("🇸🇭", "flag: St. Helena", "country flag"),
+    ("🇸🇮", "flag: Slovenia", "country flag"),
+    ("🇸🇯", "flag: Svalbard & Jan Mayen", "country flag"),
+    ("🇸🇰", "flag: Slovakia", "country flag"),
+    ("🇸🇱", "flag: Sierra Leone", "country flag"),
+    ("🇸🇲", "flag: San Marino", "country flag"),
+    ("🇸🇳", "flag: Senegal", "country flag"),
+    ("🇸🇴", "flag: Somalia", "country flag"),
+    ("🇸🇷", "flag: Suriname", "country flag"),
+    ("🇸🇸", "flag: South Sudan", "country flag"),
+    ("🇸🇹", "flag: São Tomé & Príncipe", "country flag"),
+    ("🇸🇻", "flag: El Salvador", "country flag"),
+    ("🇸🇽", "flag: Sint Maarten", "country flag"),
+    ("🇸🇾", "flag: Syria", "country flag"),
+    ("🇸🇿", "flag: Eswatini", "country flag"),
+    ("🇹🇦", "flag: Tristan da Cunha", "country flag"),
+    ("🇹🇨", "flag: Turks & Caicos Islands", "country flag"),
+    ("🇹🇩", "flag: Chad", "country flag"),
+    ("🇹🇫", "flag: French Southern Territories", "country flag"),
+    ("🇹🇬", "flag: Togo", "country flag"),
+    ("🇹🇭", "flag: Thailand", "country flag"),
+    ("🇹🇯", "flag: Tajikistan", "country flag"),
+    ("🇹🇰", "flag: Tokelau", "country flag"),
+    ("🇹🇱", "flag: Timor-Leste", "country flag"),
+    ("🇹🇲", "flag: Turkmenistan", "country flag"),
+    ("🇹🇳", "flag: Tunisia", "country flag"),
+    ("🇹🇴", "flag: Tonga", "country flag"),
+    ("🇹🇷", "flag: Türkiye", "country flag"),
+    ("🇹🇹", "flag: Trinidad & Tobago", "country flag"),
+    ("🇹🇻", "flag: Tuvalu", "country flag"),
+    ("🇹🇼", "flag: Taiwan", "country flag"),
+    ("🇹🇿", "flag: Tanzania", "country flag"),
+    ("🇺🇦", "flag: Ukraine", "country flag"),
+    ("🇺🇬", "flag: Uganda", "country flag"),
+    ("🇺🇲", "flag: U.S. Outlying Islands", "country flag"),
+    ("🇺🇳", "flag: United Nations", "country flag"),
+    ("🇺🇸", "flag: United States", "country flag"),
+    ("🇺🇾", "flag: Uruguay", "country flag"),
+    ("🇺🇿", "flag: Uzbekistan", "country flag"),
+    ("🇻🇦", "flag: Vatican City", "country flag"),
+    ("🇻🇨", "flag: St. Vincent & Grenadines", "country flag"),
+    ("🇻🇪", "flag: Venezuela", "country flag"),
+    ("🇻🇬", "flag: British Virgin Islands", "country flag"),
+    ("🇻🇮", "flag: U.S. Virgin Islands", "country flag"),
+    ("🇻🇳", "flag: Vietnam", "country flag"),
+    ("🇻🇺", "flag: Vanuatu", "country flag"),
+    ("🇼🇫", "flag: Wallis & Futuna", "country flag"),
+    ("🇼🇸", "flag: Samoa", "country flag"),
+    ("🇽🇰", "flag: Kosovo", "country flag"),
+    ("🇾🇪", "flag: Yemen", "country flag"),
+    ("🇾🇹", "flag: Mayotte", "country flag"),
+    ("🇿🇦", "flag: South Africa", "country flag"),
+    ("🇿🇲", "flag: Zambia", "country flag"),
+    ("🇿🇼", "flag: Zimbabwe", "country flag"),
+    ("🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}", "flag: England", "subdivision flag"),
+    ("🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}", "flag: Scotland", "subdivision flag"),
+    ("🏴\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}", "flag: Wales", "subdivision flag"),
+    ("→", "rightwards arrow", "arrow right symbol"),
+    ("←", "leftwards arrow", "arrow left symbol"),
+    ("↑", "upwards arrow", "arrow up symbol"),
+    ("↓", "downwards arrow", "arrow down symbol"),
+    ("↔", "left right arrow", "arrow symbol"),
+    ("⇒", "rightwards double arrow", "arrow right implies symbol"),
+    ("✓", "check mark", "tick yes symbol"),
+    ("✗", "ballot x", "cross no symbol"),
+    ("•", "bullet", "dot symbol"),
+    ("…", "horizontal ellipsis", "dots symbol"),
+    ("—", "em dash", "dash symbol"),
+    ("–", "en dash", "dash symbol"),
+    ("«", "left guillemet", "quote symbol"),
+    ("»", "right guillemet", "quote symbol"),
+    ("°", "degree sign", "temperature symbol"),
+    ("±", "plus minus sign", "math symbol"),
+    ("×", "multiplication sign", "times math symbol"),
+    ("÷", "division sign", "divide math symbol"),
+    ("≈", "almost equal to", "approximately math symbol"),
+    ("≠", "not equal to", "math symbol"),
+    ("≤", "less than or equal to", "math symbol"),
+    ("≥", "greater than or equal to", "math symbol"),
+    ("∞", "infinity", "math symbol"),
+    ("€", "euro sign", "currency money symbol"),
+    ("£", "pound sign", "currency money symbol"),
+    ("¥", "yen sign", "currency money symbol"),
+    ("©", "copyright sign", "symbol"),
+    ("®", "registered sign", "symbol"),
+    ("™", "trade mark sign", "trademark symbol"),
+    ("§", "section sign", "symbol"),
+    ("¶", "pilcrow sign", "paragraph symbol"),
+    ("¯\\_(ツ)_/¯", "shrug", "kaomoji"),
+    ("( ͡° ͜ʖ ͡°)", "lenny face", "kaomoji"),
+    ("(╯°□°)╯︵ ┻━┻", "table flip", "kaomoji angry"),
+    ("┬─┬ノ( º _ ºノ)", "put table back", "kaomoji calm"),
+    ("ಠ_ಠ", "look of disapproval", "kaomoji"),
+]
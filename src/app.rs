@@ -1,28 +1,280 @@
-use crate::config::AppConfig;
-use crate::history::History;
+use crate::cache::{CachedFile, EntryCache, FileStamp};
+use crate::compositor;
+use crate::emoji;
+use crate::clock::{Clock, SystemClock};
+use crate::config::{AppConfig, AppOverride, FeaturesConfig, FileSort, LaunchMethod, ListLayout, UndefinedEnvVars};
+use crate::history::{FavoriteReport, History};
+use crate::mime;
+use crate::preview;
+use crate::process::{self, Process};
+use crate::recent;
+use crate::tasks::{TaskKind, TaskOutput, TaskPool};
+use crate::translit;
 use dirs::config_dir;
-use freedesktop_desktop_entry::{Iter, default_paths, get_languages_from_env};
+use freedesktop_desktop_entry::{DesktopEntry, Iter, default_paths, get_languages_from_env};
 use ratatui::widgets::ListState;
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
-    fs,
-    io,
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::{self, File},
+    io::{self, Read, Seek},
     os::unix::{fs::PermissionsExt, process::CommandExt},
     path::{Path, PathBuf},
-    process::{Command, Stdio},
+    process::{Child, Command, ExitStatus, Stdio},
+    rc::Rc,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+const HIDDEN_PREFIX: &str = "hidden:";
+pub const BOOKMARK_PREFIX: &str = "@";
+pub const RECENT_PREFIX: &str = "recent:";
+const EMOJI_RESULT_LIMIT: usize = 200;
+// How long `features.capture-launch-errors` watches a launched app before counting it as started.
+const LAUNCH_WATCH: Duration = Duration::from_millis(500);
+// How long `general.abort-on-hook-failure` waits for the pre-launch hook before giving up on it.
+const HOOK_TIMEOUT: Duration = Duration::from_secs(5);
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+// A launch watched until it fails or LAUNCH_WATCH passes: every one through a launch method, and
+// plain ones with `features.capture-launch-errors`. `started` is on the App's clock.
+struct WatchedLaunch {
+    entry: AppEntry,
+    program: String,
+    args: Vec<String>,
+    child: Child,
+    log: Option<LaunchLog>,
+    route: LaunchRoute,
+    started: Duration,
+}
+
+// How a watched launch was started: as a plain child, through `general.launch-method`, or as a
+// plain child because that method failed, with the reason to show once it's running.
+enum LaunchRoute {
+    Direct,
+    Wrapped(LaunchMethod),
+    Fallback(String),
+}
+
+// The start of a launched app's stderr. The app writes into a pipe drained by a detached
+// `head -c 4096; cat >/dev/null`, which copies the first 4 KiB to an already unlinked `file` and
+// throws the rest away: a chatty app can't fill the runtime dir, and one that outlives qst never
+// gets SIGPIPE, since the drain outlives qst too.
+struct LaunchLog {
+    file: File,
+    drain: Child,
+}
+
+impl LaunchLog {
+    // The log and the app's end of its pipe.
+    fn start(now: SystemTime) -> Option<(Self, io::PipeWriter)> {
+        let file = launch_log(now)?;
+        let (reader, writer) = io::pipe().ok()?;
+        let script = ["-c".to_string(), "head -c 4096; exec cat >/dev/null".to_string()];
+        let mut drain = detached_command("sh", &script);
+        drain.stdin(reader).stdout(file.try_clone().ok()?);
+        let drain = drain.spawn().ok()?;
+        Some((Self { file, drain }, writer))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum AppMode {
     AppSelection,
     FileSelection,
     ScriptResults,
+    // Picking the app to open this file with; the query filters `open_with_apps`.
+    OpenWith(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntryKind {
+    Desktop,
+    Alias,
+    Binary,
+    Missing,
+    // The "Open in browser" row offered for URL-like queries; `exec_args` holds the URL.
+    Url,
+    // A `[power-menu]` action; `exec_args` holds its shell command.
+    Power,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppEntry {
     pub name: String,
     pub exec_args: Vec<String>,
+    pub kind: EntryKind,
+    #[serde(default)]
+    pub app_id: Option<String>,
+    #[serde(default)]
+    pub wm_class: Option<String>,
+    #[serde(default)]
+    pub desktop_path: Option<PathBuf>,
+    #[serde(default)]
+    pub generic_name: Option<String>,
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub only_show_in: Vec<String>,
+    #[serde(default)]
+    pub not_show_in: Vec<String>,
+    #[serde(default)]
+    pub mime_types: Vec<String>,
+    #[serde(skip)]
+    pub folded_name: Option<String>,
+}
+
+impl AppEntry {
+    pub fn new(name: String, exec_args: Vec<String>, kind: EntryKind) -> Self {
+        Self {
+            name,
+            exec_args,
+            kind,
+            app_id: None,
+            wm_class: None,
+            desktop_path: None,
+            generic_name: None,
+            comment: None,
+            keywords: Vec::new(),
+            only_show_in: Vec::new(),
+            not_show_in: Vec::new(),
+            mime_types: Vec::new(),
+            folded_name: None,
+        }
+    }
+}
+
+// Where a file query looks and what it keeps: `~/Doc` lists `~/` for names starting with `Doc`,
+// shown under `display_root` as typed rather than expanded.
+struct FileQuery {
+    dir: PathBuf,
+    prefix: String,
+    display_root: String,
+}
+
+impl FileQuery {
+    fn parse(query_path: &str) -> Self {
+        let expanded_input = App::expand_path(query_path);
+        let input_path = Path::new(&expanded_input);
+        if expanded_input.ends_with('/') || input_path.is_dir() {
+            let display_root = if query_path.ends_with('/') {
+                query_path.to_string()
+            } else {
+                format!("{}/", query_path)
+            };
+            return Self { dir: input_path.to_path_buf(), prefix: String::new(), display_root };
+        }
+        Self {
+            dir: input_path.parent().unwrap_or_else(|| Path::new(".")).to_path_buf(),
+            prefix: input_path.file_name().and_then(|name| name.to_str()).unwrap_or_default().to_string(),
+            display_root: query_path.rsplit_once('/').map(|(head, _)| format!("{}/", head)).unwrap_or_default(),
+        }
+    }
+
+    // `dir/**/pattern` walks subdirectories instead of reading one.
+    fn is_recursive(&self) -> bool {
+        self.dir.file_name().is_some_and(|name| name == "**")
+    }
+
+    // Under `**/`, a glob still matches names while plain text fuzzy-matches the path below `dir`.
+    fn matches_nested(&self, name: &str, relative: &str, show_hidden: bool) -> bool {
+        if is_glob_pattern(&self.prefix) {
+            return self.matches(name, show_hidden);
+        }
+        (show_hidden || !name.starts_with('.')) && fuzzy_score(&self.prefix, relative).is_some()
+    }
+
+    fn matches(&self, name: &str, show_hidden: bool) -> bool {
+        if name.starts_with('.') && !show_hidden && !self.prefix.starts_with('.') {
+            return false;
+        }
+        // A completed name like `photo[1].jpg` reads as a glob too, so it still lists itself.
+        if is_glob_pattern(&self.prefix) {
+            glob_match(&self.prefix, name) || name.starts_with(&self.prefix)
+        } else {
+            name.starts_with(&self.prefix)
+        }
+    }
+
+    fn filter(&self, listing: &[FileEntry], show_hidden: bool) -> Vec<FileEntry> {
+        listing
+            .iter()
+            .filter(|file| self.matches(file.path.trim_end_matches('/'), show_hidden))
+            .map(|file| FileEntry { path: format!("{}{}", self.display_root, file.path), ..file.clone() })
+            .collect()
+    }
+}
+
+// Repeated Tab in the file explorer: the candidates the cycle started from, the query text before
+// the word being completed, and the candidate currently filled in.
+struct CompletionCycle {
+    prefix: String,
+    files: Vec<FileEntry>,
+    index: usize,
+}
+
+// The directory the file explorer read last, hidden files included. Creating or removing a file
+// changes the directory's mtime, which retires the listing.
+struct DirListing {
+    dir: PathBuf,
+    modified: Option<SystemTime>,
+    files: Vec<FileEntry>,
+}
+
+impl DirListing {
+    fn is_current(&self, dir: &Path) -> bool {
+        self.dir == dir && dir_modified(dir) == self.modified
+    }
+}
+
+fn dir_modified(dir: &Path) -> Option<SystemTime> {
+    fs::metadata(dir).and_then(|meta| meta.modified()).ok()
+}
+
+#[derive(Debug, Clone)]
+pub struct FileEntry {
+    pub path: String,
+    pub is_dir: bool,
+    // A symlink whose target is missing; listed, but dimmed and marked.
+    pub broken_link: bool,
+    pub size: u64,
+    pub modified: Option<SystemTime>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchTier {
+    Unfiltered,
+    Full,
+    Partial,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchField {
+    Name,
+    Transliteration,
+    GenericName,
+    Keyword(usize),
+    Comment,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EntryMatch {
+    pub tier: MatchTier,
+    pub field: MatchField,
+    pub score: i64,
+}
+
+impl EntryMatch {
+    fn unfiltered() -> Self {
+        Self {
+            tier: MatchTier::Unfiltered,
+            field: MatchField::Name,
+            score: 0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -41,6 +293,10 @@ pub enum ScriptAction {
     RefreshResults,
     ExecuteAndExit,
     ExecuteAndRefresh,
+    // `value` is a pid from the `kill` mode.
+    KillProcess,
+    // `value` is a window id from the `win` mode.
+    FocusWindow,
     None,
 }
 
@@ -62,6 +318,8 @@ pub struct ScriptItem {
     pub meta: ScriptRowMeta,
 }
 
+pub type ScriptOutput = (Option<String>, Option<String>, Vec<ScriptItem>);
+
 #[derive(Debug, Clone)]
 struct ScriptPlugin {
     id: String,
@@ -75,77 +333,394 @@ pub struct App {
     pub search_query: String,
     pub search_cursor: usize,
     pub entries: Vec<AppEntry>,
-    pub filtered_entries: Vec<AppEntry>,
+    // List rows as indices into `entries`, or into `open_with_apps` while picking an app to open a
+    // file with. Use `filtered_entry` to read them: `url_entry`, when set, comes first.
+    pub filtered_entries: Vec<usize>,
+    pub url_entry: Option<AppEntry>,
+    pub filtered_matches: Vec<EntryMatch>,
     pub list_state: ListState,
     pub should_quit: bool,
     pub config: AppConfig,
     pub status_message: Option<String>,
+    // A config that failed to load or a missing theme. Unlike `status_message` it stays on screen
+    // until dismissed, and is printed again if qst quits without launching anything.
+    pub config_warning: Option<String>,
+    // Set when the user quit with Esc rather than by launching something.
+    pub cancelled: bool,
     pub launch_args: Option<Vec<String>>,
     pub mode: AppMode,
-    pub filtered_files: Vec<String>,
+    pub filtered_files: Vec<FileEntry>,
+    // Matching files left out of `filtered_files` by `features.max-file-results`.
+    pub files_omitted: usize,
+    // Files marked with `general.mark-key`, in the order they were marked.
+    pub marked_files: Vec<String>,
+    pub file_sort: FileSort,
+    pub show_hidden: bool,
     pub history: History,
     pub script_title: Option<String>,
     pub script_items: Vec<ScriptItem>,
     pub qst_ascii: String,
+    pub tasks: TaskPool,
+    pub debug: bool,
+    // Set by `--print`: launching records the command line in `printed_command` instead.
+    pub print_only: bool,
+    // `--print-name`: record the chosen entry's name (or file, or URL) rather than its command.
+    pub print_name: bool,
+    pub printed_command: Option<String>,
+    pub show_debug_overlay: bool,
+    pub show_help: bool,
+    pub list_height: usize,
+    pub favorite_report: FavoriteReport,
+    pub excluded_entries: Vec<(AppEntry, String)>,
+    favorites_reconciled: bool,
+    clipboard_history: Option<Result<Vec<String>, String>>,
+    config_modified: Option<SystemTime>,
+    processes: Option<Vec<Process>>,
+    // /etc/passwd names for the owners in `processes`, read along with them.
+    user_names: HashMap<u32, String>,
+    windows: Option<Vec<compositor::Window>>,
+    recent_files: Option<Vec<FileEntry>>,
+    dir_listing: Option<DirListing>,
+    // The last query matched against every visible entry and the entries it matched, in
+    // `entries` order. Typing more at the end can only drop matches, so only these are rescored.
+    narrowing: Option<(String, Vec<usize>)>,
+    completion_cycle: Option<CompletionCycle>,
+    open_with_apps: Vec<AppEntry>,
+    open_with_return: Option<String>,
+    pending_confirm: Option<String>,
+    pending_clear_history: bool,
+    preview_cache: HashMap<String, Vec<String>>,
+    status_repeat: Option<(String, u32)>,
+    launch_failures: HashMap<String, u32>,
+    // What the last launch started, for the status line when qst stays open afterwards.
+    launched_name: Option<String>,
+    // Whether the launch in progress was asked to keep qst open; a watched launch only finishes
+    // after `launch` has returned.
+    launch_keeps_open: bool,
+    // Set by `general.run-detached-key` for the launch in progress.
+    run_detached: bool,
+    // Launched processes not waited for yet; reaped on every tick so they don't linger as
+    // zombies while qst stays open.
+    children: Vec<Child>,
+    watched_launches: Vec<WatchedLaunch>,
     scripts: Vec<ScriptPlugin>,
+    clock: Rc<dyn Clock>,
 }
 
 impl App {
-    pub fn new(config: AppConfig, status_message: Option<String>) -> Self {
+    pub fn new(config: AppConfig, config_warning: Option<String>) -> Self {
+        Self::with_clock(config, config_warning, Rc::new(SystemClock::new()))
+    }
+
+    pub fn with_clock(config: AppConfig, config_warning: Option<String>, clock: Rc<dyn Clock>) -> Self {
         let (mut script_aliases, mut app_aliases) = Self::load_aliases();
-        let history = History::load();
+        let (history, status_message) = History::load(config.general.history_path.as_deref());
         let scripts = Self::load_scripts(&mut script_aliases);
         
-        let mut entries = scan_desktop_files(config.features.show_duplicates);
-        
-        if !config.features.show_duplicates {
-            let alias_keys: Vec<String> = app_aliases.keys().map(|k| k.to_lowercase()).collect();
-            entries.retain(|e| !alias_keys.contains(&e.name.to_lowercase()));
-        }
-
+        let mut entries = Vec::new();
         for (name, command) in app_aliases.drain() {
-            entries.push(AppEntry {
+            entries.push(AppEntry::new(
                 name,
-                exec_args: vec![
+                vec![
                     "sh".to_string(),
                     "-c".to_string(),
                     format!(r#"{} "$@""#, command),
                     "--".to_string(),
                 ],
-            });
+                EntryKind::Alias,
+            ));
         }
 
-        let qst_ascii = if let Some(path) = &config.qst_ascii.custom_path {
-            let expanded_path = path.replace("~", std::env::var("HOME").unwrap_or_else(|_| String::new()).as_str());
-            fs::read_to_string(expanded_path).unwrap_or_else(|_| include_str!("../assets/qst.txt").to_string())
-        } else {
-            include_str!("../assets/qst.txt").to_string()
-        };
+        entries.extend(power_entries(&config));
+
+        let mut app = Self::from_parts(config, config_warning, clock, history, entries, scripts);
+        app.status_message = status_message;
+        app.request_entry_scan();
+        app
+    }
+
+    // An app over `entries` as they are, before the desktop-entry scan adds to them; nothing is
+    // read from disk apart from `qst-ascii.custom-path`.
+    fn from_parts(
+        config: AppConfig,
+        config_warning: Option<String>,
+        clock: Rc<dyn Clock>,
+        history: History,
+        mut entries: Vec<AppEntry>,
+        scripts: Vec<ScriptPlugin>,
+    ) -> Self {
+        if config.features.transliterate_search {
+            for entry in &mut entries {
+                entry.folded_name = translit::fold(&entry.name);
+            }
+        }
+
+        let file_sort = history.file_sort.unwrap_or(config.features.file_sort);
+        let show_hidden = config.features.show_hidden_files;
+        let qst_ascii = load_ascii(&config);
+        let config_modified = AppConfig::modified();
 
         let mut app = Self {
             search_query: String::new(),
             search_cursor: 0,
-            filtered_entries: entries.clone(),
+            filtered_entries: Vec::new(),
+            url_entry: None,
+            filtered_matches: Vec::new(),
             entries,
             list_state: ListState::default().with_selected(Some(0)),
             should_quit: false,
             config,
-            status_message,
+            status_message: None,
+            config_warning,
+            cancelled: false,
             launch_args: None,
             mode: AppMode::AppSelection,
             filtered_files: Vec::new(),
+            files_omitted: 0,
+            marked_files: Vec::new(),
+            file_sort,
+            show_hidden,
             history,
             script_title: None,
             script_items: Vec::new(),
             qst_ascii,
+            tasks: TaskPool::new(),
+            debug: false,
+            print_only: false,
+            print_name: false,
+            printed_command: None,
+            show_debug_overlay: false,
+            show_help: false,
+            list_height: 0,
+            favorite_report: FavoriteReport::default(),
+            excluded_entries: Vec::new(),
+            favorites_reconciled: false,
+            clipboard_history: None,
+            config_modified,
+            processes: None,
+            user_names: HashMap::new(),
+            windows: None,
+            recent_files: None,
+            dir_listing: None,
+            narrowing: None,
+            completion_cycle: None,
+            open_with_apps: Vec::new(),
+            open_with_return: None,
+            pending_confirm: None,
+            pending_clear_history: false,
+            preview_cache: HashMap::new(),
+            status_repeat: None,
+            launch_failures: HashMap::new(),
+            launched_name: None,
+            launch_keeps_open: false,
+            run_detached: false,
+            children: Vec::new(),
+            watched_launches: Vec::new(),
             scripts,
+            clock,
         };
 
         app.sort_entries();
-        app.filtered_entries = app.entries.clone();
+        app.filtered_entries = (0..app.entries.len()).collect();
+        app.filtered_matches = vec![EntryMatch::unfiltered(); app.filtered_entries.len()];
         app
     }
 
+    // `from_parts` on a stopped clock, empty history and no scripts, for tests outside this module.
+    #[cfg(test)]
+    pub(crate) fn with_entries(config: AppConfig, entries: Vec<AppEntry>) -> Self {
+        let clock = Rc::new(crate::clock::ManualClock::new(UNIX_EPOCH));
+        Self::from_parts(config, None, clock, History::default(), entries, Vec::new())
+    }
+
+    fn request_entry_scan(&mut self) {
+        let include_path_binaries = self.config.features.include_path_binaries;
+        let use_cache = self.config.features.cache_entries;
+        let desktops = if self.config.features.ignore_show_in {
+            None
+        } else {
+            Some(self.current_desktops())
+        };
+        self.tasks.spawn_task(TaskKind::Scan, move |sink| {
+            let locales = get_languages_from_env();
+            let previous = if use_cache { EntryCache::load(&locales) } else { EntryCache::empty(&locales) };
+            let mut cache = EntryCache::empty(&locales);
+            let mut desktop_entries = Vec::new();
+            for dir in default_paths() {
+                let scanned = scan_desktop_dir(dir, &locales, &previous, &mut cache);
+                desktop_entries.extend(scanned.iter().cloned());
+
+                let mut batch = Vec::new();
+                let mut excluded = Vec::new();
+                for entry in scanned {
+                    match desktops.as_deref().and_then(|desktops| show_in_exclusion(&entry, desktops)) {
+                        Some(reason) => excluded.push((entry, reason)),
+                        None => batch.push(entry),
+                    }
+                }
+
+                if !excluded.is_empty() && !sink.post(TaskOutput::Excluded(excluded)) {
+                    return;
+                }
+                if !batch.is_empty() && !sink.post(TaskOutput::Entries(batch)) {
+                    return;
+                }
+            }
+
+            if use_cache {
+                cache.save();
+            }
+
+            if include_path_binaries {
+                sink.post(TaskOutput::Entries(scan_path_binaries(&desktop_entries)));
+            }
+        });
+    }
+
+    // `general.desktop-environment` wins over `$XDG_CURRENT_DESKTOP`; both are `:`-separated.
+    fn current_desktops(&self) -> Vec<String> {
+        let value = match &self.config.general.desktop_environment {
+            Some(value) => value.clone(),
+            None => std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default(),
+        };
+        value
+            .split(':')
+            .map(|desktop| desktop.trim().to_lowercase())
+            .filter(|desktop| !desktop.is_empty())
+            .collect()
+    }
+
+    fn merge_entries(&mut self, batch: Vec<AppEntry>) {
+        let show_duplicates = self.config.features.show_duplicates;
+        let mut by_name: HashMap<String, usize> = HashMap::new();
+        if !show_duplicates {
+            for (idx, entry) in self.entries.iter().enumerate() {
+                by_name.insert(entry.name.to_lowercase(), idx);
+            }
+        }
+
+        for mut entry in batch {
+            if self.config.features.transliterate_search {
+                entry.folded_name = translit::fold(&entry.name);
+            }
+
+            if show_duplicates {
+                self.entries.push(entry);
+                continue;
+            }
+
+            let key = entry.name.to_lowercase();
+            match by_name.get(&key) {
+                Some(&idx) => {
+                    // A desktop entry wins over a PATH binary of the same name; anything else keeps the first seen.
+                    if self.entries[idx].kind == EntryKind::Binary && entry.kind == EntryKind::Desktop {
+                        self.entries[idx] = entry;
+                    }
+                }
+                None => {
+                    by_name.insert(key, self.entries.len());
+                    self.entries.push(entry);
+                }
+            }
+        }
+
+        self.sort_entries();
+        // Sorting moved entries under the filtered indices, so they're rebuilt. The open-with
+        // picker indexes its own list.
+        match self.mode {
+            AppMode::AppSelection => {
+                let selected = self.selected_entry_name();
+                self.update_filter();
+                self.reselect_entry(selected);
+            }
+            AppMode::FileSelection if !self.filtered_entries.is_empty() => self.update_filter(),
+            _ => {}
+        }
+    }
+
+    // Swaps in a freshly loaded config, keeping the query and selection. Settings read during the
+    // app scan (PATH binaries, OnlyShowIn, duplicates) only change on the next start.
+    pub fn reload_config(&mut self) {
+        self.config_modified = AppConfig::modified();
+        let load_result = AppConfig::load();
+        if load_result.used_defaults {
+            let reason = load_result.warning.unwrap_or_default();
+            self.config_warning = Some(format!("Config not reloaded: {}", reason));
+            return;
+        }
+
+        let selected = self.selected_entry_name();
+        self.config = load_result.config;
+        self.qst_ascii = load_ascii(&self.config);
+        self.entries.retain(|entry| entry.kind != EntryKind::Power);
+        self.entries.extend(power_entries(&self.config));
+        let transliterate = self.config.features.transliterate_search;
+        for entry in &mut self.entries {
+            entry.folded_name = if transliterate { translit::fold(&entry.name) } else { None };
+        }
+        self.sort_entries();
+        self.update_filter();
+        self.reselect_entry(selected);
+        self.config_warning = load_result.warning;
+        self.status_message = Some("Reloaded config".to_string());
+    }
+
+    pub fn reload_config_if_changed(&mut self) -> bool {
+        let changed = self.config.features.auto_reload_config && AppConfig::modified() != self.config_modified;
+        if changed {
+            self.reload_config();
+        }
+        changed
+    }
+
+    // Sorting reorders `entries`, so a selection is carried across a re-sort by name.
+    fn selected_entry_name(&self) -> Option<String> {
+        self.list_state
+            .selected()
+            .and_then(|i| self.filtered_entry(i))
+            .map(|entry| entry.name.clone())
+    }
+
+    fn reselect_entry(&mut self, name: Option<String>) {
+        let Some(name) = name else {
+            return;
+        };
+        let row = self.filtered_rows().position(|entry| entry.name == name);
+        if row.is_some() {
+            self.list_state.select(row);
+        }
+    }
+
+    fn listed_entries(&self) -> &[AppEntry] {
+        match self.mode {
+            AppMode::OpenWith(_) => &self.open_with_apps,
+            _ => &self.entries,
+        }
+    }
+
+    // The entry shown in list row `row`.
+    pub fn filtered_entry(&self, row: usize) -> Option<&AppEntry> {
+        match (&self.url_entry, row) {
+            (Some(url), 0) => Some(url),
+            (Some(_), row) => self.filtered_entries.get(row - 1).and_then(|&idx| self.listed_entries().get(idx)),
+            (None, row) => self.filtered_entries.get(row).and_then(|&idx| self.listed_entries().get(idx)),
+        }
+    }
+
+    pub fn filtered_rows(&self) -> impl Iterator<Item = &AppEntry> {
+        let listed = self.listed_entries();
+        self.url_entry.iter().chain(self.filtered_entries.iter().filter_map(|&idx| listed.get(idx)))
+    }
+
+    pub fn filtered_len(&self) -> usize {
+        self.filtered_entries.len() + usize::from(self.url_entry.is_some())
+    }
+
+    fn clear_filtered(&mut self) {
+        self.filtered_entries.clear();
+        self.url_entry = None;
+    }
+
     fn char_count(input: &str) -> usize {
         input.chars().count()
     }
@@ -189,10 +764,41 @@ impl App {
         self.update_filter();
     }
 
+    // A bracketed paste arrives as one string and filters once, instead of once per char. Line
+    // breaks collapse to a space and other control characters are dropped, so a copied path with
+    // a trailing newline or a multi-line selection stays a single query.
+    pub fn paste_text(&mut self, text: &str) {
+        self.end_completion_cycle();
+        let text = text
+            .split(['\r', '\n'])
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace(|ch: char| ch.is_control(), "");
+        if !text.is_empty() {
+            self.insert_search_text(&text);
+        }
+    }
+
+    // For terminals without bracketed paste.
+    pub fn paste_from_clipboard(&mut self) {
+        match self.read_clipboard() {
+            Ok(text) => self.paste_text(&text),
+            Err(err) => self.set_status(format!("Paste failed: {}", err)),
+        }
+    }
+
     pub fn backspace_search_char(&mut self) {
         if self.search_cursor == 0 {
             return;
         }
+        if self.mode == AppMode::FileSelection
+            && self.config.features.backspace_path_components
+            && self.search_query.chars().nth(self.search_cursor - 1) == Some('/')
+        {
+            self.delete_path_component();
+            return;
+        }
 
         let end = Self::byte_index_at_char(&self.search_query, self.search_cursor);
         let start = Self::byte_index_at_char(&self.search_query, self.search_cursor - 1);
@@ -201,6 +807,26 @@ impl App {
         self.update_filter();
     }
 
+    // Deletes back to the `/` before the cursor's path component, so `~/src/qst/` becomes `~/src/`.
+    // Stops at the start of the word, leaving any app name and launch args before it alone.
+    pub fn delete_path_component(&mut self) {
+        let before: Vec<char> = self.search_query.chars().take(self.search_cursor).collect();
+        let word_start = before.iter().rposition(|&c| c == ' ').map_or(0, |idx| idx + 1);
+        let mut keep = before.len().saturating_sub(1);
+        while keep > word_start && before[keep - 1] != '/' {
+            keep -= 1;
+        }
+        let keep = keep.max(word_start);
+        if keep == before.len() {
+            return;
+        }
+        let start = Self::byte_index_at_char(&self.search_query, keep);
+        let end = Self::byte_index_at_char(&self.search_query, self.search_cursor);
+        self.search_query.replace_range(start..end, "");
+        self.search_cursor = keep;
+        self.update_filter();
+    }
+
     pub fn set_search_query(&mut self, query: String) {
         self.search_query = query;
         self.search_cursor = Self::char_count(&self.search_query);
@@ -265,10 +891,8 @@ impl App {
             };
 
             match key {
-                "display" => {
-                    if !value.is_empty() {
-                        meta.display = Some(value.to_string());
-                    }
+                "display" if !value.is_empty() => {
+                    meta.display = Some(value.to_string());
                 }
                 "meta" => {
                     meta.meta.extend(
@@ -348,14 +972,23 @@ impl App {
     }
 
     pub fn sort_entries(&mut self) {
+        // Every change to `entries` ends with a sort, which moves entries under the cached indices.
+        self.narrowing = None;
         let history = &self.history;
         let recent_first = self.config.features.recent_first;
 
         self.entries.sort_by(|a, b| {
-            let fav_a = history.is_favorite(&a.name);
-            let fav_b = history.is_favorite(&b.name);
-            if fav_a != fav_b {
-                return fav_b.cmp(&fav_a);
+            match (history.favorite_slot(&a.name), history.favorite_slot(&b.name)) {
+                (Some(slot_a), Some(slot_b)) => return slot_a.cmp(&slot_b),
+                (Some(_), None) => return std::cmp::Ordering::Less,
+                (None, Some(_)) => return std::cmp::Ordering::Greater,
+                (None, None) => {}
+            }
+
+            let missing_a = a.kind == EntryKind::Missing;
+            let missing_b = b.kind == EntryKind::Missing;
+            if missing_a != missing_b {
+                return missing_a.cmp(&missing_b);
             }
 
             if recent_first {
@@ -366,37 +999,124 @@ impl App {
                 }
             }
 
+            let bin_a = a.kind == EntryKind::Binary;
+            let bin_b = b.kind == EntryKind::Binary;
+            if bin_a != bin_b {
+                return bin_a.cmp(&bin_b);
+            }
+
             a.name.to_lowercase().cmp(&b.name.to_lowercase())
                 .then_with(|| a.name.cmp(&b.name))
         });
     }
 
     pub fn toggle_favorite(&mut self) {
-        if self.mode == AppMode::AppSelection {
-            if let Some(i) = self.list_state.selected() {
-                if let Some(entry) = self.filtered_entries.get(i).cloned() {
-                    self.history.toggle_favorite(&entry.name);
-                    self.sort_entries();
-                    self.update_filter();
-                }
+        if self.mode == AppMode::AppSelection
+            && let Some(i) = self.list_state.selected()
+            && let Some(entry) = self.filtered_entry(i).cloned()
+        {
+            if entry.kind == EntryKind::Url {
+                return;
             }
+            self.history.toggle_favorite(&entry.name);
+            if entry.kind == EntryKind::Missing {
+                self.entries
+                    .retain(|e| !(e.kind == EntryKind::Missing && e.name == entry.name));
+                self.favorite_report.missing.retain(|(name, _)| name != &entry.name);
+            }
+            self.sort_entries();
+            self.update_filter();
+            self.reselect_entry(Some(entry.name));
+        }
+    }
+
+    pub fn move_favorite(&mut self, delta: isize) {
+        if self.mode != AppMode::AppSelection {
+            return;
+        }
+        let Some(name) = self.selected_entry_name() else {
+            return;
+        };
+        if self.history.move_favorite(&name, delta) {
+            self.sort_entries();
+            self.update_filter();
+            self.reselect_entry(Some(name));
         }
     }
 
+    // `slot` counts from 1, like the numbers shown with `general.numbered-favorites`.
+    pub fn launch_favorite(&mut self, slot: usize) {
+        let Some(name) = slot.checked_sub(1).and_then(|idx| self.history.favorites.get(idx)).cloned() else {
+            return;
+        };
+        let Some(entry) = self.entries.iter().find(|entry| entry.name == name).cloned() else {
+            return;
+        };
+        self.settle_tasks();
+        self.launch_entry(entry, None);
+    }
 
     pub fn update_filter(&mut self) {
+        let selected = self.moved_selection();
+        let mode = self.mode.clone();
+        self.refilter();
+        if self.mode == mode {
+            self.restore_selection(selected);
+        }
+        // Marks are paths, so they outlast moving between directories but not the file list.
+        if self.mode != AppMode::FileSelection {
+            self.marked_files.clear();
+        }
+    }
+
+    // What a selection moved off the first row points at, so refiltering can keep it while it
+    // still matches. An untouched selection follows the best match instead, so typing and
+    // pressing Enter keeps launching the top result.
+    fn moved_selection(&self) -> Option<String> {
+        let row = self.list_state.selected().filter(|&row| row > 0)?;
+        match self.mode {
+            AppMode::FileSelection => self.filtered_files.get(row).map(|file| file.path.clone()),
+            AppMode::AppSelection | AppMode::OpenWith(_) => self.filtered_entry(row).map(|entry| entry.name.clone()),
+            AppMode::ScriptResults => None,
+        }
+    }
+
+    fn restore_selection(&mut self, selected: Option<String>) {
+        match self.mode {
+            AppMode::FileSelection => {
+                let row = selected.and_then(|path| self.filtered_files.iter().position(|file| file.path == path));
+                if row.is_some() {
+                    self.list_state.select(row);
+                }
+            }
+            AppMode::AppSelection | AppMode::OpenWith(_) => self.reselect_entry(selected),
+            AppMode::ScriptResults => {}
+        }
+    }
+
+    fn refilter(&mut self) {
+        self.pending_confirm = None;
+        if matches!(self.mode, AppMode::OpenWith(_)) {
+            self.filter_open_with_apps();
+            return;
+        }
         self.launch_args = None;
         self.mode = AppMode::AppSelection;
-        self.filtered_files.clear();
-        self.script_title = None;
-        self.script_items.clear();
+        self.url_entry = None;
+        self.files_omitted = 0;
 
         let query_slice_str = self.search_query.trim().to_string();
         let query_slice = query_slice_str.as_str();
 
-        if self.try_run_script_query(query_slice) {
-            let count = self.script_items.len();
-            if count == 0 {
+        if self.try_clipboard_query(query_slice)
+            || self.try_kill_query(query_slice)
+            || self.try_window_query(query_slice)
+            || self.try_emoji_query(query_slice)
+            || self.try_run_script_query(query_slice)
+        {
+            self.tasks.cancel(TaskKind::Files);
+            self.filtered_files.clear();
+            if self.script_items.is_empty() {
                 self.list_state.select(None);
             } else {
                 self.list_state.select(self.first_selectable_script_index());
@@ -404,64 +1124,85 @@ impl App {
             return;
         }
 
+        self.tasks.cancel(TaskKind::Script);
+        self.tasks.cancel(TaskKind::Clipboard);
+        self.clipboard_history = None;
+        self.processes = None;
+        self.windows = None;
+        self.script_title = None;
+        self.script_items.clear();
+
+        if self.config.features.enable_file_explorer && self.try_recent_files_query(query_slice) {
+            return;
+        }
+        self.recent_files = None;
+
+        if self.config.features.enable_file_explorer && query_slice == BOOKMARK_PREFIX {
+            self.tasks.cancel(TaskKind::Files);
+            self.clear_filtered();
+            self.filtered_files = self
+                .bookmarks()
+                .into_iter()
+                .map(|(name, target)| {
+                    file_entry(Path::new(&Self::expand_path(&target)), format!("{}{}", BOOKMARK_PREFIX, name))
+                })
+                .collect();
+            self.mode = AppMode::FileSelection;
+            self.list_state.select(if self.filtered_files.is_empty() { None } else { Some(0) });
+            return;
+        }
+
+        let query_slice_str = self.expand_query(query_slice);
+        let query_slice = query_slice_str.as_str();
+
         if self.config.features.enable_file_explorer && Self::looks_like_path_query(query_slice) {
-            let files = self.list_completions(query_slice);
-            self.filtered_entries.clear();
-            self.filtered_files = files;
+            self.request_file_listing(query_slice);
+            self.clear_filtered();
             self.mode = AppMode::FileSelection;
+        } else {
+            self.tasks.cancel(TaskKind::Files);
+            self.filtered_files.clear();
+            // Read afresh next time the explorer opens.
+            self.dir_listing = None;
+        }
+
+        if self.mode != AppMode::FileSelection
+            && let Some(rest) = query_slice.strip_prefix(HIDDEN_PREFIX)
+        {
+            let matches = self.match_entries(rest.trim(), MatchTier::Full, true);
+            self.set_filtered(matches);
+            self.list_state.select(if self.filtered_entries.is_empty() { None } else { Some(0) });
+            return;
         }
 
         if self.mode != AppMode::FileSelection && query_slice.is_empty() {
-            self.filtered_entries = self.entries.clone();
+            let listed = (0..self.entries.len()).filter(|&idx| !self.is_hidden(&self.entries[idx]));
+            self.filtered_entries = listed.collect();
+            self.filtered_matches = vec![EntryMatch::unfiltered(); self.filtered_entries.len()];
         } else if self.mode != AppMode::FileSelection {
-            let query = query_slice.to_lowercase();
-            let mut matches: Vec<(i64, AppEntry)> = self
-                .entries
-                .iter()
-                .filter_map(|e| {
-                    fuzzy_score(&query, &e.name).map(|score| (score, e.clone()))
-                })
-                .collect();
-
-            matches.sort_by(|a, b| b.0.cmp(&a.0));
-
-            let matches: Vec<AppEntry> = matches.into_iter().map(|(_, e)| e).collect();
+            let matches = self.match_narrowing(query_slice);
 
             if !matches.is_empty() {
-                self.filtered_entries = matches;
+                self.set_filtered(matches);
             } else {
-                let words: Vec<&str> = query_slice.split_whitespace().collect();
+                let words = split_args(query_slice);
                 let mut found = false;
 
                 for i in (1..words.len()).rev() {
                     let sub_query = words[0..i].join(" ");
-                    let sub_query_lower = sub_query.to_lowercase();
-
-                    let mut sub_matches: Vec<(i64, AppEntry)> = self
-                        .entries
-                        .iter()
-                        .filter_map(|e| {
-                            fuzzy_score(&sub_query_lower, &e.name).map(|score| (score, e.clone()))
-                        })
-                        .collect();
-
-                    sub_matches.sort_by(|a, b| b.0.cmp(&a.0));
-
-                    let sub_matches: Vec<AppEntry> = sub_matches.into_iter().map(|(_, e)| e).collect();
+                    let sub_matches = self.match_entries(&sub_query, MatchTier::Partial, false);
 
                     if !sub_matches.is_empty() {
-                        self.filtered_entries = sub_matches;
+                        self.set_filtered(sub_matches);
                         
                         if self.config.features.enable_launch_args {
-                            let args: Vec<String> = words[i..].iter().map(|s| s.to_string()).collect();
-                            if let Some(last_arg) = args.last() {
-                                if !last_arg.starts_with('-') && Self::looks_like_path_query(last_arg) {
-                                    let files = self.list_completions(last_arg);
-                                    if !files.is_empty() && self.config.features.enable_file_explorer {
-                                        self.filtered_files = files;
-                                        self.mode = AppMode::FileSelection;
-                                    }
-                                }
+                            let args = words[i..].to_vec();
+                            if let Some(last_arg) = args.last()
+                                && !last_arg.starts_with('-')
+                                && Self::looks_like_path_query(last_arg)
+                                && self.config.features.enable_file_explorer
+                            {
+                                self.request_arg_completions(last_arg);
                             }
                             self.launch_args = Some(args);
                         }
@@ -472,13 +1213,25 @@ impl App {
                 }
 
                 if !found {
-                    self.filtered_entries = Vec::new();
+                    self.clear_filtered();
+                    self.filtered_matches.clear();
                 }
             }
+        } else {
+            self.filtered_matches.clear();
+        }
+
+        if self.mode == AppMode::AppSelection
+            && self.config.features.url_open
+            && let Some(url) = url_from_query(query_slice)
+        {
+            let label = format!("Open in browser: {}", url);
+            self.url_entry = Some(AppEntry::new(label, vec![url], EntryKind::Url));
+            self.filtered_matches.insert(0, EntryMatch::unfiltered());
         }
         
         let count = match self.mode {
-            AppMode::AppSelection => self.filtered_entries.len(),
+            AppMode::AppSelection | AppMode::OpenWith(_) => self.filtered_len(),
             AppMode::FileSelection => self.filtered_files.len(),
             AppMode::ScriptResults => self.script_items.len(),
         };
@@ -490,24 +1243,605 @@ impl App {
         }
     }
 
-    pub fn move_selection(&mut self, delta: i32) {
-        let len = match self.mode {
-            AppMode::AppSelection => self.filtered_entries.len(),
-            AppMode::FileSelection => self.filtered_files.len(),
-            AppMode::ScriptResults => self.script_items.len(),
+    // Hidden entries only ever show up in the `hidden:` view, and nothing else does there.
+    // Matching entries as indices into `entries`, best first.
+    fn match_entries(&self, query: &str, tier: MatchTier, hidden: bool) -> Vec<(EntryMatch, usize)> {
+        self.match_candidates(query, tier, hidden, 0..self.entries.len())
+    }
+
+    // A full-tier match that starts from the previous query's matches when the query only grew
+    // at the end, and from every entry otherwise. Transliterated matching can gain entries as the
+    // query grows ("p" sounds unlike "ph"), so it always starts from every entry.
+    fn match_narrowing(&mut self, query: &str) -> Vec<(EntryMatch, usize)> {
+        let stable = !self.config.features.transliterate_search;
+        let matches = match self.narrowing.take() {
+            Some((previous, candidates)) if stable && query.starts_with(&previous) => {
+                self.match_candidates(query, MatchTier::Full, false, candidates.into_iter())
+            }
+            _ => self.match_entries(query, MatchTier::Full, false),
         };
+        let mut matched: Vec<usize> = matches.iter().map(|&(_, idx)| idx).collect();
+        matched.sort_unstable();
+        self.narrowing = Some((query.to_string(), matched));
+        matches
+    }
 
-        if len == 0 {
-            return;
-        }
+    // `candidates` must be in `entries` order so that equal scores keep the same order.
+    fn match_candidates(
+        &self,
+        query: &str,
+        tier: MatchTier,
+        hidden: bool,
+        candidates: impl Iterator<Item = usize>,
+    ) -> Vec<(EntryMatch, usize)> {
+        let query = query.to_lowercase();
+        let folded_query = self.fold_query(&query);
+        let search_keywords = self.config.features.search_keywords;
+        let mut matches: Vec<(EntryMatch, usize)> = candidates
+            .filter(|&idx| self.is_hidden(&self.entries[idx]) == hidden)
+            .filter_map(|idx| {
+                match_entry(&query, folded_query.as_deref(), search_keywords, &self.entries[idx])
+                    .map(|(score, field)| (EntryMatch { tier, field, score }, idx))
+            })
+            .collect();
 
-        if self.mode == AppMode::ScriptResults {
-            let start = match self.list_state.selected() {
-                Some(i) => i,
-                None => {
-                    if delta >= 0 {
-                        len - 1
-                    } else {
+        matches.sort_by_key(|m| (std::cmp::Reverse(m.0.score), self.entries[m.1].kind == EntryKind::Binary));
+        matches
+    }
+
+    fn is_excluded(&self, entry: &AppEntry) -> bool {
+        self.config
+            .features
+            .exclude
+            .iter()
+            .any(|pattern| glob_match(&pattern.to_lowercase(), &entry.name.to_lowercase()))
+    }
+
+    fn is_hidden(&self, entry: &AppEntry) -> bool {
+        self.history.is_hidden(&entry.name) || self.is_excluded(entry)
+    }
+
+    // Entries an empty query would list, for the match count in the list title.
+    pub fn listed_entry_count(&self) -> usize {
+        self.entries.iter().filter(|entry| !self.is_hidden(entry)).count()
+    }
+
+    // Rows for the help overlay, read from the live config so rebound keys and prefixes show up
+    // as configured. The fallbacks match the ones `handle_key` uses for unset bindings.
+    pub fn help_rows(&self) -> Vec<(String, String)> {
+        let general = &self.config.general;
+        let key = |binding: &Option<String>, fallback: &str| binding.as_deref().unwrap_or(fallback).to_string();
+        let mut rows = vec![
+            ("enter".to_string(), "Launch or open the selection".to_string()),
+            ("esc".to_string(), "Quit".to_string()),
+            ("tab".to_string(), "Complete the query".to_string()),
+            ("up / down".to_string(), "Move the selection".to_string()),
+            ("pageup / pagedown".to_string(), "Move a page".to_string()),
+            (key(&general.half_page_up_key, "ctrl+u"), "Move half a page up".to_string()),
+            (key(&general.half_page_down_key, "ctrl+d"), "Move half a page down".to_string()),
+            (key(&general.jump_to_top_key, "alt+up"), "Jump to the top".to_string()),
+            (key(&general.jump_to_bottom_key, "alt+down"), "Jump to the bottom".to_string()),
+            (key(&general.favorite_key, "alt+f"), "Toggle favorite".to_string()),
+            (key(&general.favorite_up_key, "alt+shift+up"), "Move favorite up".to_string()),
+            (key(&general.favorite_down_key, "alt+shift+down"), "Move favorite down".to_string()),
+            ("alt+1..9".to_string(), "Launch favorite by slot".to_string()),
+            (key(&general.hide_key, "alt+h"), "Hide or unhide app".to_string()),
+            (key(&general.copy_key, "ctrl+y"), "Copy the selection".to_string()),
+            (key(&general.keep_open_key, "alt+enter"), "Launch and stay open".to_string()),
+            (key(&general.print_key, "ctrl+p"), "Print the command and exit".to_string()),
+            (key(&general.clear_history_key, "alt+c"), "Clear usage history (press twice)".to_string()),
+            (key(&general.window_switcher_key, "alt+w"), "Switch windows".to_string()),
+            (key(&general.reload_config_key, "ctrl+r"), "Reload the config".to_string()),
+            (key(&general.dismiss_warning_key, "alt+x"), "Dismiss the config warning".to_string()),
+            (key(&general.paste_key, "ctrl+v"), "Paste the clipboard".to_string()),
+        ];
+        if self.config.features.enable_file_explorer {
+            rows.extend([
+                (key(&general.file_sort_key, "ctrl+s"), "Cycle file sort".to_string()),
+                (key(&general.hidden_files_key, "ctrl+h"), "Toggle hidden files".to_string()),
+                (key(&general.bookmark_key, "alt+b"), "Bookmark the directory".to_string()),
+                (key(&general.open_with_key, "ctrl+o"), "Open with…".to_string()),
+                (key(&general.delete_component_key, "ctrl+backspace"), "Delete a path component".to_string()),
+                (key(&general.parent_dir_key, "alt+left"), "Go to the parent directory".to_string()),
+                (key(&general.mark_key, "ctrl+space"), "Mark the file for Enter".to_string()),
+                (key(&general.recursive_key, "alt+r"), "Search subdirectories too".to_string()),
+                (key(&general.open_dir_key, "ctrl+enter"), "Open the directory instead of browsing".to_string()),
+                (key(&general.run_detached_key, "shift+enter"), "Run the script without a terminal".to_string()),
+            ]);
+        }
+        if !self.config.kill.prefix.is_empty() {
+            rows.extend([
+                (key(&general.force_kill_key, "alt+enter"), "Force kill (SIGKILL)".to_string()),
+                (key(&general.process_refresh_key, "ctrl+r"), "Refresh processes".to_string()),
+            ]);
+        }
+        if self.debug {
+            rows.push((key(&general.debug_overlay_key, "f12"), "Toggle match diagnostics".to_string()));
+        }
+        rows.push((key(&general.help_key, "f1"), "Toggle this help".to_string()));
+
+        if self.config.features.enable_file_explorer {
+            rows.extend([
+                ("/".to_string(), "Browse files from the root".to_string()),
+                ("~".to_string(), "Browse files from home".to_string()),
+                (BOOKMARK_PREFIX.to_string(), "Bookmarked directories".to_string()),
+                (RECENT_PREFIX.to_string(), "Recent files".to_string()),
+            ]);
+        }
+        rows.push((HIDDEN_PREFIX.to_string(), "Hidden apps".to_string()));
+        let prefixes = [
+            (&self.config.clipboard.prefix, "Clipboard history"),
+            (&self.config.emoji.prefix, "Emoji and symbols"),
+            (&self.config.kill.prefix, "Kill a process"),
+            (&self.config.windows.prefix, "Open windows"),
+        ];
+        for (prefix, description) in prefixes {
+            if !prefix.is_empty() {
+                rows.push((prefix.clone(), description.to_string()));
+            }
+        }
+        rows
+    }
+
+    pub fn toggle_hidden(&mut self) {
+        if self.mode != AppMode::AppSelection {
+            return;
+        }
+        let Some(entry) = self.list_state.selected().and_then(|i| self.filtered_entry(i)).cloned() else {
+            return;
+        };
+        if entry.kind == EntryKind::Url {
+            return;
+        }
+
+        if self.is_excluded(&entry) && !self.history.is_hidden(&entry.name) {
+            self.status_message = Some(format!("{} is excluded by features.exclude", entry.name));
+            return;
+        }
+
+        let hidden = self.history.toggle_hidden(&entry.name);
+        self.narrowing = None;
+        self.status_message = Some(if hidden {
+            format!("Hid {} (type {} to see hidden apps)", entry.name, HIDDEN_PREFIX)
+        } else {
+            format!("Unhid {}", entry.name)
+        });
+        self.update_filter();
+    }
+
+    fn set_filtered(&mut self, matches: Vec<(EntryMatch, usize)>) {
+        let (matches, entries) = matches.into_iter().unzip();
+        self.filtered_matches = matches;
+        self.filtered_entries = entries;
+    }
+
+    fn fold_query(&self, query: &str) -> Option<String> {
+        if self.config.features.transliterate_search {
+            translit::fold(query)
+        } else {
+            None
+        }
+    }
+
+    // Typing more of a name in the directory read last only re-filters the cached listing; a
+    // different directory is read in the background and filtered once it arrives.
+    fn request_file_listing(&mut self, query_path: &str) {
+        let query = FileQuery::parse(query_path);
+        let dirs_first = self.config.features.dirs_first;
+        let sort = self.file_sort;
+        let show_hidden = self.show_hidden;
+        if query.is_recursive() {
+            let query_path = query_path.to_string();
+            let features = self.config.features.clone();
+            self.tasks.spawn_task(TaskKind::Files, move |sink| {
+                sink.post(TaskOutput::Files(Self::list_completions(&query_path, &features, sort, show_hidden)));
+            });
+            return;
+        }
+        match &self.dir_listing {
+            Some(listing) if listing.is_current(&query.dir) => {
+                let mut files = query.filter(&listing.files, show_hidden);
+                sort_files(&mut files, sort, dirs_first);
+                self.tasks.cancel(TaskKind::Files);
+                self.show_files(files);
+            }
+            _ => {
+                let dir = query.dir;
+                self.tasks.spawn_task(TaskKind::Files, move |sink| {
+                    let modified = dir_modified(&dir);
+                    let files = read_listing(&dir);
+                    sink.post(TaskOutput::Listing(dir, modified, files));
+                });
+            }
+        }
+    }
+
+    // A path typed as a launch arg lists its completions once they arrive, switching to the file
+    // list only if there are any.
+    fn request_arg_completions(&mut self, query_path: &str) {
+        let query_path = query_path.to_string();
+        let features = self.config.features.clone();
+        let (sort, show_hidden) = (self.file_sort, self.show_hidden);
+        self.tasks.spawn_task(TaskKind::Files, move |sink| {
+            sink.post(TaskOutput::Files(Self::list_completions(&query_path, &features, sort, show_hidden)));
+        });
+    }
+
+    fn show_files(&mut self, mut files: Vec<FileEntry>) {
+        self.files_omitted = cap_files(&mut files, self.config.features.max_file_results);
+        self.filtered_files = files;
+        self.preview_cache.clear();
+        if self.filtered_files.is_empty() {
+            self.list_state.select(None);
+        } else {
+            self.list_state.select(Some(0));
+        }
+    }
+
+    // Bookmarks from `[bookmarks]` in config.toml win over ones added with the bookmark key.
+    pub fn bookmarks(&self) -> BTreeMap<String, String> {
+        let mut bookmarks = self.history.bookmarks.clone();
+        bookmarks.extend(self.config.bookmarks.clone());
+        bookmarks
+    }
+
+    // Bookmarks, then `$VAR`/`${VAR}` when `features.expand-env-vars` is on.
+    fn expand_query(&self, query: &str) -> String {
+        let query = self.expand_bookmarks(query);
+        if self.config.features.expand_env_vars {
+            expand_env(&query, self.config.features.undefined_env_vars)
+        } else {
+            query
+        }
+    }
+
+    // Rewrites every `@name` or `@name/rest` word whose name is a bookmark into its target path.
+    fn expand_bookmarks(&self, query: &str) -> String {
+        if !query.contains(BOOKMARK_PREFIX) {
+            return query.to_string();
+        }
+        let bookmarks = self.bookmarks();
+        query
+            .split(' ')
+            .map(|word| {
+                let Some(rest) = word.strip_prefix(BOOKMARK_PREFIX) else {
+                    return word.to_string();
+                };
+                let (name, tail) = rest.split_once('/').map(|(name, tail)| (name, Some(tail))).unwrap_or((rest, None));
+                match bookmarks.get(name) {
+                    Some(target) => format!("{}/{}", target.trim_end_matches('/'), tail.unwrap_or_default()),
+                    None => word.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    // Rewrites the listed directory to its parent, so both `~/src/qst/` and `~/src/qst/ma` become
+    // `~/src/`. `~` goes up to the home directory's parent; nothing happens at `/`.
+    pub fn open_parent_dir(&mut self) {
+        let Some(query_path) = self.current_file_query_path() else {
+            return;
+        };
+        let dir = if query_path.ends_with('/') || Path::new(&Self::expand_path(&query_path)).is_dir() {
+            query_path.trim_end_matches('/')
+        } else {
+            query_path.rsplit_once('/').map(|(head, _)| head).unwrap_or_default()
+        };
+        let parent = match dir.rsplit_once('/') {
+            _ if dir == "." => "../".to_string(),
+            _ if dir == ".." || dir.ends_with("/..") => format!("{}/../", dir),
+            Some(("", _)) => "/".to_string(),
+            Some((head, _)) => format!("{}/", head),
+            None if dir == "~" => match Path::new(&Self::expand_path(dir)).parent() {
+                Some(parent) => format!("{}/", parent.to_string_lossy().trim_end_matches('/')),
+                None => return,
+            },
+            None => return,
+        };
+
+        let prefix = self.search_query.rfind(' ').map(|idx| &self.search_query[..=idx]).unwrap_or("");
+        let query = format!("{}{}", prefix, parent);
+        self.set_search_query(query);
+        self.update_filter();
+    }
+
+    // `~/src/ma` becomes `~/src/**/ma` and back.
+    pub fn toggle_recursive_search(&mut self) {
+        let Some(query_path) = self.current_file_query_path() else {
+            return;
+        };
+        let toggled = if let Some(idx) = query_path.rfind("**/") {
+            format!("{}{}", &query_path[..idx], &query_path[idx + 3..])
+        } else if query_path.ends_with('/') || Path::new(&Self::expand_path(&query_path)).is_dir() {
+            format!("{}/**/", query_path.trim_end_matches('/'))
+        } else {
+            match query_path.rsplit_once('/') {
+                Some((head, name)) => format!("{}/**/{}", head, name),
+                None => format!("**/{}", query_path),
+            }
+        };
+        let prefix = self.search_query.rfind(' ').map(|idx| &self.search_query[..=idx]).unwrap_or("");
+        let query = format!("{}{}", prefix, toggled);
+        self.set_search_query(query);
+        self.update_filter();
+    }
+
+    pub fn bookmark_current_dir(&mut self) {
+        let Some(query_path) = self.current_file_query_path() else {
+            return;
+        };
+        let dir = if query_path.ends_with('/') || Path::new(&Self::expand_path(&query_path)).is_dir() {
+            query_path.trim_end_matches('/').to_string()
+        } else {
+            query_path.rsplit_once('/').map(|(head, _)| head.to_string()).unwrap_or_default()
+        };
+        let dir = if dir.is_empty() { "/".to_string() } else { dir };
+
+        let bookmarks = self.bookmarks();
+        if let Some((name, _)) = bookmarks.iter().find(|(_, target)| target.trim_end_matches('/') == dir) {
+            self.status_message = Some(format!("{} is already bookmarked as {}{}", dir, BOOKMARK_PREFIX, name));
+            return;
+        }
+
+        let base: String = Path::new(&dir)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("root")
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .take(3)
+            .collect();
+        let base = if base.is_empty() { "dir".to_string() } else { base };
+        let name = (1..)
+            .map(|n| if n == 1 { base.clone() } else { format!("{}{}", base, n) })
+            .find(|name| !bookmarks.contains_key(name))
+            .unwrap_or(base);
+
+        self.history.add_bookmark(&name, &dir);
+        self.status_message = Some(format!("Bookmarked {} as {}{}", dir, BOOKMARK_PREFIX, name));
+    }
+
+    // Copies what Enter would act on: the Exec command line of the selected app, the absolute
+    // path of the selected file, or the value of the selected script row.
+    pub fn copy_selected(&mut self) {
+        let Some(i) = self.list_state.selected() else {
+            return;
+        };
+        let text = match &self.mode {
+            AppMode::AppSelection | AppMode::OpenWith(_) => {
+                self.filtered_entry(i).and_then(|entry| match entry.kind {
+                    EntryKind::Missing => None,
+                    EntryKind::Url | EntryKind::Power => entry.exec_args.first().cloned(),
+                    _ => {
+                        let embedded_codes = entry.kind == EntryKind::Desktop;
+                        Some(shell_words(&substitute_file_codes(&entry.exec_args, None, embedded_codes)))
+                    }
+                })
+            }
+            AppMode::FileSelection => self.filtered_files.get(i).map(|file| {
+                let expanded = Self::expand_path(&self.expand_query(&file.path));
+                match std::env::current_dir() {
+                    Ok(cwd) if Path::new(&expanded).is_relative() => {
+                        cwd.join(&expanded).to_string_lossy().into_owned()
+                    }
+                    _ => expanded,
+                }
+            }),
+            AppMode::ScriptResults => self.script_items.get(i).map(|item| item.value.clone()),
+        };
+        let Some(text) = text.filter(|text| !text.is_empty()) else {
+            return;
+        };
+
+        self.status_message = Some(match self.copy_to_clipboard(&text) {
+            Ok(command) => format!("Copied with {}: {}", command.split_whitespace().next().unwrap_or_default(), text),
+            Err(err) => format!("Clipboard failed: {}", err),
+        });
+    }
+
+    pub fn toggle_hidden_files(&mut self) {
+        self.show_hidden = !self.show_hidden;
+        self.status_message = Some(if self.show_hidden {
+            "Showing hidden files".to_string()
+        } else {
+            "Hiding hidden files".to_string()
+        });
+        self.update_filter();
+    }
+
+    pub fn cycle_file_sort(&mut self) {
+        self.file_sort = self.file_sort.next();
+        self.history.set_file_sort(self.file_sort);
+        sort_files(&mut self.filtered_files, self.file_sort, self.config.features.dirs_first);
+        self.preview_cache.clear();
+        if !self.filtered_files.is_empty() {
+            self.list_state.select(Some(0));
+        }
+        self.status_message = Some(format!("Sorting files by {}", self.file_sort.label()));
+    }
+
+    // Returns whether anything on screen may have changed: a task posted output, a watched launch
+    // finished or favorites were just reconciled.
+    pub fn poll_tasks(&mut self) -> bool {
+        let outputs = self.tasks.drain();
+        let posted = !outputs.is_empty();
+        for output in outputs {
+            self.apply_task_output(output);
+        }
+        self.children.retain_mut(|child| matches!(child.try_wait(), Ok(None)));
+        let (watched, reconciled) = (self.watched_launches.len(), self.favorites_reconciled);
+        self.poll_watched_launches();
+        self.reconcile_favorites();
+        posted || self.watched_launches.len() != watched || self.favorites_reconciled != reconciled
+    }
+
+    pub fn settle_tasks(&mut self) {
+        for kind in [TaskKind::Script, TaskKind::Files, TaskKind::Clipboard] {
+            for output in self.tasks.wait(kind) {
+                self.apply_task_output(output);
+            }
+        }
+        self.reconcile_favorites();
+    }
+
+    pub fn wait_for_scan(&mut self) {
+        for output in self.tasks.wait(TaskKind::Scan) {
+            self.apply_task_output(output);
+        }
+        self.reconcile_favorites();
+    }
+
+    // Runs once, after the first full scan, so favorites are only judged against complete results.
+    fn reconcile_favorites(&mut self) {
+        if self.favorites_reconciled || self.tasks.is_pending(TaskKind::Scan) {
+            return;
+        }
+        self.favorites_reconciled = true;
+        self.check_app_overrides();
+        let features = &self.config.features;
+        if features.history_max_entries > 0 || features.history_max_age_days > 0 {
+            let installed = self.entries.iter().map(|entry| entry.name.as_str()).collect();
+            let (max_entries, max_age_days) = (features.history_max_entries, features.history_max_age_days);
+            self.history.prune(&installed, self.clock.unix_secs(), max_entries, max_age_days);
+        }
+        self.favorite_report = self.history.reconcile_favorites(&self.entries, self.clock.unix_secs());
+
+        if self.config.features.show_missing_favorites && !self.favorite_report.missing.is_empty() {
+            let missing = self
+                .favorite_report
+                .missing
+                .iter()
+                .map(|(name, _)| AppEntry::new(name.clone(), Vec::new(), EntryKind::Missing))
+                .collect();
+            self.merge_entries(missing);
+        } else if !self.favorite_report.reattached.is_empty() {
+            self.merge_entries(Vec::new());
+        }
+    }
+
+    fn apply_task_output(&mut self, output: TaskOutput) {
+        match output {
+            TaskOutput::Entries(batch) => self.merge_entries(batch),
+            TaskOutput::Excluded(excluded) => self.excluded_entries.extend(excluded),
+            // A Tab cycle keeps the files it started from until it ends; it lists afresh then.
+            TaskOutput::Files(_) if self.completion_cycle.is_some() => {}
+            TaskOutput::Files(files) => {
+                if self.mode == AppMode::AppSelection && self.launch_args.is_some() && !files.is_empty() {
+                    self.mode = AppMode::FileSelection;
+                    self.show_files(files);
+                    return;
+                }
+                if self.mode != AppMode::FileSelection {
+                    return;
+                }
+                let selected = self.moved_selection();
+                self.show_files(files);
+                self.restore_selection(selected);
+            }
+            TaskOutput::Listing(dir, modified, result) => {
+                if self.mode != AppMode::FileSelection {
+                    return;
+                }
+                // An unreadable directory is cached as empty so typing on doesn't retry it.
+                let (files, error) = match result {
+                    Ok(files) => (files, None),
+                    Err(error) => (Vec::new(), Some(error)),
+                };
+                self.dir_listing = Some(DirListing { dir, modified, files });
+                if self.completion_cycle.is_none() {
+                    self.update_filter();
+                }
+                if error.is_some() {
+                    self.status_message = error;
+                }
+            }
+            TaskOutput::Clipboard(result) => {
+                self.clipboard_history = Some(result);
+                if self.mode == AppMode::ScriptResults {
+                    self.update_filter();
+                }
+            }
+            TaskOutput::Script(script_id, result) => {
+                if self.mode != AppMode::ScriptResults {
+                    return;
+                }
+                match result {
+                    Ok((title, message, items)) => {
+                        self.script_title = title.or_else(|| Some(format!(" {} ", script_id)));
+                        self.script_items = items;
+                        self.status_message = message;
+                    }
+                    Err(err) => {
+                        self.script_title = Some(format!(" {} ", script_id));
+                        self.script_items = vec![ScriptItem {
+                            title: format!("Script error: {}", err),
+                            value: String::new(),
+                            action: ScriptAction::None,
+                            meta: ScriptRowMeta::default(),
+                        }];
+                    }
+                }
+                self.list_state.select(self.first_selectable_script_index());
+            }
+        }
+    }
+
+    // Built on first request per path and kept until the next listing arrives.
+    pub fn selected_preview(&mut self) -> Option<&[String]> {
+        let file = self.list_state.selected().and_then(|i| self.filtered_files.get(i))?.path.clone();
+        if !self.preview_cache.contains_key(&file) {
+            let lines = preview::build(Path::new(&Self::expand_path(&file)), &self.config.preview);
+            self.preview_cache.insert(file.clone(), lines);
+        }
+        self.preview_cache.get(&file).map(Vec::as_slice)
+    }
+
+    pub fn now(&self) -> SystemTime {
+        self.clock.now()
+    }
+
+    pub fn is_busy(&self) -> bool {
+        match self.mode {
+            AppMode::AppSelection => self.tasks.is_pending(TaskKind::Scan),
+            AppMode::OpenWith(_) => false,
+            AppMode::FileSelection => self.tasks.is_pending(TaskKind::Files),
+            AppMode::ScriptResults => {
+                self.tasks.is_pending(TaskKind::Script) || self.tasks.is_pending(TaskKind::Clipboard)
+            }
+        }
+    }
+
+    // The screen changes on its own while the spinner turns, so it is redrawn every tick; otherwise
+    // only after an event or a change from `poll_tasks`.
+    pub fn is_animating(&self) -> bool {
+        self.is_busy() || !self.watched_launches.is_empty()
+    }
+
+    pub fn spinner_frame(&self) -> &'static str {
+        let frame = (self.clock.elapsed().as_millis() / 80) as usize;
+        SPINNER_FRAMES[frame % SPINNER_FRAMES.len()]
+    }
+
+    pub fn move_selection(&mut self, delta: i32) {
+        let len = match self.mode {
+            AppMode::AppSelection | AppMode::OpenWith(_) => self.filtered_len(),
+            AppMode::FileSelection => self.filtered_files.len(),
+            AppMode::ScriptResults => self.script_items.len(),
+        };
+
+        if len == 0 {
+            return;
+        }
+
+        if self.mode == AppMode::ScriptResults {
+            let start = match self.list_state.selected() {
+                Some(i) => i,
+                None => {
+                    if delta >= 0 {
+                        len - 1
+                    } else {
                         0
                     }
                 }
@@ -536,9 +1870,79 @@ impl App {
         self.list_state.select(Some(i));
     }
 
+    // Index step of one row down on screen; the bottom layout draws the list upward.
+    pub fn downward(&self) -> i32 {
+        match self.config.general.layout {
+            ListLayout::Top => 1,
+            ListLayout::Bottom => -1,
+        }
+    }
+
+    // Columns the list is drawn in; only app lists use `general.columns`.
+    pub fn grid_columns(&self) -> usize {
+        match self.mode {
+            AppMode::AppSelection | AppMode::OpenWith(_) => self.config.general.columns.max(1),
+            AppMode::FileSelection | AppMode::ScriptResults => 1,
+        }
+    }
+
+    // Up/Down: one item in a single column, one grid row otherwise. Grid moves stop at the
+    // edges instead of wrapping, except that moving down onto a short last row picks its last item.
+    pub fn move_row(&mut self, delta: i32) {
+        let columns = self.grid_columns();
+        if columns == 1 {
+            self.move_selection(delta);
+            return;
+        }
+        let len = self.filtered_len();
+        let Some(current) = self.list_state.selected() else {
+            self.move_selection(delta);
+            return;
+        };
+        let target = current as i64 + delta as i64 * columns as i64;
+        if (0..len as i64).contains(&target) {
+            self.list_state.select(Some(target as usize));
+        } else if delta > 0 && current / columns < (len - 1) / columns {
+            self.list_state.select(Some(len - 1));
+        }
+    }
+
+    // Moves by whole or half pages of the last rendered list height, stopping at the ends. In a
+    // grid the height is in rows, so a page keeps the selection in its column.
+    pub fn move_page(&mut self, pages: f32) {
+        let len = match self.mode {
+            AppMode::AppSelection | AppMode::OpenWith(_) => self.filtered_len(),
+            AppMode::FileSelection => self.filtered_files.len(),
+            AppMode::ScriptResults => self.script_items.len(),
+        };
+        if len == 0 {
+            return;
+        }
+
+        let step = ((self.list_height.max(1) as f32 * pages.abs()).round() as usize).max(1) * self.grid_columns();
+        let current = self.list_state.selected().unwrap_or(0);
+        let mut target = if pages < 0.0 {
+            current.saturating_sub(step)
+        } else {
+            (current + step).min(len - 1)
+        };
+
+        if self.mode == AppMode::ScriptResults {
+            let forward = (target..len).find(|&i| self.script_item_is_selectable(i));
+            let backward = (0..=target).rev().find(|&i| self.script_item_is_selectable(i));
+            let nearest = if pages < 0.0 { backward.or(forward) } else { forward.or(backward) };
+            match nearest {
+                Some(i) => target = i,
+                None => return,
+            }
+        }
+
+        self.list_state.select(Some(target));
+    }
+
     pub fn select_first(&mut self) {
         let len = match self.mode {
-            AppMode::AppSelection => self.filtered_entries.len(),
+            AppMode::AppSelection | AppMode::OpenWith(_) => self.filtered_len(),
             AppMode::FileSelection => self.filtered_files.len(),
             AppMode::ScriptResults => self.script_items.len(),
         };
@@ -554,7 +1958,7 @@ impl App {
 
     pub fn select_last(&mut self) {
         let len = match self.mode {
-            AppMode::AppSelection => self.filtered_entries.len(),
+            AppMode::AppSelection | AppMode::OpenWith(_) => self.filtered_len(),
             AppMode::FileSelection => self.filtered_files.len(),
             AppMode::ScriptResults => self.script_items.len(),
         };
@@ -569,346 +1973,1028 @@ impl App {
     }
 
     pub fn auto_complete(&mut self) {
+        self.complete(1);
+    }
+
+    pub fn auto_complete_previous(&mut self) {
+        self.complete(-1);
+    }
+
+    // With several files listed, Tab fills in the selected one and further presses step through
+    // the rest without re-reading the list; a lone candidate is completed and listed into as before.
+    fn complete(&mut self, step: isize) {
         if !self.config.features.enable_auto_complete {
             return;
         }
+        self.settle_tasks();
         if self.mode == AppMode::FileSelection {
+            if let Some(cycle) = &mut self.completion_cycle {
+                cycle.index = (cycle.index as isize + step).rem_euclid(cycle.files.len() as isize) as usize;
+                self.fill_completion();
+                return;
+            }
             if let Some(i) = self.list_state.selected() {
+                // A glob stays as typed until the user moves onto one of its results.
+                let glob_query = self
+                    .current_file_query_path()
+                    .is_some_and(|path| is_glob_pattern(path.rsplit('/').next().unwrap_or_default()));
+                if glob_query && i == 0 && self.filtered_files.len() > 1 {
+                    return;
+                }
+                if self.filtered_files.len() > 1 {
+                    let prefix = self.search_query.rfind(' ').map(|idx| &self.search_query[..=idx]).unwrap_or("");
+                    let index = if step < 0 { i.checked_sub(1).unwrap_or(self.filtered_files.len() - 1) } else { i };
+                    self.completion_cycle =
+                        Some(CompletionCycle { prefix: prefix.to_string(), files: self.filtered_files.clone(), index });
+                    self.fill_completion();
+                    return;
+                }
                 if let Some(selected_file) = self.filtered_files.get(i) {
-                    let mut new_path = selected_file.clone();
-
-                    let expanded_path = self.expand_path(&new_path);
-                    if Path::new(&expanded_path).is_dir() && !new_path.ends_with('/') {
-                        new_path.push('/');
-                    }
-
-                    if let Some(last_space_idx) = self.search_query.rfind(' ') {
-                        let (prefix, _) = self.search_query.split_at(last_space_idx + 1);
-                        self.set_search_query(format!("{}{}", prefix, new_path));
-                    } else {
-                        self.set_search_query(new_path);
-                    }
-                    self.update_filter();
+                    self.complete_path(selected_file.path.clone());
                 }
             }
+        } else if self.mode == AppMode::AppSelection {
+            self.complete_app_name();
         }
     }
 
-    pub fn launch_selected(&mut self) {
-        if self.mode == AppMode::ScriptResults {
-            if let Some(i) = self.list_state.selected() {
-                if let Some(item) = self.script_items.get(i).cloned() {
-                    if item.meta.nonselectable {
-                        return;
-                    }
-                    self.apply_script_action(&item);
-                }
-            }
-            return;
+    // Replaces the path being typed with `path`, a directory gaining its trailing `/`, and lists it.
+    fn complete_path(&mut self, mut path: String) {
+        let expanded_path = Self::expand_path(&path);
+        if Path::new(&expanded_path).is_dir() && !path.ends_with('/') {
+            path.push('/');
         }
 
-        if let Some(i) = self.list_state.selected() {
-            if self.mode == AppMode::FileSelection && self.filtered_entries.is_empty() {
-                if self.should_use_selected_file_completion() {
-                    if let Some(selected_file) = self.filtered_files.get(i).cloned() {
-                        self.open_file(&selected_file);
-                    }
-                } else if let Some(query_path) = self.current_file_query_path() {
-                    self.open_file(&query_path);
-                }
-                return;
-            }
+        if let Some(last_space_idx) = self.search_query.rfind(' ') {
+            let (prefix, _) = self.search_query.split_at(last_space_idx + 1);
+            self.set_search_query(format!("{}{}", prefix, path));
+        } else {
+            self.set_search_query(path);
+        }
+        self.update_filter();
+    }
 
-            let app_entry = if self.mode == AppMode::FileSelection {
-                self.filtered_entries.first().cloned()
-            } else {
-                self.filtered_entries.get(i).cloned()
-            };
+    fn fill_completion(&mut self) {
+        let Some(cycle) = &self.completion_cycle else {
+            return;
+        };
+        let mut path = cycle.files[cycle.index].path.clone();
+        if Path::new(&Self::expand_path(&path)).is_dir() && !path.ends_with('/') {
+            path.push('/');
+        }
+        let (query, index) = (format!("{}{}", cycle.prefix, path), cycle.index);
+        self.filtered_files = cycle.files.clone();
+        self.set_search_query(query);
+        self.list_state.select(Some(index));
+    }
 
-            if let Some(entry) = app_entry {
-                self.history.increment(&entry.name);
-                if let Some((cmd, args)) = entry.exec_args.split_first() {
-                    let mut final_args = Vec::new();
-
-                    if self.config.features.enable_launch_args {
-                        if let Some(launch_args) = &self.launch_args {
-                            let mut current_launch_args = launch_args.clone();
-                            
-                            if self.mode == AppMode::FileSelection {
-                                if self.should_use_selected_file_completion() {
-                                    if let Some(selected_file) = self.filtered_files.get(i) {
-                                        if let Some(last) = current_launch_args.last_mut() {
-                                            *last = selected_file.clone();
-                                        }
-                                    }
-                                }
-                            }
+    // Any key other than Tab/Shift+Tab ends the cycle and lists what the query now names.
+    pub fn end_completion_cycle(&mut self) {
+        if self.completion_cycle.take().is_some() {
+            self.update_filter();
+        }
+    }
 
-                            let expanded_launch_args: Vec<String> = current_launch_args
-                                .iter()
-                                .map(|arg| self.expand_path(arg))
-                                .collect();
-
-                            let mut replaced = false;
-                            for arg in args {
-                                if ["%f", "%F", "%u", "%U"].contains(&arg.as_str()) {
-                                    final_args.extend(expanded_launch_args.clone());
-                                    replaced = true;
-                                } else {
-                                    final_args.push(arg.clone());
-                                }
-                            }
+    // Fish-style: the longest prefix shared by every name starting with the query, else the
+    // selected entry's name; once the name is complete, a space so launch args can follow.
+    fn complete_app_name(&mut self) {
+        let query = self.search_query.trim_start();
+        if query.is_empty() || query.starts_with(HIDDEN_PREFIX) || self.launch_args.is_some() {
+            return;
+        }
+        let Some(row) = self.list_state.selected() else {
+            return;
+        };
+        let Some(selected) = self.filtered_entry(row).filter(|entry| entry.kind != EntryKind::Url) else {
+            return;
+        };
+        if query.to_lowercase() == selected.name.to_lowercase() {
+            let completed = format!("{} ", selected.name);
+            self.set_search_query(completed);
+            return;
+        }
 
-                            if !replaced {
-                                final_args.extend(expanded_launch_args);
-                            }
-                        } else {
-                            for arg in args {
-                                if !["%f", "%F", "%u", "%U"].contains(&arg.as_str()) {
-                                    final_args.push(arg.clone());
-                                }
-                            }
-                        }
-                    } else {
-                        for arg in args {
-                            if !["%f", "%F", "%u", "%U"].contains(&arg.as_str()) {
-                                final_args.push(arg.clone());
-                            }
-                        }
-                    }
+        let lowered = query.to_lowercase();
+        let shared = (row == 0)
+            .then(|| {
+                self.filtered_rows()
+                    .map(|entry| entry.name.as_str())
+                    .filter(|name| name.to_lowercase().starts_with(&lowered))
+                    .reduce(common_prefix)
+            })
+            .flatten()
+            .filter(|prefix| prefix.chars().count() > query.chars().count());
+        let completed = shared.unwrap_or(&selected.name).to_string();
+        self.set_search_query(completed);
+        self.update_filter();
+    }
 
-                    self.spawn_command(cmd, final_args, &entry.name);
-                }
-            }
+    // Runs `launch`, then, for the keep-open key or with `features.close-on-launch` off, stays open
+    // on an empty query for the next launch instead of quitting.
+    pub fn launch(&mut self, launch: fn(&mut Self), keep_open: bool) {
+        self.launched_name = None;
+        self.launch_keeps_open = keep_open;
+        launch(self);
+        self.stay_open_after_launch();
+    }
+
+    fn stay_open_after_launch(&mut self) {
+        let Some(name) = self.launched_name.take() else {
+            return;
+        };
+        if self.print_only || (self.config.features.close_on_launch && !self.launch_keeps_open) {
+            return;
         }
+        // A launch that already chose to stay open has a message of its own to show.
+        let message = if self.should_quit { Some(format!("Launched {}", name)) } else { self.status_message.take() };
+        self.should_quit = false;
+        self.set_search_query(String::new());
+        self.update_filter();
+        self.status_message = message;
     }
 
-    fn spawn_command(&mut self, cmd: &str, args: Vec<String>, entry_name: &str) {
-        let mut command = Command::new(cmd);
-        command
-            .args(args)
-            .stdin(Stdio::null())
-            .stdout(Stdio::null())
-            .stderr(Stdio::null());
+    // Like Enter, but a selected script runs in the background instead of in a terminal.
+    pub fn launch_detached(&mut self) {
+        self.run_detached = true;
+        self.launch_selected();
+        self.run_detached = false;
+    }
 
-        unsafe {
-            command.pre_exec(|| {
-                libc::setsid();
-                libc::signal(libc::SIGHUP, libc::SIG_IGN);
-                Ok(()) as io::Result<()>
-            });
+    // Enter on a directory in the file list descends into it rather than opening it; everything
+    // else launches as `launch_typed` would, as do marked files.
+    pub fn launch_selected(&mut self) {
+        self.settle_tasks();
+        if self.mode == AppMode::FileSelection && self.marked_files.is_empty() {
+            let selected = self.list_state.selected().and_then(|i| self.filtered_files.get(i));
+            if let Some(dir) = selected.filter(|file| file.is_dir).map(|file| file.path.clone()) {
+                self.complete_path(dir);
+                return;
+            }
         }
+        self.launch_typed();
+    }
 
-        match command.spawn() {
-            Ok(_) => {
-                self.should_quit = true;
-                self.status_message = None;
+    // Opens a directory query as typed, e.g. in the file manager, and passes a completed
+    // directory as a launch arg instead of browsing into it.
+    pub fn launch_typed(&mut self) {
+        self.settle_tasks();
+        if let AppMode::OpenWith(file) = &self.mode {
+            let file = file.clone();
+            let entry = self.list_state.selected().and_then(|i| self.filtered_entry(i)).cloned();
+            if let Some(entry) = entry.map(|entry| self.with_override(entry))
+                && let Some((cmd, args)) = entry.exec_args.split_first()
+            {
+                let args = substitute_file_codes(args, Some(&[file]), entry.kind == EntryKind::Desktop);
+                self.spawn_command(cmd, args, &entry);
             }
-            Err(err) => {
-                self.status_message =
-                    Some(format!("Failed to launch {}: {}", entry_name, err));
+            return;
+        }
+        if self.mode == AppMode::ScriptResults {
+            if let Some(i) = self.list_state.selected()
+                && let Some(item) = self.script_items.get(i).cloned()
+            {
+                if item.meta.nonselectable {
+                    return;
+                }
+                self.apply_script_action(&item);
             }
+            return;
         }
-    }
 
-    fn open_file(&mut self, path_str: &str) {
-        let expanded = self.expand_path(path_str);
-        let path = Path::new(&expanded);
+        if self.mode == AppMode::FileSelection && !self.marked_files.is_empty() {
+            self.launch_marked();
+            return;
+        }
+        if let Some(i) = self.list_state.selected() {
+            if self.mode == AppMode::FileSelection && self.filtered_len() == 0 {
+                if self.should_use_selected_file_completion() {
+                    if let Some(selected_file) = self.filtered_files.get(i).cloned() {
+                        self.open_file(&selected_file.path);
+                    }
+                } else if let Some(query_path) = self.current_file_query_path() {
+                    self.open_file(&query_path);
+                }
+                return;
+            }
 
-        let is_executable = if let Ok(metadata) = fs::metadata(path) {
-            metadata.permissions().mode() & 0o111 != 0
-        } else {
-            false
+            let app_entry = if self.mode == AppMode::FileSelection {
+                self.filtered_entry(0).cloned()
+            } else {
+                self.filtered_entry(i).cloned()
+            };
+            let selected_file = if self.mode == AppMode::FileSelection && self.should_use_selected_file_completion() {
+                self.filtered_files.get(i).map(|file| file.path.clone())
+            } else {
+                None
+            };
+
+            if let Some(entry) = app_entry {
+                self.launch_entry(entry, selected_file.as_deref());
+            }
+        }
+    }
+
+    pub fn toggle_mark(&mut self) {
+        let Some(file) = self.list_state.selected().and_then(|i| self.filtered_files.get(i)) else {
+            return;
+        };
+        match self.marked_files.iter().position(|marked| *marked == file.path) {
+            Some(pos) => {
+                self.marked_files.remove(pos);
+            }
+            None => self.marked_files.push(file.path.clone()),
+        }
+        self.status_message = match self.marked_files.len() {
+            0 => None,
+            1 => Some("1 file marked".to_string()),
+            count => Some(format!("{} files marked", count)),
         };
+        self.move_selection(1);
+    }
 
-        let mut command = if is_executable && !path.is_dir() {
-            Command::new(path)
+    // The app being given arguments gets every marked file in place of the one being completed,
+    // in one launch, or one launch per file when its Exec line only takes one (`%f`, `%u`).
+    // Without an app, each file is opened on its own.
+    fn launch_marked(&mut self) {
+        let files = std::mem::take(&mut self.marked_files);
+        let Some(entry) = self.filtered_entry(0).cloned() else {
+            for file in &files {
+                self.open_file(file);
+            }
+            return;
+        };
+        let mut args = self.launch_args.clone().unwrap_or_default();
+        args.pop();
+        let exec_args = self.with_override(entry.clone()).exec_args;
+        let takes_one = |codes: [&str; 2]| exec_args.iter().any(|arg| codes.contains(&arg.as_str()));
+        let embeds_one =
+            entry.kind == EntryKind::Desktop && exec_args.iter().any(|arg| embedded_file_code(arg).is_some());
+        let batches = if (takes_one(["%f", "%u"]) || embeds_one) && !takes_one(["%F", "%U"]) {
+            files.into_iter().map(|file| vec![file]).collect()
         } else {
-            let mut cmd = Command::new("xdg-open");
-            cmd.arg(path);
-            cmd
+            vec![files]
         };
-
-        command
-            .stdin(Stdio::null())
-            .stdout(Stdio::null())
-            .stderr(Stdio::null());
-
-        unsafe {
-            command.pre_exec(|| {
-                libc::setsid();
-                libc::signal(libc::SIGHUP, libc::SIG_IGN);
-                Ok(()) as io::Result<()>
-            });
+        for batch in batches {
+            self.launch_args = Some(args.iter().cloned().chain(batch).collect());
+            self.launch_entry(entry.clone(), None);
         }
+    }
 
-        match command.spawn() {
-            Ok(_) => {
-                self.should_quit = true;
-                self.status_message = None;
+    fn launch_entry(&mut self, entry: AppEntry, selected_file: Option<&str>) {
+        match entry.kind {
+            EntryKind::Url => {
+                if let Some(url) = entry.exec_args.first() {
+                    self.open_url(url);
+                }
+                return;
             }
-            Err(err) => {
-                self.status_message = Some(format!("Failed to open {}: {}", path_str, err));
+            EntryKind::Power => {
+                self.confirm_power_action(&entry);
+                return;
+            }
+            EntryKind::Missing => {
+                let forget_key = self.config.general.favorite_key.as_deref().unwrap_or("alt+f");
+                self.status_message = Some(format!(
+                    "{} is no longer installed; press {} to forget it",
+                    entry.name, forget_key
+                ));
+                return;
             }
+            _ => {}
+        }
+        if self.config.features.focus_if_running
+            && !self.print_only
+            && self.launch_args.is_none()
+            && compositor::focus_running(&entry)
+        {
+            self.history.increment(&entry.name, self.clock.unix_secs());
+            self.launched_name = Some(entry.name.clone());
+            self.should_quit = true;
+            self.status_message = None;
+            return;
+        }
+
+        let entry = self.with_override(entry);
+        let command = build_command(&entry, self.launch_args.as_deref(), selected_file, &self.config.features);
+        if let Some(command) = command {
+            self.spawn_command(&command.program, command.args, &entry);
         }
     }
 
-    fn looks_like_path_query(query: &str) -> bool {
-        query.starts_with("/")
-            || query.starts_with("~/")
-            || query.starts_with("./")
-            || query.starts_with("../")
+    fn app_override(&self, entry: &AppEntry) -> Option<&AppOverride> {
+        let app_id = entry.app_id.as_deref();
+        self.config.app_overrides.iter().find_map(|(key, value)| {
+            let matches = *key == entry.name || Some(key.trim_end_matches(".desktop")) == app_id;
+            matches.then_some(value)
+        })
     }
 
-    fn current_file_query_path(&self) -> Option<String> {
-        let query = self.search_query.trim();
-        if query.is_empty() {
-            return None;
+    // `[app-overrides]` rewrites the Exec line before field codes are substituted; the
+    // environment is added in `spawn_command`.
+    fn with_override(&self, entry: AppEntry) -> AppEntry {
+        match self.app_override(&entry) {
+            Some(app_override) => apply_override(entry, app_override),
+            None => entry,
         }
+    }
 
-        let path = query
-            .rsplit_once(' ')
-            .map(|(_, tail)| tail)
-            .unwrap_or(query);
-
-        if Self::looks_like_path_query(path) {
-            Some(path.to_string())
-        } else {
-            None
+    // Names in `[app-overrides]` that match no installed app, listed or excluded, reported once
+    // after the first scan.
+    fn check_app_overrides(&mut self) {
+        // An app left out by `OnlyShowIn=`/`NotShowIn=` is still installed, so its override is no typo.
+        let installed = self.entries.iter().chain(self.excluded_entries.iter().map(|(entry, _)| entry));
+        let unknown: Vec<&str> = self
+            .config
+            .app_overrides
+            .keys()
+            .filter(|key| {
+                !installed.clone().any(|entry| {
+                    entry.name == **key || entry.app_id.as_deref() == Some(key.trim_end_matches(".desktop"))
+                })
+            })
+            .map(String::as_str)
+            .collect();
+        if unknown.is_empty() {
+            return;
         }
+        let warning = format!("[app-overrides] matches no app: {}", unknown.join(", "));
+        self.status_message = Some(match self.status_message.take() {
+            Some(existing) => format!("{} · {}", existing, warning),
+            None => warning,
+        });
     }
 
-    fn should_use_selected_file_completion(&self) -> bool {
-        let Some(path) = self.current_file_query_path() else {
-            return true;
-        };
+    // The shell-quoted command `entry` runs with no launch args, after `[app-overrides]`.
+    pub fn resolved_command(&self, entry: &AppEntry) -> Option<String> {
+        let entry = self.with_override(entry.clone());
+        let command = build_command(&entry, None, None, &self.config.features)?;
+        let argv: Vec<String> = [command.program].into_iter().chain(command.args).collect();
+        Some(shell_words(&argv))
+    }
 
-        let segment_after_last_slash = path.rsplit('/').next().unwrap_or(path.as_str());
-        !segment_after_last_slash.is_empty()
+    // Prints the selected item's command instead of running it, whatever `--print` says.
+    pub fn print_selected(&mut self) {
+        let print_only = self.print_only;
+        self.print_only = true;
+        self.launch_typed();
+        self.print_only = print_only;
     }
 
-    fn expand_path(&self, path: &str) -> String {
-        if path == "~" {
-            return std::env::var("HOME").unwrap_or_else(|_| path.to_string());
+    // Hooks are skipped and nothing counts as a launch.
+    fn print_command(&mut self, name: &str, env: &BTreeMap<String, String>, argv: &[String]) {
+        let previous = self.printed_command.take();
+        if self.print_name {
+            self.printed_command = Some(name.to_string());
+        } else {
+            let assignments = env.iter().map(|(key, value)| format!("{}={}", key, shell_quote(value)));
+            let line: Vec<String> = assignments.chain([shell_words(argv)]).collect();
+            self.printed_command = Some(line.join(" "));
         }
-
-        if let Some(rest) = path.strip_prefix("~/") {
-            let home = std::env::var("HOME").unwrap_or_default();
-            if home.is_empty() {
-                return path.to_string();
-            }
-            return format!("{}/{}", home, rest);
+        if let (Some(printed), Some(earlier)) = (&mut self.printed_command, previous) {
+            // Several marked files print a line each.
+            *printed = format!("{}\n{}", earlier, printed);
         }
-
-        path.to_string()
+        self.should_quit = true;
+        self.status_message = None;
     }
 
-    fn list_completions(&self, query_path: &str) -> Vec<String> {
-        let expanded_input = self.expand_path(query_path);
-        let input_path = Path::new(&expanded_input);
-        let query_root = query_path
-            .rsplit_once('/')
-            .map(|(head, _)| format!("{}/", head))
-            .unwrap_or_default();
-        let is_directory_query = expanded_input.ends_with('/') || input_path.is_dir();
-
-        let (dir_path, prefix, display_root) = if is_directory_query {
-            let root = if query_path.ends_with('/') {
-                query_path.to_string()
-            } else {
-                format!("{}/", query_path)
-            };
-            (input_path.to_path_buf(), String::new(), root)
-        } else {
-            (
-                input_path.parent().unwrap_or_else(|| Path::new(".")).to_path_buf(),
-                input_path
-                    .file_name()
-                    .and_then(|name| name.to_str())
-                    .unwrap_or_default()
-                    .to_string(),
-                query_root,
-            )
-        };
-
-        let mut results: Vec<String> = match fs::read_dir(&dir_path) {
-            Ok(entries) => entries
-                .filter_map(|entry| entry.ok())
-                .filter_map(|entry| {
-                    let name = entry.file_name();
-                    let name = name.to_str()?.to_string();
-                    if !prefix.is_empty() && !name.starts_with(&prefix) {
-                        return None;
-                    }
+    fn spawn_command(&mut self, cmd: &str, args: Vec<String>, entry: &AppEntry) {
+        let method = self.config.general.launch_method;
+        if self.print_only {
+            let env = self.app_override(entry).map(|app_override| app_override.env.clone()).unwrap_or_default();
+            let mut argv = launch_wrapper(method, entry, self.clock.now());
+            argv.push(cmd.to_string());
+            argv.extend(args);
+            self.print_command(&entry.name, &env, &argv);
+            return;
+        }
+        if !self.run_pre_launch_hook(&entry.name, cmd, &args) {
+            return;
+        }
+        if method == LaunchMethod::Spawn {
+            self.spawn_directly(cmd, args, entry, None);
+            return;
+        }
 
-                    let mut relative = format!("{}{}", display_root, name);
+        // systemd-run and uwsm can start and then fail (no user manager, a bad unit), so the
+        // wrapper is always watched; see `poll_watched_launches`.
+        let mut wrapped = launch_wrapper(method, entry, self.clock.now());
+        wrapped.push(cmd.to_string());
+        wrapped.extend(args.iter().cloned());
+        let mut command = detached_command(&wrapped[0], &wrapped[1..]);
+        command.envs(self.launch_env(entry));
+        let log = LaunchLog::start(self.clock.now()).map(|(log, stderr)| {
+            command.stderr(stderr);
+            log
+        });
+        let spawned = command.spawn();
+        drop(command);
+        match spawned {
+            Ok(child) => self.watch_launch(child, log, entry, cmd, args, LaunchRoute::Wrapped(method)),
+            Err(err) => {
+                let message = format!("{} failed ({}); started {} directly", method.label(), err, entry.name);
+                self.spawn_directly(cmd, args, entry, Some(message));
+            }
+        }
+    }
 
-                    if entry.path().is_dir() {
-                        relative.push('/');
-                    }
-                    Some(relative)
-                })
-                .collect(),
-            Err(_) => Vec::new(),
-        };
+    fn launch_env(&self, entry: &AppEntry) -> BTreeMap<String, String> {
+        self.app_override(entry).map(|app_override| app_override.env.clone()).unwrap_or_default()
+    }
 
-        if self.config.features.dirs_first {
-            results.sort_by(|a, b| {
-                let a_is_dir = a.ends_with('/');
-                let b_is_dir = b.ends_with('/');
-                b_is_dir.cmp(&a_is_dir).then_with(|| a.cmp(b))
-            });
-        } else {
-            results.sort();
+    // The app as a plain child of qst, watched with `features.capture-launch-errors`. `fallback`
+    // says why the launch method was bypassed; qst stays open so it doesn't go unnoticed.
+    fn spawn_directly(&mut self, cmd: &str, args: Vec<String>, entry: &AppEntry, fallback: Option<String>) {
+        let mut command = detached_command(cmd, &args);
+        command.envs(self.launch_env(entry));
+        let capture = self.config.features.capture_launch_errors;
+        let log = capture.then(|| LaunchLog::start(self.clock.now())).flatten().map(|(log, stderr)| {
+            command.stderr(stderr);
+            log
+        });
+        // Dropping the command closes qst's copy of the pipe, so the drain sees EOF with the app.
+        let spawned = command.spawn();
+        drop(command);
+        match (spawned, log) {
+            (Ok(child), Some(log)) => {
+                let route = fallback.map_or(LaunchRoute::Direct, LaunchRoute::Fallback);
+                self.watch_launch(child, Some(log), entry, cmd, args, route);
+            }
+            (Ok(child), None) => {
+                self.children.push(child);
+                self.launched(entry);
+                self.run_post_launch_hook(&entry.name, cmd, &args);
+                if let Some(message) = fallback {
+                    self.should_quit = false;
+                    self.status_message = Some(message);
+                }
+            }
+            (Err(err), _) => self.launch_failed(entry, format!("Failed to launch {}: {}", entry.name, err)),
         }
+    }
 
-        results
+    // A second failure in a row points at the desktop file, whose Exec line is the likely cause.
+    fn launch_failed(&mut self, entry: &AppEntry, message: String) {
+        let failures = self.launch_failures.entry(entry.name.clone()).or_insert(0);
+        *failures += 1;
+        let failures = *failures;
+        self.set_status(message);
+        if failures >= 2
+            && let (Some(message), Some(path)) = (&mut self.status_message, &entry.desktop_path)
+        {
+            message.push_str(&format!(" — open {} in $EDITOR to fix its Exec line", path.display()));
+        }
     }
 
-    fn scripts_dir() -> Option<PathBuf> {
-        let mut dir = config_dir()?;
-        dir.push("qst");
-        dir.push("scripts");
-        Some(dir)
+    // Checked on every tick for LAUNCH_WATCH, so the UI keeps drawing, until the app either keeps
+    // running or fails; see `poll_watched_launches`.
+    fn watch_launch(
+        &mut self,
+        child: Child,
+        log: Option<LaunchLog>,
+        entry: &AppEntry,
+        program: &str,
+        args: Vec<String>,
+        route: LaunchRoute,
+    ) {
+        self.status_message = Some(format!("Starting {}…", entry.name));
+        let (entry, program, started) = (entry.clone(), program.to_string(), self.clock.elapsed());
+        self.watched_launches.push(WatchedLaunch { entry, program, args, child, log, route, started });
     }
 
-    fn script_interpreter_for_extension(ext: &str) -> Option<&'static str> {
-        match ext {
-            "sh" => Some("sh"),
-            "bash" => Some("bash"),
-            "zsh" => Some("zsh"),
-            "fish" => Some("fish"),
-            "py" => Some("python3"),
-            "pl" => Some("perl"),
-            "rb" => Some("ruby"),
-            "js" => Some("node"),
-            "lua" => Some("lua"),
-            _ => None,
+    // A launch method that can't start the app is bypassed: the app is started again without it.
+    // systemd-run and uwsm otherwise pass on the app's own exit status, so an app that fails
+    // through them is reported like one started directly rather than run a second time.
+    fn poll_watched_launches(&mut self) {
+        let now = self.clock.elapsed();
+        let mut idx = 0;
+        while let Some(launch) = self.watched_launches.get_mut(idx) {
+            let watching = now.saturating_sub(launch.started) < LAUNCH_WATCH;
+            let failure = match launch.child.try_wait() {
+                Ok(Some(status)) if !status.success() => {
+                    // The drain reaches EOF just after the app exits, unless something the app
+                    // left behind still holds its stderr.
+                    let draining = launch.log.as_mut().is_some_and(|log| matches!(log.drain.try_wait(), Ok(None)));
+                    if draining && watching {
+                        idx += 1;
+                        continue;
+                    }
+                    let stderr = read_launch_log(launch.log.as_mut().map(|log| &mut log.file));
+                    let (bypass, name) = match launch.route {
+                        LaunchRoute::Wrapped(method) if wrapper_failed(method, &stderr) => (true, method.label()),
+                        _ => (false, launch.entry.name.as_str()),
+                    };
+                    Some((bypass, launch_failure(name, &status.to_string(), &stderr)))
+                }
+                Ok(None) if watching => {
+                    idx += 1;
+                    continue;
+                }
+                Ok(_) | Err(_) => None,
+            };
+            let mut launch = self.watched_launches.remove(idx);
+            self.children.extend(launch.log.take().map(|log| log.drain));
+            match failure {
+                Some((true, failure)) => {
+                    let message = format!("{}; started {} directly", failure, launch.entry.name);
+                    self.spawn_directly(&launch.program, launch.args, &launch.entry, Some(message));
+                    self.stay_open_after_launch();
+                }
+                failure => self.finish_watched_launch(launch, failure.map(|(_, failure)| failure)),
+            }
         }
     }
 
-    fn normalize_alias_key(key: &str) -> String {
-        let normalized = key.trim();
-        if normalized.is_empty() {
-            return String::new();
+    // An app that failed stays reported and qst stays open; one that's running counts as launched.
+    fn finish_watched_launch(&mut self, launch: WatchedLaunch, failure: Option<String>) {
+        let WatchedLaunch { entry, program, args, child, route, .. } = launch;
+        if let Some(message) = failure {
+            self.launch_failed(&entry, message);
+            return;
+        }
+        self.children.push(child);
+        self.launched(&entry);
+        self.run_post_launch_hook(&entry.name, &program, &args);
+        if let LaunchRoute::Fallback(message) = route {
+            self.should_quit = false;
+            self.status_message = Some(message);
         }
+        self.stay_open_after_launch();
+    }
 
-        let Some((base, ext)) = normalized.rsplit_once('.') else {
-            return normalized.to_string();
+    // `general.pre-launch-hook` runs detached unless `abort-on-hook-failure` is set, in which case
+    // qst waits for it, up to HOOK_TIMEOUT, and a failure cancels the launch.
+    fn run_pre_launch_hook(&mut self, name: &str, program: &str, args: &[String]) -> bool {
+        let Some(hook) = self.config.general.pre_launch_hook.as_deref().filter(|hook| !hook.trim().is_empty()) else {
+            return true;
+        };
+        let mut command = hook_command(hook, name, program, args);
+        if !self.config.general.abort_on_hook_failure {
+            self.children.extend(command.spawn());
+            return true;
+        }
+        let failure = match command.spawn() {
+            Ok(mut child) => match wait_timeout(&mut child, HOOK_TIMEOUT) {
+                Ok(Some(status)) if status.success() => return true,
+                Ok(Some(status)) => status.to_string(),
+                Ok(None) => {
+                    // The hook leads its own process group, so this takes anything it started too.
+                    unsafe { libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL) };
+                    self.children.push(child);
+                    format!("still running after {}s", HOOK_TIMEOUT.as_secs())
+                }
+                Err(err) => err.to_string(),
+            },
+            Err(err) => err.to_string(),
         };
+        self.set_status(format!("Pre-launch hook failed ({}); not starting {}", failure, name));
+        false
+    }
 
-        if Self::script_interpreter_for_extension(&ext.to_ascii_lowercase()).is_some() {
-            return base.to_string();
+    fn run_post_launch_hook(&mut self, name: &str, program: &str, args: &[String]) {
+        if let Some(hook) = self.config.general.post_launch_hook.as_deref().filter(|hook| !hook.trim().is_empty()) {
+            self.children.extend(hook_command(hook, name, program, args).spawn());
         }
+    }
 
-        normalized.to_string()
+    // qst quits right after a launch, so the count is written now rather than at exit.
+    fn launched(&mut self, entry: &AppEntry) {
+        self.history.increment(&entry.name, self.clock.unix_secs());
+        self.history.save_if_dirty();
+        self.launch_failures.remove(&entry.name);
+        self.launched_name = Some(entry.name.clone());
+        self.should_quit = true;
+        self.status_message = None;
     }
 
-    fn load_scripts(aliases: &mut HashMap<String, String>) -> Vec<ScriptPlugin> {
-        let mut scripts = Vec::new();
-        
-        let Some(dir) = Self::scripts_dir() else {
-            return scripts;
+    // Lists the installed apps that declare the selected file's MIME type, the ones mimeapps.list
+    // associates with it first, and lets the query pick one of them.
+    pub fn open_with_selected(&mut self) {
+        if self.mode != AppMode::FileSelection {
+            return;
+        }
+        self.settle_tasks();
+        let Some(file) = self.list_state.selected().and_then(|i| self.filtered_files.get(i)).cloned() else {
+            return;
+        };
+        let expanded = Self::expand_path(&self.expand_query(&file.path));
+        let Some(mime) = mime::mime_type(Path::new(&expanded)) else {
+            self.status_message = Some(format!("Unknown file type for {}", file.path));
+            return;
+        };
+
+        let wildcard = mime.split_once('/').map(|(kind, _)| format!("{}/*", kind)).unwrap_or_default();
+        let mut apps: Vec<AppEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.kind == EntryKind::Desktop)
+            .filter(|entry| entry.mime_types.iter().any(|m| *m == mime || *m == wildcard))
+            .cloned()
+            .collect();
+        let associated = mime::associated_apps(&mime);
+        let rank = |entry: &AppEntry| {
+            let app_id = entry.app_id.as_deref().unwrap_or_default();
+            associated
+                .iter()
+                .position(|id| id.strip_suffix(".desktop").unwrap_or(id) == app_id)
+                .unwrap_or(usize::MAX)
+        };
+        apps.sort_by(|a, b| rank(a).cmp(&rank(b)).then_with(|| a.name.cmp(&b.name)));
+
+        if apps.is_empty() {
+            self.status_message = Some(format!("No installed app opens {}", mime));
+            return;
+        }
+
+        self.tasks.cancel(TaskKind::Files);
+        self.open_with_apps = apps;
+        self.open_with_return = Some(self.search_query.clone());
+        self.mode = AppMode::OpenWith(expanded);
+        self.set_search_query(String::new());
+        self.filter_open_with_apps();
+    }
+
+    // Esc from the picker returns to the file list it was opened from.
+    pub fn close_open_with(&mut self) {
+        self.mode = AppMode::AppSelection;
+        self.open_with_apps.clear();
+        let query = self.open_with_return.take().unwrap_or_default();
+        self.set_search_query(query);
+        self.update_filter();
+    }
+
+    fn filter_open_with_apps(&mut self) {
+        let query = self.search_query.trim();
+        let mut scored: Vec<(i64, usize)> = self
+            .open_with_apps
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, entry)| fuzzy_score(query, &entry.name).map(|score| (score, idx)))
+            .collect();
+        if !query.is_empty() {
+            scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        }
+        self.url_entry = None;
+        self.filtered_entries = scored.iter().map(|&(_, idx)| idx).collect();
+        self.filtered_matches = vec![EntryMatch::unfiltered(); self.filtered_entries.len()];
+        self.list_state.select(if self.filtered_entries.is_empty() { None } else { Some(0) });
+    }
+
+    // The first installed app that mimeapps.list associates with the file's MIME type, then one
+    // from mimeinfo.cache, then any whose desktop file lists the type in `MimeType=`.
+    fn default_app_for(&self, path: &Path) -> Option<AppEntry> {
+        self.default_app_for_mime(&mime::mime_type(path)?)
+    }
+
+    fn default_app_for_mime(&self, mime: &str) -> Option<AppEntry> {
+        let installed: Vec<&AppEntry> = self
+            .entries
+            .iter()
+            .chain(self.excluded_entries.iter().map(|(entry, _)| entry))
+            .filter(|entry| entry.kind == EntryKind::Desktop)
+            .collect();
+        let by_id = |desktop_id: &String| {
+            let app_id = desktop_id.strip_suffix(".desktop").unwrap_or(desktop_id);
+            installed.iter().find(|entry| entry.app_id.as_deref() == Some(app_id)).copied()
+        };
+        mime::associated_apps(mime)
+            .iter()
+            .find_map(by_id)
+            .or_else(|| mime::cached_apps(mime).iter().find_map(by_id))
+            .or_else(|| installed.iter().find(|entry| entry.mime_types.iter().any(|m| m == mime)).copied())
+            .cloned()
+    }
+
+    // Collapses identical consecutive messages into one line with a repeat counter.
+    fn set_status(&mut self, message: String) {
+        let repeat = match &self.status_repeat {
+            Some((last, count)) if *last == message && self.status_message.is_some() => count + 1,
+            _ => 1,
+        };
+        self.status_message = Some(if repeat > 1 {
+            format!("{} (×{})", message, repeat)
+        } else {
+            message.clone()
+        });
+        self.status_repeat = Some((message, repeat));
+    }
+
+    fn open_file(&mut self, path_str: &str) {
+        let expanded = Self::expand_path(&self.expand_query(path_str));
+        let path = Path::new(&expanded);
+
+        let is_executable = if let Ok(metadata) = fs::metadata(path) {
+            metadata.permissions().mode() & 0o111 != 0
+        } else {
+            false
+        };
+
+        let run_directly = is_executable && !path.is_dir();
+        if !run_directly
+            && self.config.features.internal_mime_resolution
+            && let Some(entry) = self.default_app_for(path).map(|entry| self.with_override(entry))
+            && let Some((cmd, args)) = entry.exec_args.split_first()
+        {
+            let files = std::slice::from_ref(&expanded);
+            let args = substitute_file_codes(args, Some(files), entry.kind == EntryKind::Desktop);
+            self.spawn_command(cmd, args, &entry);
+            return;
+        }
+
+        let in_terminal = self.config.features.run_scripts_in_terminal && !self.run_detached;
+        let command = if run_directly && in_terminal && is_script(path) {
+            match self.terminal_command(&expanded) {
+                Some(command) => command,
+                None => {
+                    self.set_status(format!("No terminal found to run {} in; set general.terminal", path_str));
+                    return;
+                }
+            }
+        } else if run_directly {
+            Command::new(path)
+        } else {
+            self.opener_command(&expanded, path.is_dir())
+        };
+        self.spawn_opener(command, path_str);
+    }
+
+    // `script` in `general.terminal`, or a detected one, through a shell that waits for Enter
+    // once it exits so its output can be read.
+    fn terminal_command(&self, script: &str) -> Option<Command> {
+        let configured = self.config.general.terminal.as_deref().filter(|terminal| !terminal.trim().is_empty());
+        let words = match configured {
+            Some(terminal) => split_args(&expand_env(terminal, UndefinedEnvVars::Literal)),
+            None => detect_terminal()?,
+        };
+        let (program, args) = words.split_first()?;
+        let mut command = Command::new(program);
+        command.args(args).args(["sh", "-c", SCRIPT_WRAPPER, "sh", script]);
+        Some(command)
+    }
+
+    // `general.file-opener`, or `dir-opener` for a directory, split like launch args after `$VAR`
+    // expansion, with `target` appended. xdg-open when neither is set.
+    fn opener_command(&self, target: &str, is_dir: bool) -> Command {
+        let general = &self.config.general;
+        let opener = general.dir_opener.as_deref().filter(|_| is_dir).or(general.file_opener.as_deref());
+        let words = opener
+            .map(|opener| split_args(&expand_env(opener, UndefinedEnvVars::Literal)))
+            .unwrap_or_default();
+        let mut command = match words.split_first() {
+            Some((program, args)) => {
+                let mut command = Command::new(program);
+                command.args(args);
+                command
+            }
+            None => Command::new("xdg-open"),
+        };
+        command.arg(target);
+        command
+    }
+
+    // The first press asks, the second clears; any other key in between cancels.
+    pub fn clear_history(&mut self, confirm_key: &str) {
+        if !self.pending_clear_history {
+            self.pending_clear_history = true;
+            self.status_message = Some(format!(
+                "Press {} again to clear usage history (favorites are kept)",
+                confirm_key
+            ));
+            return;
+        }
+        self.pending_clear_history = false;
+        self.history.clear_usage();
+        self.sort_entries();
+        self.update_filter();
+        self.status_message = Some("Usage history cleared".to_string());
+    }
+
+    pub fn cancel_clear_history(&mut self) {
+        if self.pending_clear_history {
+            self.pending_clear_history = false;
+            self.status_message = None;
+        }
+    }
+
+    // Power actions run on the second Enter on the same row, so a stray key can't shut down.
+    fn confirm_power_action(&mut self, entry: &AppEntry) {
+        if self.pending_confirm.as_deref() != Some(entry.name.as_str()) {
+            self.pending_confirm = Some(entry.name.clone());
+            self.status_message = Some(format!("Press Enter again to {}", entry.name.to_lowercase()));
+            return;
+        }
+        self.pending_confirm = None;
+        let Some(command) = entry.exec_args.first() else {
+            return;
+        };
+        if self.config.power_menu.count_usage {
+            self.history.increment(&entry.name, self.clock.unix_secs());
+        }
+        self.execute_shell_command(command, true);
+    }
+
+    fn open_url(&mut self, url: &str) {
+        if self.config.features.internal_mime_resolution {
+            let scheme = url.split_once(':').map(|(scheme, _)| scheme).unwrap_or("https");
+            let handler = self.default_app_for_mime(&format!("x-scheme-handler/{}", scheme));
+            if let Some(entry) = handler.map(|entry| self.with_override(entry))
+                && let Some((cmd, args)) = entry.exec_args.split_first()
+            {
+                let args = substitute_file_codes(args, Some(&[url.to_string()]), entry.kind == EntryKind::Desktop);
+                self.spawn_command(cmd, args, &entry);
+                return;
+            }
+        }
+
+        let command = self.opener_command(url, false);
+        self.spawn_opener(command, url);
+    }
+
+    fn spawn_opener(&mut self, mut command: Command, target: &str) {
+        let program = command.get_program().to_string_lossy().into_owned();
+        let args: Vec<String> = command.get_args().map(|arg| arg.to_string_lossy().into_owned()).collect();
+        if self.print_only {
+            let argv: Vec<String> = [program].into_iter().chain(args).collect();
+            self.print_command(target, &BTreeMap::new(), &argv);
+            return;
+        }
+        if !self.run_pre_launch_hook(target, &program, &args) {
+            return;
+        }
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+
+        unsafe {
+            command.pre_exec(|| {
+                libc::setsid();
+                libc::signal(libc::SIGHUP, libc::SIG_IGN);
+                Ok(()) as io::Result<()>
+            });
+        }
+
+        match command.spawn() {
+            Ok(child) => {
+                self.children.push(child);
+                self.launched_name = Some(target.to_string());
+                self.should_quit = true;
+                self.status_message = None;
+                self.run_post_launch_hook(target, &program, &args);
+            }
+            // Name the missing opener rather than leave it looking like the file is missing.
+            Err(err) if err.kind() == io::ErrorKind::NotFound && !Path::new(&program).ends_with(target) => {
+                let hint = "see general.file-opener and dir-opener";
+                self.set_status(format!("Can't open {}: {} isn't installed ({})", target, program, hint));
+            }
+            Err(err) => {
+                self.set_status(format!("Failed to open {}: {}", target, err));
+            }
+        }
+    }
+
+    fn looks_like_path_query(query: &str) -> bool {
+        query.starts_with("/")
+            || query.starts_with("~/")
+            || query.starts_with("./")
+            || query.starts_with("../")
+    }
+
+    fn current_file_query_path(&self) -> Option<String> {
+        let query = self.expand_query(self.search_query.trim());
+        let query = query.as_str();
+        if query.is_empty() {
+            return None;
+        }
+
+        let path = query
+            .rsplit_once(' ')
+            .map(|(_, tail)| tail)
+            .unwrap_or(query);
+
+        if Self::looks_like_path_query(path) {
+            Some(path.to_string())
+        } else {
+            None
+        }
+    }
+
+    fn should_use_selected_file_completion(&self) -> bool {
+        let Some(path) = self.current_file_query_path() else {
+            return true;
+        };
+
+        let segment_after_last_slash = path.rsplit('/').next().unwrap_or(path.as_str());
+        !segment_after_last_slash.is_empty()
+    }
+
+    fn expand_path(path: &str) -> String {
+        if path == "~" {
+            return std::env::var("HOME").unwrap_or_else(|_| path.to_string());
+        }
+
+        if let Some(rest) = path.strip_prefix("~/") {
+            let home = std::env::var("HOME").unwrap_or_default();
+            if home.is_empty() {
+                return path.to_string();
+            }
+            return format!("{}/{}", home, rest);
+        }
+
+        path.to_string()
+    }
+
+    // Launch args also get `~` expanded after a `=`, as in `--config=~/app.toml`.
+    fn expand_arg(arg: &str) -> String {
+        match arg.split_once('=') {
+            Some((key, value)) if !arg.starts_with('~') && (value == "~" || value.starts_with("~/")) => {
+                format!("{}={}", key, Self::expand_path(value))
+            }
+            _ => Self::expand_path(arg),
+        }
+    }
+
+    // Dotfiles are listed when `show_hidden` is set or the typed name itself starts with a dot.
+    fn list_completions(
+        query_path: &str,
+        features: &FeaturesConfig,
+        sort: FileSort,
+        show_hidden: bool,
+    ) -> Vec<FileEntry> {
+        let query = FileQuery::parse(query_path);
+        if !query.is_recursive() {
+            let mut results = query.filter(&read_listing(&query.dir).unwrap_or_default(), show_hidden);
+            sort_files(&mut results, sort, features.dirs_first);
+            return results;
+        }
+        let base = query.dir.parent().unwrap_or_else(|| Path::new("."));
+        let root = query.display_root.strip_suffix("**/").unwrap_or(&query.display_root);
+        let mut walk = RecursiveWalk {
+            query: &query,
+            root,
+            max_depth: features.recursive_depth,
+            ignore_dirs: &features.ignore_dirs,
+            show_hidden,
+            scanned: 0,
+            results: Vec::new(),
+        };
+        walk.collect(base, "", 0);
+        let mut results = walk.results;
+        sort_files(&mut results, sort, features.dirs_first);
+        if !is_glob_pattern(&query.prefix) {
+            results.sort_by_cached_key(|file| {
+                let relative = file.path.strip_prefix(root).unwrap_or(&file.path);
+                std::cmp::Reverse(fuzzy_score(&query.prefix, relative.trim_end_matches('/')))
+            });
+        }
+        results.truncate(RECURSIVE_MAX_RESULTS);
+        results
+    }
+
+    fn scripts_dir() -> Option<PathBuf> {
+        let mut dir = config_dir()?;
+        dir.push("qst");
+        dir.push("scripts");
+        Some(dir)
+    }
+
+    fn script_interpreter_for_extension(ext: &str) -> Option<&'static str> {
+        match ext {
+            "sh" => Some("sh"),
+            "bash" => Some("bash"),
+            "zsh" => Some("zsh"),
+            "fish" => Some("fish"),
+            "py" => Some("python3"),
+            "pl" => Some("perl"),
+            "rb" => Some("ruby"),
+            "js" => Some("node"),
+            "lua" => Some("lua"),
+            _ => None,
+        }
+    }
+
+    fn normalize_alias_key(key: &str) -> String {
+        let normalized = key.trim();
+        if normalized.is_empty() {
+            return String::new();
+        }
+
+        let Some((base, ext)) = normalized.rsplit_once('.') else {
+            return normalized.to_string();
+        };
+
+        if Self::script_interpreter_for_extension(&ext.to_ascii_lowercase()).is_some() {
+            return base.to_string();
+        }
+
+        normalized.to_string()
+    }
+
+    fn load_scripts(aliases: &mut HashMap<String, String>) -> Vec<ScriptPlugin> {
+        let mut scripts = Vec::new();
+        
+        let Some(dir) = Self::scripts_dir() else {
+            return scripts;
         };
 
         let Ok(entries) = fs::read_dir(&dir) else {
@@ -1033,32 +3119,402 @@ impl App {
         }
     }
 
-    fn try_run_script_query(&mut self, query: &str) -> bool {
-        if query.is_empty() || self.scripts.is_empty() {
+    // `recent:` lists files from recently-used.xbel, most recent first, fuzzy-filtered by whatever
+    // follows the prefix. The file is read once per visit to the mode.
+    fn try_recent_files_query(&mut self, query: &str) -> bool {
+        let Some(filter) = query.strip_prefix(RECENT_PREFIX) else {
             return false;
-        }
+        };
+        let filter = filter.trim();
 
-        let mut matched: Option<(ScriptPlugin, String)> = None;
+        self.tasks.cancel(TaskKind::Files);
+        self.clear_filtered();
+        self.filtered_matches.clear();
+        self.mode = AppMode::FileSelection;
 
-        let mut aliases: Vec<&ScriptPlugin> = self
-            .scripts
+        if self.recent_files.is_none() {
+            let home = std::env::var("HOME").unwrap_or_default();
+            self.recent_files = Some(match recent::load(self.config.features.recent_files_limit) {
+                Ok(paths) => paths
+                    .iter()
+                    .map(|path| {
+                        let display = path.to_string_lossy();
+                        let display = match display.strip_prefix(home.as_str()) {
+                            Some(rest) if !home.is_empty() && rest.starts_with('/') => format!("~{}", rest),
+                            _ => display.into_owned(),
+                        };
+                        file_entry(path, display)
+                    })
+                    .collect(),
+                Err(err) => {
+                    self.status_message = Some(err);
+                    Vec::new()
+                }
+            });
+        }
+
+        let recent = self.recent_files.as_deref().unwrap_or_default();
+        let mut scored: Vec<(i64, usize)> = recent
             .iter()
-            .filter(|script| script.trigger.as_ref().is_some_and(|t| !t.is_empty()))
+            .enumerate()
+            .filter_map(|(idx, file)| fuzzy_score(filter, &file.path).map(|score| (score, idx)))
             .collect();
-        aliases.sort_by(|a, b| {
-            b.trigger
-                .as_ref()
-                .map(|t| t.len())
-                .unwrap_or(0)
-                .cmp(&a.trigger.as_ref().map(|t| t.len()).unwrap_or(0))
-        });
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        self.filtered_files = scored.into_iter().map(|(_, idx)| recent[idx].clone()).collect();
+        self.preview_cache.clear();
+        self.list_state.select(if self.filtered_files.is_empty() { None } else { Some(0) });
+        true
+    }
 
-        for script in aliases {
-            let trigger = script.trigger.as_ref().expect("filtered non-empty trigger");
-            if let Some(rest) = query.strip_prefix(trigger) {
-                matched = Some((script.clone(), rest.trim_start().to_string()));
-                break;
-            }
+    // `kill <filter>` lists processes from a `/proc` and /etc/passwd snapshot taken on entering the
+    // mode, so filtering doesn't rescan; `refresh_processes` takes a new one. Processes owned by another
+    // user are shown with their owner but can't be selected.
+    fn try_kill_query(&mut self, query: &str) -> bool {
+        let prefix = self.config.kill.prefix.as_str();
+        if prefix.is_empty() {
+            return false;
+        }
+        let filter = if query == prefix {
+            ""
+        } else if let Some(rest) = query.strip_prefix(&format!("{} ", prefix)) {
+            rest.trim_start()
+        } else {
+            return false;
+        };
+
+        self.tasks.cancel(TaskKind::Script);
+        self.clear_filtered();
+        self.filtered_matches.clear();
+        self.filtered_files.clear();
+        self.script_title = Some(" Processes ".to_string());
+        self.mode = AppMode::ScriptResults;
+
+        if self.processes.is_none() {
+            self.processes = Some(process::list());
+            self.user_names = process::user_names();
+        }
+        let processes = self.processes.as_deref().unwrap_or_default();
+        let users = &self.user_names;
+        let uid = process::current_uid();
+        let filter = filter.to_lowercase();
+        let mut scored: Vec<(i64, usize)> = processes
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, proc)| {
+                if filter.is_empty() {
+                    return Some((0, idx));
+                }
+                fuzzy_score(&filter, &proc.name.to_lowercase()).map(|score| (score, idx))
+            })
+            .collect();
+        scored.sort_by_key(|&(score, idx)| (std::cmp::Reverse(score), idx));
+
+        self.script_items = scored
+            .into_iter()
+            .map(|(_, idx)| {
+                let proc = &processes[idx];
+                let owner = (proc.uid != uid).then(|| {
+                    users.get(&proc.uid).cloned().unwrap_or_else(|| proc.uid.to_string())
+                });
+                let mut title = format!(
+                    "{:<16} {:>7} {:>10}  {}",
+                    proc.name,
+                    proc.pid,
+                    preview::human_size(proc.rss_kb * 1024),
+                    proc.cmdline
+                );
+                if let Some(owner) = &owner {
+                    title = format!("[{}] {}", owner, title);
+                }
+                ScriptItem {
+                    title,
+                    value: proc.pid.to_string(),
+                    action: ScriptAction::KillProcess,
+                    meta: ScriptRowMeta {
+                        nonselectable: owner.is_some(),
+                        ..ScriptRowMeta::default()
+                    },
+                }
+            })
+            .collect();
+        true
+    }
+
+    pub fn showing_processes(&self) -> bool {
+        self.mode == AppMode::ScriptResults && self.processes.is_some()
+    }
+
+    pub fn refresh_processes(&mut self) {
+        self.processes = None;
+        self.update_filter();
+    }
+
+    // SIGTERM, or SIGKILL when `force`. qst stays open so several can be killed in a row.
+    pub fn kill_selected(&mut self, force: bool) {
+        let Some(item) = self.list_state.selected().and_then(|i| self.script_items.get(i)).cloned() else {
+            return;
+        };
+        if item.action != ScriptAction::KillProcess || item.meta.nonselectable {
+            return;
+        }
+        let Ok(pid) = item.value.parse::<i32>() else {
+            return;
+        };
+        let name = self
+            .processes
+            .as_deref()
+            .and_then(|processes| processes.iter().find(|proc| proc.pid == pid))
+            .map(|proc| proc.name.clone())
+            .unwrap_or_default();
+        let (signal, signal_name) = if force { (libc::SIGKILL, "SIGKILL") } else { (libc::SIGTERM, "SIGTERM") };
+
+        let status = match process::signal(pid, signal) {
+            Ok(()) => format!("Sent {} to {} ({})", signal_name, name, pid),
+            Err(err) => format!("Failed to signal {} ({}): {}", name, pid, err),
+        };
+        self.refresh_processes();
+        self.status_message = Some(status);
+    }
+
+    // `win <filter>` lists open windows, asked from the compositor once on entering the mode.
+    fn try_window_query(&mut self, query: &str) -> bool {
+        let prefix = self.config.windows.prefix.as_str();
+        if prefix.is_empty() {
+            return false;
+        }
+        let filter = if query == prefix {
+            ""
+        } else if let Some(rest) = query.strip_prefix(&format!("{} ", prefix)) {
+            rest.trim_start()
+        } else {
+            return false;
+        };
+
+        self.tasks.cancel(TaskKind::Script);
+        self.clear_filtered();
+        self.filtered_matches.clear();
+        self.filtered_files.clear();
+        self.script_title = Some(" Windows ".to_string());
+        self.mode = AppMode::ScriptResults;
+
+        if self.windows.is_none() {
+            self.windows = Some(match compositor::list_windows() {
+                Ok(windows) => windows,
+                Err(err) => {
+                    self.status_message = Some(err);
+                    Vec::new()
+                }
+            });
+        }
+
+        let windows = self.windows.as_deref().unwrap_or_default();
+        let filter = filter.to_lowercase();
+        let mut scored: Vec<(i64, usize)> = windows
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, window)| {
+                if filter.is_empty() {
+                    return Some((0, idx));
+                }
+                fuzzy_score(&filter, &window_label(window).to_lowercase()).map(|score| (score, idx))
+            })
+            .collect();
+        scored.sort_by_key(|&(score, idx)| (std::cmp::Reverse(score), idx));
+
+        self.script_items = scored
+            .into_iter()
+            .map(|(_, idx)| ScriptItem {
+                title: window_label(&windows[idx]),
+                value: windows[idx].id.clone(),
+                action: ScriptAction::FocusWindow,
+                meta: ScriptRowMeta::default(),
+            })
+            .collect();
+        true
+    }
+
+    pub fn open_window_switcher(&mut self) {
+        let query = format!("{} ", self.config.windows.prefix);
+        self.set_search_query(query);
+        self.update_filter();
+    }
+
+    fn focus_window(&mut self, id: &str) {
+        let windows = self.windows.as_deref().unwrap_or_default();
+        let Some(window) = windows.iter().find(|window| window.id == id) else {
+            return;
+        };
+        if compositor::focus(window) {
+            self.should_quit = true;
+            self.status_message = None;
+        } else {
+            self.status_message = Some(format!("Could not focus {}", window_label(window)));
+        }
+    }
+
+    fn try_emoji_query(&mut self, query: &str) -> bool {
+        let prefix = self.config.emoji.prefix.as_str();
+        let Some(filter) = query.strip_prefix(prefix).filter(|_| !prefix.is_empty()) else {
+            return false;
+        };
+
+        self.tasks.cancel(TaskKind::Script);
+        self.clear_filtered();
+        self.filtered_matches.clear();
+        self.filtered_files.clear();
+        self.script_title = Some(" Emoji ".to_string());
+        self.mode = AppMode::ScriptResults;
+
+        let type_command = self.config.emoji.type_command.as_deref().filter(|command| !command.trim().is_empty());
+        self.script_items = emoji::search(filter.trim(), EMOJI_RESULT_LIMIT)
+            .into_iter()
+            .map(|(glyph, name)| {
+                let (value, action) = match type_command {
+                    Some(command) => (command.replace("{glyph}", &shell_quote(glyph)), ScriptAction::ExecuteAndExit),
+                    None => (glyph.to_string(), ScriptAction::CopyToClipboardAndExit),
+                };
+                ScriptItem {
+                    title: format!("{}  {}", glyph, name),
+                    value,
+                    action,
+                    meta: ScriptRowMeta::default(),
+                }
+            })
+            .collect();
+        true
+    }
+
+    // Lists clipboard history behind the `clipboard.prefix` trigger. History is fetched once when
+    // the prefix is typed and filtered locally on every keystroke after that.
+    fn try_clipboard_query(&mut self, query: &str) -> bool {
+        let prefix = self.config.clipboard.prefix.as_str();
+        if prefix.is_empty() || self.config.clipboard.list_command.trim().is_empty() {
+            return false;
+        }
+        let filter = if query == prefix {
+            ""
+        } else if let Some(rest) = query.strip_prefix(&format!("{} ", prefix)) {
+            rest.trim_start()
+        } else {
+            return false;
+        };
+
+        self.tasks.cancel(TaskKind::Script);
+        self.clear_filtered();
+        self.filtered_matches.clear();
+        self.filtered_files.clear();
+        self.script_title = Some(" Clipboard ".to_string());
+        self.mode = AppMode::ScriptResults;
+
+        match &self.clipboard_history {
+            None => {
+                self.script_items.clear();
+                if !self.tasks.is_pending(TaskKind::Clipboard) {
+                    let list_command = self.config.clipboard.list_command.clone();
+                    self.tasks.spawn_task(TaskKind::Clipboard, move |sink| {
+                        sink.post(TaskOutput::Clipboard(Self::list_clipboard_history(&list_command)));
+                    });
+                }
+            }
+            Some(Err(err)) => {
+                self.script_items = vec![ScriptItem {
+                    title: err.clone(),
+                    value: String::new(),
+                    action: ScriptAction::None,
+                    meta: ScriptRowMeta {
+                        nonselectable: true,
+                        ..ScriptRowMeta::default()
+                    },
+                }];
+            }
+            Some(Ok(lines)) => {
+                let select_command = &self.config.clipboard.select_command;
+                let filter = filter.to_lowercase();
+                let mut scored: Vec<(i64, usize)> = lines
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(idx, line)| {
+                        if filter.is_empty() {
+                            return Some((0, idx));
+                        }
+                        fuzzy_score(&filter, &clipboard_label(line).to_lowercase()).map(|score| (score, idx))
+                    })
+                    .collect();
+                scored.sort_by_key(|&(score, idx)| (std::cmp::Reverse(score), idx));
+
+                self.script_items = scored
+                    .into_iter()
+                    .map(|(_, idx)| {
+                        let line = &lines[idx];
+                        ScriptItem {
+                            title: clipboard_label(line),
+                            value: select_command.replace("{selection}", &shell_quote(line)),
+                            action: ScriptAction::ExecuteAndExit,
+                            meta: ScriptRowMeta::default(),
+                        }
+                    })
+                    .collect();
+            }
+        }
+
+        true
+    }
+
+    fn list_clipboard_history(list_command: &str) -> Result<Vec<String>, String> {
+        let program = list_command.split_whitespace().next().unwrap_or(list_command);
+        let output = Command::new("sh")
+            .arg("-c")
+            .arg(list_command)
+            .stdin(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()
+            .map_err(|err| format!("Clipboard history unavailable: {}", err))?;
+
+        if output.status.code() == Some(127) {
+            return Err(format!(
+                "Clipboard history unavailable: `{}` not found (set [clipboard] list-command)",
+                program
+            ));
+        }
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let detail = stderr.lines().next().unwrap_or("").trim();
+            return Err(format!("`{}` failed: {}", program, detail));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.to_string())
+            .collect())
+    }
+
+    fn try_run_script_query(&mut self, query: &str) -> bool {
+        if query.is_empty() || self.scripts.is_empty() {
+            return false;
+        }
+
+        let mut matched: Option<(ScriptPlugin, String)> = None;
+
+        let mut aliases: Vec<&ScriptPlugin> = self
+            .scripts
+            .iter()
+            .filter(|script| script.trigger.as_ref().is_some_and(|t| !t.is_empty()))
+            .collect();
+        aliases.sort_by(|a, b| {
+            b.trigger
+                .as_ref()
+                .map(|t| t.len())
+                .unwrap_or(0)
+                .cmp(&a.trigger.as_ref().map(|t| t.len()).unwrap_or(0))
+        });
+
+        for script in aliases {
+            let trigger = script.trigger.as_ref().expect("filtered non-empty trigger");
+            if let Some(rest) = query.strip_prefix(trigger) {
+                matched = Some((script.clone(), rest.trim_start().to_string()));
+                break;
+            }
         }
 
         if matched.is_none() {
@@ -1104,31 +3560,23 @@ impl App {
             return false;
         };
 
-        self.filtered_entries.clear();
+        self.clear_filtered();
+        self.filtered_matches.clear();
         self.filtered_files.clear();
+        if self.script_title.is_none() {
+            self.script_title = Some(format!(" {} ", script.id));
+        }
         self.mode = AppMode::ScriptResults;
 
-        match self.run_script(&script, &payload) {
-            Ok((title, message, items)) => {
-                self.script_title = title.or_else(|| Some(format!(" {} ", script.id)));
-                self.script_items = items;
-                self.status_message = message;
-            }
-            Err(err) => {
-                self.script_title = Some(format!(" {} ", script.id));
-                self.script_items = vec![ScriptItem {
-                    title: format!("Script error: {}", err),
-                    value: String::new(),
-                    action: ScriptAction::None,
-                    meta: ScriptRowMeta::default(),
-                }];
-            }
-        }
+        self.tasks.spawn_task(TaskKind::Script, move |sink| {
+            let result = Self::run_script(&script, &payload);
+            sink.post(TaskOutput::Script(script.id, result));
+        });
 
         true
     }
 
-    fn run_script(&self, script: &ScriptPlugin, payload: &str) -> Result<(Option<String>, Option<String>, Vec<ScriptItem>), String> {
+    fn run_script(script: &ScriptPlugin, payload: &str) -> Result<ScriptOutput, String> {
         let mut command = if let Some(interpreter) = script.interpreter {
             let mut command = Command::new(interpreter);
             command.arg(&script.path);
@@ -1156,7 +3604,7 @@ impl App {
         Ok(Self::parse_script_output(&stdout))
     }
 
-    fn parse_script_output(output: &str) -> (Option<String>, Option<String>, Vec<ScriptItem>) {
+    fn parse_script_output(output: &str) -> ScriptOutput {
         let mut title: Option<String> = None;
         let mut message: Option<String> = None;
         let mut items = Vec::new();
@@ -1340,117 +3788,1120 @@ impl App {
             ScriptAction::ClearStatusMessage => {
                 self.status_message = None;
             }
-            ScriptAction::CopyToClipboardAndExit => {
-                if let Err(err) = self.copy_to_clipboard(&item.value) {
-                    self.status_message = Some(format!("Clipboard failed: {}", err));
-                } else {
-                    self.should_quit = true;
+            ScriptAction::CopyToClipboardAndExit => {
+                if let Err(err) = self.copy_to_clipboard(&item.value) {
+                    self.status_message = Some(format!("Clipboard failed: {}", err));
+                } else {
+                    self.should_quit = true;
+                }
+            }
+            ScriptAction::ExecuteAndExit => {
+                self.execute_shell_command(&item.value, true);
+            }
+            ScriptAction::KillProcess => {
+                self.kill_selected(false);
+            }
+            ScriptAction::FocusWindow => {
+                self.focus_window(&item.value);
+            }
+            ScriptAction::ExecuteAndRefresh => {
+                self.execute_shell_command(&item.value, false);
+                self.update_filter();
+            }
+        }
+    }
+
+    fn execute_shell_command(&mut self, command_text: &str, exit_after: bool) {
+        if self.print_only {
+            let argv = ["sh".to_string(), "-lc".to_string(), command_text.to_string()];
+            self.print_command(command_text, &BTreeMap::new(), &argv);
+            return;
+        }
+        let mut command = Command::new("sh");
+        command
+            .arg("-lc")
+            .arg(command_text)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+
+        unsafe {
+            command.pre_exec(|| {
+                libc::setsid();
+                libc::signal(libc::SIGHUP, libc::SIG_IGN);
+                Ok(()) as io::Result<()>
+            });
+        }
+
+        match command.spawn() {
+            Ok(child) => {
+                self.children.push(child);
+                self.status_message = None;
+                if exit_after {
+                    self.launched_name = Some(command_text.to_string());
+                    self.should_quit = true;
+                }
+            }
+            Err(err) => {
+                self.set_status(format!("Failed to execute command: {}", err));
+            }
+        }
+    }
+
+    fn read_clipboard(&self) -> Result<String, String> {
+        let paste_command = match &self.config.general.paste_command {
+            Some(command) => command.clone(),
+            None => detect_paste_command()
+                .ok_or_else(|| "no clipboard tool found (install wl-paste, xclip or xsel)".to_string())?
+                .to_string(),
+        };
+
+        let output = Command::new("sh")
+            .arg("-lc")
+            .arg(&paste_command)
+            .stdin(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()
+            .map_err(|err| err.to_string())?;
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        }
+    }
+
+    // Returns the command the text was handed to.
+    fn copy_to_clipboard(&self, value: &str) -> Result<String, String> {
+        let clipboard_command = match &self.config.general.clipboard_command {
+            Some(command) => command.clone(),
+            None => detect_clipboard_command()
+                .ok_or_else(|| "no clipboard tool found (install wl-copy, xclip or xsel)".to_string())?
+                .to_string(),
+        };
+
+        // xclip and xsel fork a daemon that keeps stderr open, so a pipe would never reach EOF;
+        // a file can be read once the command itself has exited.
+        let mut log = launch_log(self.clock.now());
+        let stderr = log.as_ref().and_then(|log| log.try_clone().ok()).map_or_else(Stdio::null, Stdio::from);
+        let mut command = Command::new("sh");
+        command.arg("-lc").arg(&clipboard_command);
+        command.stdin(Stdio::piped()).stdout(Stdio::null()).stderr(stderr);
+
+        let mut child = command.spawn().map_err(|err| err.to_string())?;
+        if let Some(mut stdin) = child.stdin.take() {
+            use std::io::Write;
+            stdin
+                .write_all(value.as_bytes())
+                .map_err(|err| err.to_string())?;
+        }
+
+        let status = child.wait().map_err(|err| err.to_string())?;
+        if status.success() {
+            return Ok(clipboard_command);
+        }
+        let mut output = String::new();
+        if let Some(log) = log.as_mut() {
+            let _ = log.rewind();
+            let _ = log.take(4096).read_to_string(&mut output);
+        }
+        match output.trim() {
+            "" => Err(format!("{} exited with {}", clipboard_command, status)),
+            output => Err(output.to_string()),
+        }
+    }
+}
+
+// Parses every .desktop file under `dir`, reusing `previous` cache records whose mtime is
+// unchanged and recording the result for each file in `cache`.
+pub(crate) fn scan_desktop_dir(
+    dir: PathBuf,
+    locales: &[String],
+    previous: &EntryCache,
+    cache: &mut EntryCache,
+) -> Vec<AppEntry> {
+    let mut entries = Vec::new();
+    for path in Iter::new(std::iter::once(dir)) {
+        let Some(stamp) = FileStamp::of(&path) else {
+            continue;
+        };
+
+        let entry = match previous.lookup(&path, &stamp) {
+            Some(cached) => cached.entry.clone(),
+            None => match DesktopEntry::from_path(path.clone(), Some(locales)) {
+                Ok(desktop_entry) => desktop_entry_to_app_entry(&desktop_entry, locales),
+                Err(_) => None,
+            },
+        };
+
+        if let Some(entry) = &entry {
+            entries.push(entry.clone());
+        }
+        cache.insert(&path, CachedFile { stamp, entry });
+    }
+    entries
+}
+
+fn desktop_entry_to_app_entry(entry: &DesktopEntry, locales: &[String]) -> Option<AppEntry> {
+    if entry.no_display() || entry.hidden() {
+        return None;
+    }
+
+    let name = entry
+        .full_name(locales)
+        .or_else(|| entry.name(locales))
+        .map(|cow| cow.into_owned())
+        .unwrap_or_else(|| entry.appid.clone());
+    let exec_args = parse_exec_line(entry.exec()?, &name, entry.icon(), &entry.path)?;
+
+    let mut app_entry = AppEntry::new(name, exec_args, EntryKind::Desktop);
+    app_entry.app_id = Some(entry.appid.clone());
+    app_entry.wm_class = entry.startup_wm_class().map(str::to_string);
+    app_entry.desktop_path = Some(entry.path.clone());
+    app_entry.generic_name = entry.generic_name(locales).map(|cow| cow.into_owned());
+    app_entry.comment = entry.comment(locales).map(|cow| cow.into_owned());
+    app_entry.keywords = entry
+        .keywords(locales)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|cow| cow.trim().to_string())
+        .filter(|keyword| !keyword.is_empty())
+        .collect();
+    app_entry.only_show_in = desktop_list(entry.only_show_in());
+    app_entry.not_show_in = desktop_list(entry.not_show_in());
+    app_entry.mime_types = desktop_list(entry.mime_type());
+    Some(app_entry)
+}
+
+// Splits an Exec value the way the Desktop Entry spec describes: double quotes group an argument
+// and inside them a backslash escapes `"`, `` ` ``, `$` and `\`. Field codes in unquoted
+// arguments are expanded here (`%c` name, `%k` desktop file, `%i` as `--icon <Icon>`, `%%` a
+// literal percent) and deprecated or unknown ones dropped. `%f`/`%F`/`%u`/`%U` stay as their own
+// arguments for `substitute_file_codes` to fill at launch.
+fn parse_exec_line(exec: &str, name: &str, icon: Option<&str>, desktop_path: &Path) -> Option<Vec<String>> {
+    let mut tokens: Vec<(String, bool)> = Vec::new();
+    let mut current: Option<(String, bool)> = None;
+    let mut chars = exec.chars();
+    let mut in_quotes = false;
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.get_or_insert_with(|| (String::new(), true)).1 = true;
+            }
+            '\\' if in_quotes => {
+                let escaped = chars.next()?;
+                current.get_or_insert_with(|| (String::new(), true)).0.push(escaped);
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if let Some(token) = current.take() {
+                    tokens.push(token);
+                }
+            }
+            c => current.get_or_insert_with(|| (String::new(), false)).0.push(c),
+        }
+    }
+    if in_quotes {
+        return None;
+    }
+    tokens.extend(current);
+
+    let mut args = Vec::new();
+    for (token, quoted) in tokens {
+        if quoted {
+            args.push(token);
+            continue;
+        }
+        match token.as_str() {
+            "%f" | "%F" | "%u" | "%U" => args.push(token),
+            "%i" => {
+                if let Some(icon) = icon.filter(|icon| !icon.is_empty()) {
+                    args.push("--icon".to_string());
+                    args.push(icon.to_string());
+                }
+            }
+            _ => {
+                let mut expanded = String::new();
+                let mut chars = token.chars();
+                while let Some(ch) = chars.next() {
+                    if ch != '%' {
+                        expanded.push(ch);
+                        continue;
+                    }
+                    match chars.next() {
+                        Some('%') => expanded.push('%'),
+                        // Filled in at launch by `substitute_file_codes`, like a standalone one.
+                        Some(code @ ('f' | 'u')) => {
+                            expanded.push('%');
+                            expanded.push(code);
+                        }
+                        Some('c') => expanded.push_str(name),
+                        Some('k') => expanded.push_str(&desktop_path.to_string_lossy()),
+                        Some('i') => expanded.push_str(icon.unwrap_or_default()),
+                        _ => {}
+                    }
+                }
+                if !expanded.is_empty() {
+                    args.push(expanded);
+                }
+            }
+        }
+    }
+
+    if args.is_empty() { None } else { Some(args) }
+}
+
+fn desktop_list(values: Option<Vec<&str>>) -> Vec<String> {
+    values
+        .unwrap_or_default()
+        .into_iter()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+fn show_in_exclusion(entry: &AppEntry, desktops: &[String]) -> Option<String> {
+    let listed = |list: &[String]| list.iter().any(|value| desktops.contains(&value.to_lowercase()));
+
+    if !entry.only_show_in.is_empty() && !listed(&entry.only_show_in) {
+        return Some(format!("OnlyShowIn={}", entry.only_show_in.join(";")));
+    }
+    if listed(&entry.not_show_in) {
+        return Some(format!("NotShowIn={}", entry.not_show_in.join(";")));
+    }
+    None
+}
+
+fn scan_path_binaries(desktop_entries: &[AppEntry]) -> Vec<AppEntry> {
+    let mut seen: HashSet<String> = desktop_entries
+        .iter()
+        .filter_map(|entry| entry.exec_args.first())
+        .filter_map(|cmd| Path::new(cmd).file_name()?.to_str().map(|name| name.to_string()))
+        .collect();
+
+    let Some(path_var) = std::env::var_os("PATH") else {
+        return Vec::new();
+    };
+
+    let mut binaries = Vec::new();
+    for dir in std::env::split_paths(&path_var) {
+        let Ok(read_dir) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in read_dir.flatten() {
+            let Some(name) = entry.file_name().to_str().map(|name| name.to_string()) else {
+                continue;
+            };
+            if seen.contains(&name) {
+                continue;
+            }
+
+            let path = entry.path();
+            let Ok(meta) = fs::metadata(&path) else {
+                continue;
+            };
+            if !meta.is_file() || meta.permissions().mode() & 0o111 == 0 {
+                continue;
+            }
+
+            seen.insert(name.clone());
+            binaries.push(AppEntry::new(
+                name,
+                vec![path.to_string_lossy().into_owned()],
+                EntryKind::Binary,
+            ));
+        }
+    }
+
+    binaries
+}
+
+fn power_entries(config: &AppConfig) -> Vec<AppEntry> {
+    if !config.features.power_menu {
+        return Vec::new();
+    }
+    config
+        .power_menu
+        .actions()
+        .into_iter()
+        .filter(|(_, command)| !command.trim().is_empty())
+        .map(|(name, command)| AppEntry::new(name.to_string(), vec![command.to_string()], EntryKind::Power))
+        .collect()
+}
+
+fn load_ascii(config: &AppConfig) -> String {
+    if let Some(path) = &config.qst_ascii.custom_path {
+        let expanded_path = path.replace("~", std::env::var("HOME").unwrap_or_else(|_| String::new()).as_str());
+        fs::read_to_string(expanded_path).unwrap_or_else(|_| include_str!("../assets/qst.txt").to_string())
+    } else {
+        include_str!("../assets/qst.txt").to_string()
+    }
+}
+
+// "class — title", falling back to whichever of the two the window has.
+fn window_label(window: &compositor::Window) -> String {
+    let class = window.class.as_deref().or(window.app_id.as_deref()).unwrap_or("");
+    match window.title.as_deref().filter(|title| !title.is_empty()) {
+        Some(title) if !class.is_empty() => format!("{}  —  {}", class, title),
+        Some(title) => title.to_string(),
+        None => class.to_string(),
+    }
+}
+
+// cliphist lines are `<id>\t<preview>`; binary previews look like `[[ binary data 4 KiB png 64x64 ]]`.
+fn clipboard_label(line: &str) -> String {
+    let preview = line.split_once('\t').map(|(_, preview)| preview).unwrap_or(line);
+    match preview
+        .trim()
+        .strip_prefix("[[ binary data")
+        .and_then(|rest| rest.strip_suffix("]]"))
+    {
+        Some(details) => format!("[binary {}]", details.trim()),
+        None => preview.to_string(),
+    }
+}
+
+fn file_entry(path: &Path, mut display: String) -> FileEntry {
+    let metadata = fs::metadata(path).ok();
+    let is_dir = metadata.as_ref().is_some_and(|m| m.is_dir());
+    if is_dir {
+        display.push('/');
+    }
+    let broken_link = metadata.is_none() && fs::symlink_metadata(path).is_ok_and(|m| m.is_symlink());
+    FileEntry {
+        path: display,
+        is_dir,
+        broken_link,
+        size: metadata.as_ref().map(|m| m.len()).unwrap_or(0),
+        modified: metadata.and_then(|m| m.modified().ok()),
+    }
+}
+
+// Every entry of `dir`, with paths relative to it, or why it can't be read.
+fn read_listing(dir: &Path) -> Result<Vec<FileEntry>, String> {
+    let entries = fs::read_dir(dir).map_err(|err| {
+        let shown = dir.to_string_lossy();
+        let shown = shown.trim_end_matches('/');
+        let shown = if shown.is_empty() { "/" } else { shown };
+        match err.kind() {
+            io::ErrorKind::PermissionDenied => format!("Permission denied: {}", shown),
+            io::ErrorKind::NotFound => format!("No such directory: {}", shown),
+            io::ErrorKind::NotADirectory => format!("Not a directory: {}", shown),
+            _ => format!("Can't read {}: {}", shown, err),
+        }
+    })?;
+    Ok(entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| Some(file_entry(&entry.path(), entry.file_name().to_str()?.to_string())))
+        .collect())
+}
+
+// A `[` only opens a class once a `]` closes it, so `photo[` is still typed as a plain prefix.
+fn is_glob_pattern(segment: &str) -> bool {
+    let chars: Vec<char> = segment.chars().collect();
+    chars
+        .iter()
+        .enumerate()
+        .any(|(i, &c)| c == '*' || c == '?' || (c == '[' && glob_class(&chars, i, c).is_some()))
+}
+
+// `dir/**/pattern` looks at most `features.recursive-depth` directories below `dir`, skipping
+// `features.ignore-dirs`, and stops after this many entries read, so a stray `~/**/` stays
+// responsive. Every hit is ranked before the best this many are kept. Symlinked directories are
+// not followed.
+const RECURSIVE_MAX_RESULTS: usize = 500;
+const RECURSIVE_MAX_SCANNED: usize = 50_000;
+
+struct RecursiveWalk<'a> {
+    query: &'a FileQuery,
+    // What the results are shown under, as typed: `~/src/` for `~/src/**/main`.
+    root: &'a str,
+    max_depth: usize,
+    ignore_dirs: &'a [String],
+    show_hidden: bool,
+    scanned: usize,
+    results: Vec<FileEntry>,
+}
+
+impl RecursiveWalk<'_> {
+    // `relative_dir` is `dir` below the walk's base, empty or ending in `/`.
+    fn collect(&mut self, dir: &Path, relative_dir: &str, depth: usize) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            self.scanned += 1;
+            if self.scanned > RECURSIVE_MAX_SCANNED {
+                return;
+            }
+            let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            let relative = format!("{}{}", relative_dir, name);
+            let is_dir = entry.file_type().is_ok_and(|kind| kind.is_dir());
+            if is_dir && self.ignore_dirs.contains(&name) {
+                continue;
+            }
+            if self.query.matches_nested(&name, &relative, self.show_hidden) {
+                self.results.push(file_entry(&entry.path(), format!("{}{}", self.root, relative)));
+            }
+            if is_dir && depth < self.max_depth && (self.show_hidden || !name.starts_with('.')) {
+                self.collect(&entry.path(), &format!("{}/", relative), depth + 1);
+            }
+        }
+    }
+}
+
+// A single word that is a URL (`https://…`), starts with `www.`, or looks like `host.tld/path`.
+// Bare `host.tld` is not enough since desktop ids like `org.gnome.Nautilus` look the same.
+fn url_from_query(query: &str) -> Option<String> {
+    if query.is_empty() || query.contains(char::is_whitespace) {
+        return None;
+    }
+    if query.starts_with("http://") || query.starts_with("https://") {
+        return Some(query.to_string());
+    }
+
+    let (host, path) = match query.split_once('/') {
+        Some((host, path)) => (host, Some(path)),
+        None => (query, None),
+    };
+    let labels: Vec<&str> = host.split('.').collect();
+    let valid_host = labels.len() >= 2
+        && labels.iter().all(|label| !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+        && labels.last().is_some_and(|tld| tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()));
+    if valid_host && (host.starts_with("www.") || path.is_some()) {
+        Some(format!("https://{}", query))
+    } else {
+        None
+    }
+}
+
+// Puts `files` in place of `%f`/`%F`/`%u`/`%U`, or after the arguments when the Exec line has no
+// such code. Without files the codes are dropped. `%u`/`%U` get local paths as `file://` URIs.
+// With `embedded_codes` (desktop entries, whose Exec lines went through `parse_exec_line`), an
+// argument like `--file=%f` is repeated once per file, or left out when there are none.
+fn substitute_file_codes(args: &[String], files: Option<&[String]>, embedded_codes: bool) -> Vec<String> {
+    let mut final_args = Vec::new();
+    let mut replaced = false;
+    for arg in args {
+        let code = match arg.as_str() {
+            "%f" | "%F" => Some((String::new(), 'f', String::new())),
+            "%u" | "%U" => Some((String::new(), 'u', String::new())),
+            _ if embedded_codes => embedded_file_code(arg),
+            _ => None,
+        };
+        let Some((before, code, after)) = code else {
+            final_args.push(arg.clone());
+            continue;
+        };
+        let standalone = before.is_empty() && after.is_empty();
+        for file in files.unwrap_or_default() {
+            let value = if code == 'u' { file_uri(file) } else { file.clone() };
+            final_args.push(if standalone { value } else { format!("{}{}{}", before, value, after) });
+        }
+        replaced = true;
+    }
+    if let (false, Some(files)) = (replaced, files) {
+        final_args.extend(files.iter().cloned());
+    }
+    final_args
+}
+
+// `--file=%f` split around its code: ("--file=", 'f', "").
+fn embedded_file_code(arg: &str) -> Option<(String, char, String)> {
+    let idx = arg.find("%f").into_iter().chain(arg.find("%u")).min()?;
+    let code = arg[idx + 1..].chars().next()?;
+    Some((arg[..idx].to_string(), code, arg[idx + 2..].to_string()))
+}
+
+fn apply_override(mut entry: AppEntry, app_override: &AppOverride) -> AppEntry {
+    if let Some(exec) = app_override.exec.as_deref() {
+        let desktop_path = entry.desktop_path.clone().unwrap_or_default();
+        if let Some(args) = parse_exec_line(exec, &entry.name, None, &desktop_path) {
+            entry.exec_args = args;
+        }
+    }
+    if !entry.exec_args.is_empty() {
+        let prepend = app_override.prepend_args.iter().cloned();
+        entry.exec_args.splice(1..1, prepend);
+        entry.exec_args.extend(app_override.append_args.iter().cloned());
+    }
+    entry
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedCommand {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+// The program and arguments `entry` runs with. Typed launch args fill the Exec line's field
+// codes (or are appended when it has none) after `~` expansion; `selected_file` replaces the
+// last of them, since that's the one being completed. Launch args are ignored when
+// `enable-launch-args` is off. None for entries without a command.
+pub fn build_command(
+    entry: &AppEntry,
+    launch_args: Option<&[String]>,
+    selected_file: Option<&str>,
+    features: &FeaturesConfig,
+) -> Option<ResolvedCommand> {
+    let (program, args) = entry.exec_args.split_first()?;
+    let files = launch_args.filter(|_| features.enable_launch_args).map(|launch_args| {
+        let mut files = launch_args.to_vec();
+        if let (Some(last), Some(selected)) = (files.last_mut(), selected_file) {
+            *last = selected.to_string();
+        }
+        files.iter().map(|arg| App::expand_arg(arg)).collect::<Vec<String>>()
+    });
+    Some(ResolvedCommand {
+        program: program.clone(),
+        args: substitute_file_codes(args, files.as_deref(), entry.kind == EntryKind::Desktop),
+    })
+}
+
+// An already unlinked file for the start of a launched app's stderr (see `LaunchLog`), or all of
+// a clipboard tool's.
+fn launch_log(now: SystemTime) -> Option<File> {
+    let dir = std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from).unwrap_or_else(std::env::temp_dir);
+    let nanos = now.duration_since(UNIX_EPOCH).map(|time| time.subsec_nanos()).unwrap_or(0);
+    let path = dir.join(format!("qst-launch-{}-{}", std::process::id(), nanos));
+    let file = File::options().read(true).write(true).create_new(true).open(&path).ok()?;
+    let _ = fs::remove_file(&path);
+    Some(file)
+}
+
+// The stderr a launch left in its log, as much as the drain kept.
+fn read_launch_log(log: Option<&mut File>) -> String {
+    let mut output = Vec::new();
+    if let Some(log) = log {
+        let _ = log.rewind();
+        let _ = log.take(4096).read_to_end(&mut output);
+    }
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+// What systemd-run and uwsm print when they can't start the app at all, as opposed to passing on
+// the status of an app that started and then failed.
+fn wrapper_failed(method: LaunchMethod, stderr: &str) -> bool {
+    let markers: &[&str] = match method {
+        LaunchMethod::Spawn => &[],
+        LaunchMethod::SystemdRun => &[
+            "Failed to start transient scope",
+            "Failed to connect to bus",
+            "Failed to create bus connection",
+        ],
+        LaunchMethod::Uwsm => &["uwsm:", "uwsm app:"],
+    };
+    stderr.lines().any(|line| markers.iter().any(|marker| line.trim_start().starts_with(marker)))
+}
+
+// "Foo exited with exit status: 1: first · lines · of stderr", at most three lines of it.
+fn launch_failure(name: &str, status: &str, output: &str) -> String {
+    let lines: Vec<&str> = output.lines().map(str::trim).filter(|line| !line.is_empty()).take(3).collect();
+    if lines.is_empty() {
+        format!("{} exited with {}", name, status)
+    } else {
+        format!("{} exited with {}: {}", name, status, lines.join(" · "))
+    }
+}
+
+// A new session with SIGHUP ignored and no stdio, so the app survives qst's terminal closing.
+fn detached_command(program: &str, args: &[String]) -> Command {
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    unsafe {
+        command.pre_exec(|| {
+            libc::setsid();
+            libc::signal(libc::SIGHUP, libc::SIG_IGN);
+            Ok(()) as io::Result<()>
+        });
+    }
+    command
+}
+
+// `sh -c hook` with the launch described in `FLARE_APP_NAME`, `FLARE_EXEC` and `FLARE_ARGS` (the
+// arguments shell-quoted on one line), also set as `QST_*`.
+fn hook_command(hook: &str, name: &str, program: &str, args: &[String]) -> Command {
+    let mut command = detached_command("sh", &["-c".to_string(), hook.to_string()]);
+    let args = shell_words(args);
+    for prefix in ["FLARE", "QST"] {
+        command
+            .env(format!("{}_APP_NAME", prefix), name)
+            .env(format!("{}_EXEC", prefix), program)
+            .env(format!("{}_ARGS", prefix), &args);
+    }
+    command
+}
+
+// None if `child` is still running after `timeout`.
+fn wait_timeout(child: &mut Child, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match child.try_wait()? {
+            Some(status) => return Ok(Some(status)),
+            None if Instant::now() >= deadline => return Ok(None),
+            None => thread::sleep(Duration::from_millis(10)),
+        }
+    }
+}
+
+// The program and arguments that go before the app's own command line for `method`.
+// systemd-run scopes are named `app-qst-<id>-<random>.scope`, as the systemd desktop
+// application spec suggests for launchers.
+fn launch_wrapper(method: LaunchMethod, entry: &AppEntry, now: SystemTime) -> Vec<String> {
+    match method {
+        LaunchMethod::Spawn => Vec::new(),
+        LaunchMethod::Uwsm => vec!["uwsm".to_string(), "app".to_string(), "--".to_string()],
+        LaunchMethod::SystemdRun => {
+            let id = entry.app_id.as_deref().unwrap_or(&entry.name);
+            let id: String = id
+                .trim_end_matches(".desktop")
+                .chars()
+                .map(|ch| if ch.is_ascii_alphanumeric() || matches!(ch, '.' | '_') { ch } else { '_' })
+                .collect();
+            let nanos = now
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.subsec_nanos())
+                .unwrap_or_default();
+            let random = format!("{:08x}", nanos ^ std::process::id().rotate_left(16));
+            vec![
+                "systemd-run".to_string(),
+                "--user".to_string(),
+                "--scope".to_string(),
+                "--quiet".to_string(),
+                format!("--unit=app-qst-{}-{}", id, random),
+                "--".to_string(),
+            ]
+        }
+    }
+}
+
+// Turns a local path into a percent-encoded `file://` URI. Anything that already has a scheme,
+// or is neither absolute nor an existing relative path (a flag, a bare word), is left alone.
+fn file_uri(arg: &str) -> String {
+    let path = Path::new(arg);
+    // `a:b` is a file name before it is a URI with scheme `a`.
+    let has_scheme = !path.exists()
+        && arg.split_once(':').is_some_and(|(scheme, _)| {
+            scheme.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+                && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c))
+        });
+    if has_scheme {
+        return arg.to_string();
+    }
+
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else if path.exists() {
+        match std::env::current_dir() {
+            Ok(cwd) => cwd.join(path),
+            Err(_) => return arg.to_string(),
+        }
+    } else {
+        return arg.to_string();
+    };
+
+    let mut uri = String::from("file://");
+    for byte in absolute.as_os_str().as_encoded_bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => uri.push(*byte as char),
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
+
+// Newest and largest come first; ties and the name sort fall back to the path.
+fn sort_files(files: &mut [FileEntry], sort: FileSort, dirs_first: bool) {
+    files.sort_by(|a, b| {
+        let dirs = if dirs_first { b.is_dir.cmp(&a.is_dir) } else { std::cmp::Ordering::Equal };
+        let key = match sort {
+            FileSort::Name => std::cmp::Ordering::Equal,
+            FileSort::Modified => b.modified.cmp(&a.modified),
+            FileSort::Size => b.size.cmp(&a.size),
+        };
+        dirs.then(key).then_with(|| a.path.cmp(&b.path))
+    });
+}
+
+// Keeps the first `max` of the already sorted `files`, or all of them when `max` is 0, and returns
+// how many were cut.
+fn cap_files(files: &mut Vec<FileEntry>, max: usize) -> usize {
+    if max == 0 || files.len() <= max {
+        return 0;
+    }
+    let omitted = files.len() - max;
+    files.truncate(max);
+    omitted
+}
+
+// Runs "$1" and keeps the terminal open on its exit status until Enter.
+const SCRIPT_WRAPPER: &str = r#""$1"; status=$?; printf '\n[%s exited with %d, press Enter to close]' "$1" "$status"; read -r _"#;
+
+// Terminals and what runs a program in each, tried in order when `general.terminal` is unset.
+const TERMINALS: [(&str, &[&str]); 7] = [
+    ("foot", &["foot"]),
+    ("kitty", &["kitty"]),
+    ("alacritty", &["alacritty", "-e"]),
+    ("wezterm", &["wezterm", "start", "--"]),
+    ("gnome-terminal", &["gnome-terminal", "--"]),
+    ("konsole", &["konsole", "-e"]),
+    ("xterm", &["xterm", "-e"]),
+];
+
+// `$TERMINAL -e`, else the first installed of TERMINALS.
+fn detect_terminal() -> Option<Vec<String>> {
+    if let Some(terminal) = std::env::var("TERMINAL").ok().filter(|terminal| !terminal.is_empty()) {
+        return Some(vec![terminal, "-e".to_string()]);
+    }
+    let (_, command) = TERMINALS.iter().find(|(name, _)| on_path(name))?;
+    Some(command.iter().map(|word| word.to_string()).collect())
+}
+
+// Whether an executable is a script, which likely wants a terminal to show its output or read
+// input: a `#!` line or a `.sh` name. Binaries (ELF and the rest) are assumed to bring a window.
+fn is_script(path: &Path) -> bool {
+    if path.extension().is_some_and(|extension| extension == "sh") {
+        return true;
+    }
+    let mut start = [0u8; 2];
+    File::open(path).and_then(|mut file| file.read_exact(&mut start)).is_ok() && start == *b"#!"
+}
+
+// A program name is looked up on $PATH; one with a `/` is a path.
+pub fn program_exists(program: &str) -> bool {
+    if program.contains('/') { Path::new(program).is_file() } else { on_path(program) }
+}
+
+fn on_path(name: &str) -> bool {
+    std::env::var_os("PATH").is_some_and(|paths| std::env::split_paths(&paths).any(|dir| dir.join(name).is_file()))
+}
+
+// wl-copy on Wayland, otherwise xclip or xsel, whichever is installed.
+fn detect_clipboard_command() -> Option<&'static str> {
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    if wayland && on_path("wl-copy") {
+        Some("wl-copy")
+    } else if on_path("xclip") {
+        Some("xclip -selection clipboard")
+    } else if on_path("xsel") {
+        Some("xsel --clipboard --input")
+    } else if on_path("wl-copy") {
+        Some("wl-copy")
+    } else {
+        None
+    }
+}
+
+// The reading side of `detect_clipboard_command`.
+fn detect_paste_command() -> Option<&'static str> {
+    let wayland = std::env::var_os("WAYLAND_DISPLAY").is_some();
+    if wayland && on_path("wl-paste") {
+        Some("wl-paste --no-newline")
+    } else if on_path("xclip") {
+        Some("xclip -selection clipboard -o")
+    } else if on_path("xsel") {
+        Some("xsel --clipboard --output")
+    } else if on_path("wl-paste") {
+        Some("wl-paste --no-newline")
+    } else {
+        None
+    }
+}
+
+// The longest case-insensitive common prefix, cased as in `a`.
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .char_indices()
+        .zip(b.chars())
+        .take_while(|((_, x), y)| x.to_lowercase().eq(y.to_lowercase()))
+        .last()
+        .map(|((idx, x), _)| idx + x.len_utf8())
+        .unwrap_or(0);
+    &a[..len]
+}
+
+// Quotes only the arguments that need it, so the copied line reads like one typed by hand.
+fn shell_words(args: &[String]) -> String {
+    args.iter()
+        .map(|arg| {
+            let plain = !arg.is_empty()
+                && arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./=:,+%@".contains(c));
+            if plain { arg.clone() } else { shell_quote(arg) }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+// Splits typed launch args the way a shell would: single quotes are literal, double quotes allow
+// `\"` and `\\` escapes, and a bare backslash escapes the next character. A quote with no closing
+// partner yet is kept as a literal character, so a half-typed argument still splits on spaces.
+pub(crate) fn split_args(input: &str) -> Vec<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => {
+                words.extend(word.take());
+                i += 1;
+            }
+            '\\' => {
+                let current = word.get_or_insert_with(String::new);
+                match chars.get(i + 1) {
+                    Some(&next) => current.push(next),
+                    None => current.push('\\'),
                 }
+                i += 2;
             }
-            ScriptAction::ExecuteAndExit => {
-                self.execute_shell_command(&item.value, true);
+            '\'' | '"' => {
+                let current = word.get_or_insert_with(String::new);
+                let Some(close) = closing_quote(&chars, i) else {
+                    current.push(c);
+                    i += 1;
+                    continue;
+                };
+                let mut j = i + 1;
+                while j < close {
+                    if c == '"' && chars[j] == '\\' && matches!(chars.get(j + 1), Some('"' | '\\')) {
+                        j += 1;
+                    }
+                    current.push(chars[j]);
+                    j += 1;
+                }
+                i = close + 1;
             }
-            ScriptAction::ExecuteAndRefresh => {
-                self.execute_shell_command(&item.value, false);
-                self.update_filter();
+            c => {
+                word.get_or_insert_with(String::new).push(c);
+                i += 1;
             }
         }
     }
+    words.extend(word);
+    words
+}
 
-    fn execute_shell_command(&mut self, command_text: &str, exit_after: bool) {
-        let mut command = Command::new("sh");
-        command
-            .arg("-lc")
-            .arg(command_text)
-            .stdin(Stdio::null())
-            .stdout(Stdio::null())
-            .stderr(Stdio::null());
-
-        unsafe {
-            command.pre_exec(|| {
-                libc::setsid();
-                libc::signal(libc::SIGHUP, libc::SIG_IGN);
-                Ok(()) as io::Result<()>
-            });
-        }
-
-        match command.spawn() {
-            Ok(_) => {
-                self.status_message = None;
-                if exit_after {
-                    self.should_quit = true;
+// Replaces `$NAME` and `${NAME}` with the variable's value. Single-quoted text and `\$` are left
+// alone, as is a `$` not followed by a name; unset variables follow `undefined`.
+fn expand_env(text: &str, undefined: UndefinedEnvVars) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => {
+                out.extend(chars[i..].iter().take(2));
+                i += 2;
+            }
+            '\'' => {
+                let end = closing_quote(&chars, i).unwrap_or(i);
+                out.extend(&chars[i..=end]);
+                i = end + 1;
+            }
+            '$' => {
+                let braced = chars.get(i + 1) == Some(&'{');
+                let start = if braced { i + 2 } else { i + 1 };
+                let len = chars[start.min(chars.len())..]
+                    .iter()
+                    .enumerate()
+                    .take_while(|&(n, &c)| c == '_' || c.is_ascii_alphabetic() || (n > 0 && c.is_ascii_digit()))
+                    .count();
+                let end = start + len;
+                if len == 0 || (braced && chars.get(end) != Some(&'}')) {
+                    out.push('$');
+                    i += 1;
+                    continue;
                 }
+                let name: String = chars[start..end].iter().collect();
+                let next = if braced { end + 1 } else { end };
+                match std::env::var(&name) {
+                    Ok(value) => out.push_str(&value),
+                    Err(_) if undefined == UndefinedEnvVars::Empty => {}
+                    Err(_) => out.extend(&chars[i..next]),
+                }
+                i = next;
             }
-            Err(err) => {
-                self.status_message = Some(format!("Failed to execute command: {}", err));
+            c => {
+                out.push(c);
+                i += 1;
             }
         }
     }
+    out
+}
 
-    fn copy_to_clipboard(&self, value: &str) -> Result<(), String> {
-        let clipboard_command = self
-            .config
-            .general
-            .clipboard_command
-            .clone()
-            .unwrap_or_else(|| "wl-copy".to_string());
+// Index of the quote closing the one at `open`, skipping `\"` inside double quotes.
+fn closing_quote(chars: &[char], open: usize) -> Option<usize> {
+    let quote = chars[open];
+    let mut j = open + 1;
+    while j < chars.len() {
+        if quote == '"' && chars[j] == '\\' {
+            j += 2;
+            continue;
+        }
+        if chars[j] == quote {
+            return Some(j);
+        }
+        j += 1;
+    }
+    None
+}
 
-        let mut command = Command::new("sh");
-        command.arg("-lc").arg(format!("{}", clipboard_command));
-        command
-            .stdin(Stdio::piped())
-            .stdout(Stdio::null())
-            .stderr(Stdio::piped());
+// Secondary fields only count as plain substring hits, and their scores are pushed below any
+// name match so an app called "Files" always outranks one that mentions files in its comment.
+const GENERIC_NAME_PENALTY: i64 = 1_000;
+const KEYWORD_PENALTY: i64 = 1_500;
+const COMMENT_PENALTY: i64 = 2_000;
+
+// A name only matched once vowels are dropped (see `translit::skeleton`) ranks below a letter
+// for letter match of its transliteration.
+const SKELETON_PENALTY: i64 = 500;
+
+// Case-sensitive `*`/`?`/`[...]` glob; callers that want case-insensitive matching lowercase both
+// sides. A class may be negated with `!` or `^` and contain ranges like `a-z`.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        let step = match pattern.get(p) {
+            Some('*') => None,
+            Some('?') => Some(p + 1),
+            Some('[') => match glob_class(&pattern, p, text[t]) {
+                Some((true, next)) => Some(next),
+                Some((false, _)) => None,
+                None if text[t] == '[' => Some(p + 1),
+                None => None,
+            },
+            Some(&c) if c == text[t] => Some(p + 1),
+            _ => None,
+        };
 
-        let mut child = command.spawn().map_err(|err| err.to_string())?;
-        if let Some(stdin) = child.stdin.as_mut() {
-            use std::io::Write;
-            stdin
-                .write_all(value.as_bytes())
-                .map_err(|err| err.to_string())?;
+        if let Some(next) = step {
+            p = next;
+            t += 1;
+        } else if pattern.get(p) == Some(&'*') {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
         }
+    }
 
-        let output = child.wait_with_output().map_err(|err| err.to_string())?;
-        if output.status.success() {
-            Ok(())
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+// Returns whether `c` is in the class opening at `pattern[start]` and the index just past it, or
+// None when the `[` is never closed and should be taken literally.
+fn glob_class(pattern: &[char], start: usize, c: char) -> Option<(bool, usize)> {
+    let mut i = start + 1;
+    let negated = matches!(pattern.get(i), Some('!') | Some('^'));
+    if negated {
+        i += 1;
+    }
+
+    let mut matched = false;
+    let mut first = true;
+    while let Some(&ch) = pattern.get(i) {
+        if ch == ']' && !first {
+            return Some((matched != negated, i + 1));
+        }
+        first = false;
+        if pattern.get(i + 1) == Some(&'-') && pattern.get(i + 2).is_some_and(|&end| end != ']') {
+            matched |= (ch..=pattern[i + 2]).contains(&c);
+            i += 3;
         } else {
-            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+            matched |= ch == c;
+            i += 1;
         }
     }
+    None
 }
 
-fn scan_desktop_files(show_duplicates: bool) -> Vec<AppEntry> {
-    let locales = get_languages_from_env();
-    let locale_slice = locales.as_slice();
+fn match_entry(
+    query: &str,
+    folded_query: Option<&str>,
+    search_keywords: bool,
+    entry: &AppEntry,
+) -> Option<(i64, MatchField)> {
+    let primary = match_entry_name(query, folded_query, entry);
+    if primary.is_some() || !search_keywords || query.is_empty() {
+        return primary;
+    }
 
-    let mut entries: Vec<AppEntry> = Iter::new(default_paths())
-        .entries(Some(locale_slice))
-        .filter(|entry| !entry.no_display() && !entry.hidden())
-        .filter_map(|entry| {
-            let exec_args = entry.parse_exec().ok()?;
-            let name = entry
-                .full_name(locale_slice)
-                .or_else(|| entry.name(locale_slice))
-                .map(|cow| cow.into_owned())
-                .unwrap_or_else(|| entry.appid.clone());
+    let secondary = |text: &str, penalty: i64| {
+        text.to_lowercase()
+            .contains(query)
+            .then(|| fuzzy_score(query, text).unwrap_or(0) - penalty)
+    };
+
+    let generic = entry
+        .generic_name
+        .as_deref()
+        .and_then(|text| secondary(text, GENERIC_NAME_PENALTY))
+        .map(|score| (score, MatchField::GenericName));
+    let keyword = entry
+        .keywords
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, text)| secondary(text, KEYWORD_PENALTY).map(|score| (score, MatchField::Keyword(idx))))
+        .max_by_key(|(score, _)| *score);
+    let comment = entry
+        .comment
+        .as_deref()
+        .and_then(|text| secondary(text, COMMENT_PENALTY))
+        .map(|score| (score, MatchField::Comment));
+
+    [generic, keyword, comment].into_iter().flatten().max_by_key(|(score, _)| *score)
+}
 
-            Some(AppEntry { name, exec_args })
+// Scores the raw name and, when transliteration is on, the ASCII-folded name against the
+// folded query so Latin input finds Cyrillic/Greek names and vice versa. When either side was
+// folded, their consonants are compared too, since a transliteration spells sounds.
+fn match_entry_name(query: &str, folded_query: Option<&str>, entry: &AppEntry) -> Option<(i64, MatchField)> {
+    let direct = fuzzy_score(query, &entry.name).map(|score| (score, MatchField::Name));
+    let folded = match (folded_query, entry.folded_name.as_deref()) {
+        (None, None) => None,
+        (query_form, name_form) => fuzzy_score(
+            query_form.unwrap_or(query),
+            name_form.unwrap_or(&entry.name),
+        )
+        .map(|score| (score, MatchField::Transliteration)),
+    };
+    let transliterated = folded_query.is_some() || entry.folded_name.is_some();
+    let sounded = transliterated.then(|| {
+        let query_form = translit::skeleton(folded_query.unwrap_or(query));
+        let name_form = translit::skeleton(entry.folded_name.as_deref().unwrap_or(&entry.name));
+        (!query_form.is_empty() && name_form.contains(&query_form)).then(|| {
+            let score = fuzzy_score(&query_form, &name_form).unwrap_or(0) - SKELETON_PENALTY;
+            (score, MatchField::Transliteration)
         })
-        .collect();
-
-    entries.sort_by(|a, b| {
-        a.name.to_lowercase().cmp(&b.name.to_lowercase())
-            .then_with(|| a.name.cmp(&b.name))
     });
-    
-    if !show_duplicates {
-        entries.dedup_by(|a, b| a.name.to_lowercase() == b.name.to_lowercase());
-    }
-    
-    entries
+    let sounded = sounded.flatten();
+    [direct, folded, sounded].into_iter().flatten().max_by_key(|(score, _)| *score)
 }
 
-
 pub(crate) fn fuzzy_score(query: &str, target: &str) -> Option<i64> {
     let query_chars: Vec<char> = query.chars().collect();
     let target_chars: Vec<char> = target.chars().collect();
@@ -1503,3 +4954,939 @@ fn fuzzy_match(query: &str, target: &str) -> bool {
     fuzzy_score(query, target).is_some()
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(exec: &[&str]) -> AppEntry {
+        AppEntry::new("Viewer".to_string(), exec.iter().map(|arg| arg.to_string()).collect(), EntryKind::Desktop)
+    }
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn argv(entry: &AppEntry, launch_args: Option<&[&str]>, selected_file: Option<&str>) -> Vec<String> {
+        let launch_args = launch_args.map(strings);
+        let command = build_command(entry, launch_args.as_deref(), selected_file, &FeaturesConfig::default()).unwrap();
+        [command.program].into_iter().chain(command.args).collect()
+    }
+
+    #[test]
+    fn build_command_fills_file_codes() {
+        let files = Some(&["/tmp/a.txt", "/tmp/b.txt"][..]);
+        assert_eq!(argv(&entry(&["viewer", "%f"]), files, None), strings(&["viewer", "/tmp/a.txt", "/tmp/b.txt"]));
+        assert_eq!(
+            argv(&entry(&["viewer", "--open", "%F", "--new"]), files, None),
+            strings(&["viewer", "--open", "/tmp/a.txt", "/tmp/b.txt", "--new"])
+        );
+        assert_eq!(
+            argv(&entry(&["viewer", "%U"]), Some(&["/tmp/a b.txt", "https://example.com"]), None),
+            strings(&["viewer", "file:///tmp/a%20b.txt", "https://example.com"])
+        );
+        assert_eq!(argv(&entry(&["viewer", "%u"]), Some(&["/tmp/x"]), None), strings(&["viewer", "file:///tmp/x"]));
+    }
+
+    #[test]
+    fn build_command_appends_args_without_file_codes() {
+        assert_eq!(
+            argv(&entry(&["mpv", "--fs"]), Some(&["--mute", "/tmp/a.mkv"]), None),
+            strings(&["mpv", "--fs", "--mute", "/tmp/a.mkv"])
+        );
+    }
+
+    #[test]
+    fn build_command_without_files_drops_codes() {
+        assert_eq!(argv(&entry(&["viewer", "%U", "--new"]), None, None), strings(&["viewer", "--new"]));
+        assert_eq!(argv(&entry(&["viewer", "%f"]), Some(&[]), None), strings(&["viewer"]));
+    }
+
+    #[test]
+    fn build_command_selected_file_replaces_last_arg() {
+        assert_eq!(
+            argv(&entry(&["viewer", "%F"]), Some(&["-v", "/tmp/pa"]), Some("/tmp/partial.txt")),
+            strings(&["viewer", "-v", "/tmp/partial.txt"])
+        );
+    }
+
+    #[test]
+    fn build_command_ignores_args_when_launch_args_are_off() {
+        let features = FeaturesConfig { enable_launch_args: false, ..FeaturesConfig::default() };
+        let args = strings(&["/tmp/a.txt"]);
+        let command = build_command(&entry(&["viewer", "%f"]), Some(&args), None, &features).unwrap();
+        assert_eq!(command, ResolvedCommand { program: "viewer".to_string(), args: Vec::new() });
+    }
+
+    #[test]
+    fn build_command_needs_a_program() {
+        assert_eq!(build_command(&entry(&[]), None, None, &FeaturesConfig::default()), None);
+    }
+
+    #[test]
+    fn overrides_wrap_and_replace_the_exec_line() {
+        let wrapped = AppOverride {
+            prepend_args: strings(&["--profile", "work"]),
+            append_args: strings(&["--new-window"]),
+            ..AppOverride::default()
+        };
+        let entry = apply_override(entry(&["firefox", "%u"]), &wrapped);
+        assert_eq!(entry.exec_args, strings(&["firefox", "--profile", "work", "%u", "--new-window"]));
+        assert_eq!(
+            argv(&entry, Some(&["/tmp/x"]), None),
+            strings(&["firefox", "--profile", "work", "file:///tmp/x", "--new-window"])
+        );
+
+        let replaced = AppOverride {
+            exec: Some("env GDK_SCALE=2 viewer %F".to_string()),
+            append_args: strings(&["--fit"]),
+            ..AppOverride::default()
+        };
+        let entry = apply_override(self::entry(&["viewer", "%f"]), &replaced);
+        assert_eq!(argv(&entry, Some(&["/tmp/a"]), None), strings(&["env", "GDK_SCALE=2", "viewer", "/tmp/a", "--fit"]));
+    }
+
+    #[test]
+    fn launch_methods_wrap_the_command() {
+        let mut entry = entry(&["viewer"]);
+        entry.app_id = Some("org.example.Viewer+Beta.desktop".to_string());
+        let now = UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_789);
+        assert!(launch_wrapper(LaunchMethod::Spawn, &entry, now).is_empty());
+        assert_eq!(launch_wrapper(LaunchMethod::Uwsm, &entry, now), strings(&["uwsm", "app", "--"]));
+
+        let systemd = launch_wrapper(LaunchMethod::SystemdRun, &entry, now);
+        assert_eq!(launch_wrapper(LaunchMethod::SystemdRun, &entry, now), systemd);
+        assert_eq!(systemd[..4], strings(&["systemd-run", "--user", "--scope", "--quiet"]));
+        assert!(systemd[4].starts_with("--unit=app-qst-org.example.Viewer_Beta-"), "{}", systemd[4]);
+        assert_eq!(systemd.last().map(String::as_str), Some("--"));
+    }
+
+    #[test]
+    fn exec_lines_expand_field_codes() {
+        let desktop_path = Path::new("/usr/share/applications/viewer.desktop");
+        let parse = |exec: &str, icon: Option<&str>| parse_exec_line(exec, "Viewer", icon, desktop_path);
+        assert_eq!(
+            parse("viewer --name=%c %i --from=%k 100%% %d %D %n %N %v %m -x %F", Some("viewer")),
+            Some(strings(&[
+                "viewer",
+                "--name=Viewer",
+                "--icon",
+                "viewer",
+                "--from=/usr/share/applications/viewer.desktop",
+                "100%",
+                "-x",
+                "%F",
+            ]))
+        );
+        assert_eq!(parse("viewer %i %u", None), Some(strings(&["viewer", "%u"])));
+        assert_eq!(
+            parse(r#""/opt/My App/viewer" "--title=a \"b\"" %f"#, None),
+            Some(strings(&["/opt/My App/viewer", "--title=a \"b\"", "%f"]))
+        );
+        assert_eq!(parse("viewer --file=%f --url=%u", None), Some(strings(&["viewer", "--file=%f", "--url=%u"])));
+        assert_eq!(parse(r#"viewer "unclosed"#, None), None);
+        assert_eq!(parse("%d %D", None), None);
+    }
+
+    #[test]
+    fn embedded_file_codes_repeat_per_file() {
+        let args = strings(&["--file=%f", "--url=<%u>", "-v"]);
+        let files = strings(&["/tmp/a.txt", "/tmp/b.txt"]);
+        assert_eq!(
+            substitute_file_codes(&args, Some(&files), true),
+            strings(&[
+                "--file=/tmp/a.txt",
+                "--file=/tmp/b.txt",
+                "--url=<file:///tmp/a.txt>",
+                "--url=<file:///tmp/b.txt>",
+                "-v",
+            ])
+        );
+        assert_eq!(substitute_file_codes(&args, None, true), strings(&["-v"]));
+        assert_eq!(substitute_file_codes(&args, Some(&[]), true), strings(&["-v"]));
+
+        // Binaries and typed commands take `%f` literally.
+        assert_eq!(substitute_file_codes(&args, Some(&files), false), [args.clone(), files].concat());
+        assert_eq!(
+            argv(&entry(&["viewer", "--file=%f"]), Some(&["/tmp/a"]), None),
+            strings(&["viewer", "--file=/tmp/a"])
+        );
+    }
+
+    #[test]
+    fn file_uris_encode_local_paths_only() {
+        assert_eq!(file_uri("/tmp/a b/ü#1.txt"), "file:///tmp/a%20b/%C3%BC%231.txt");
+        assert_eq!(file_uri("https://example.com/a b"), "https://example.com/a b");
+        assert_eq!(file_uri("mailto:someone@example.com"), "mailto:someone@example.com");
+        assert_eq!(file_uri("--fullscreen"), "--fullscreen");
+        assert_eq!(file_uri("qst-missing-file"), "qst-missing-file");
+
+        // An existing relative `a:b` is a file in the working directory, not a URI with scheme `a`.
+        let dir = std::env::temp_dir().join(format!("qst-uri-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("notes:draft"), "").unwrap();
+        let cwd = std::env::current_dir().unwrap();
+        std::env::set_current_dir(&dir).unwrap();
+        let uri = file_uri("notes:draft");
+        std::env::set_current_dir(cwd).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        let dir = std::fs::canonicalize(std::env::temp_dir()).unwrap().join(dir.file_name().unwrap());
+        assert_eq!(uri, format!("file://{}/notes%3Adraft", dir.display()));
+    }
+
+    fn test_app(config: AppConfig, history: History) -> App {
+        let desktop = |name: &str, keywords: &[&str]| AppEntry {
+            keywords: strings(keywords),
+            ..AppEntry::new(name.to_string(), vec![name.to_lowercase()], EntryKind::Desktop)
+        };
+        let entries = vec![
+            desktop("Firefox", &["browser", "web"]),
+            desktop("Firefox Developer Edition", &["browser"]),
+            desktop("Files", &["folder"]),
+            desktop("Foot", &["terminal"]),
+            desktop("Hidden Thing", &[]),
+            desktop("mpv", &["video"]),
+            AppEntry::new("fish".to_string(), strings(&["fish"]), EntryKind::Binary),
+            AppEntry::new("firejail".to_string(), strings(&["firejail"]), EntryKind::Binary),
+        ];
+        let clock = Rc::new(crate::clock::ManualClock::new(UNIX_EPOCH));
+        App::from_parts(config, None, clock, history, entries, Vec::new())
+    }
+
+    fn hiding(name: &str) -> History {
+        let mut history = History::default();
+        history.toggle_hidden(name);
+        history
+    }
+
+    // What the list shows: each row's name and match tier, then the launch args.
+    type Filtered = (Vec<(String, MatchTier)>, Option<Vec<String>>);
+
+    fn filtered(app: &App) -> Filtered {
+        let rows = app.filtered_rows().map(|entry| entry.name.clone());
+        let tiers = app.filtered_matches.iter().map(|m| m.tier);
+        (rows.zip(tiers).collect(), app.launch_args.clone())
+    }
+
+    fn filter(app: &mut App, query: &str) -> Filtered {
+        app.set_search_query(query.to_string());
+        app.update_filter();
+        filtered(app)
+    }
+
+    // The filter as it was before rows became indices: every pass clones what it keeps.
+    fn clone_filter(app: &App, query: &str) -> Filtered {
+        let entries: Vec<AppEntry> = app.entries.clone();
+        let matching = |query: &str, tier: MatchTier, hidden: bool| {
+            let query = query.to_lowercase();
+            let folded = app.fold_query(&query);
+            let mut matches: Vec<(EntryMatch, AppEntry)> = entries
+                .iter()
+                .filter(|entry| app.is_hidden(entry) == hidden)
+                .filter_map(|entry| {
+                    match_entry(&query, folded.as_deref(), app.config.features.search_keywords, entry)
+                        .map(|(score, field)| (EntryMatch { tier, field, score }, entry.clone()))
+                })
+                .collect();
+            matches.sort_by_key(|m| (std::cmp::Reverse(m.0.score), m.1.kind == EntryKind::Binary));
+            matches.into_iter().map(|(m, entry)| (entry.name, m.tier)).collect::<Vec<_>>()
+        };
+
+        let query = query.trim();
+        if let Some(rest) = query.strip_prefix(HIDDEN_PREFIX) {
+            return (matching(rest.trim(), MatchTier::Full, true), None);
+        }
+        let mut launch_args = None;
+        let mut rows = if query.is_empty() {
+            let listed = entries.iter().filter(|entry| !app.is_hidden(entry));
+            listed.map(|entry| (entry.name.clone(), MatchTier::Unfiltered)).collect()
+        } else {
+            let mut rows = matching(query, MatchTier::Full, false);
+            if rows.is_empty() {
+                let words = split_args(query);
+                for i in (1..words.len()).rev() {
+                    rows = matching(&words[..i].join(" "), MatchTier::Partial, false);
+                    if !rows.is_empty() {
+                        launch_args = Some(words[i..].to_vec());
+                        break;
+                    }
+                }
+            }
+            rows
+        };
+        if let Some(url) = url_from_query(query) {
+            rows.insert(0, (format!("Open in browser: {}", url), MatchTier::Unfiltered));
+        }
+        (rows, launch_args)
+    }
+
+    const QUERIES: [&str; 14] = [
+        "",
+        "f",
+        "fi",
+        "fire",
+        "FIREFOX",
+        "web",
+        "hidden:",
+        "hidden: thing",
+        "thing",
+        "example.com/page",
+        "mpv --fs video.mkv",
+        "firefox dev notes.txt",
+        "files a b c",
+        "zzz",
+    ];
+
+    #[test]
+    fn index_filter_matches_the_clone_filter() {
+        let mut app = test_app(AppConfig::default(), hiding("Hidden Thing"));
+        for query in QUERIES {
+            let expected = clone_filter(&app, query);
+            assert_eq!(filter(&mut app, query), expected, "query {:?}", query);
+        }
+        assert_eq!(filter(&mut app, "example.com/page").0[0].0, "Open in browser: https://example.com/page");
+        assert_eq!(filter(&mut app, "mpv --fs video.mkv").1, Some(strings(&["--fs", "video.mkv"])));
+        assert_eq!(filter(&mut app, "hidden:").0, vec![("Hidden Thing".to_string(), MatchTier::Full)]);
+        assert!(filter(&mut app, "thing").0.is_empty());
+    }
+
+    #[test]
+    fn index_filter_keeps_the_favorite_selected() {
+        let mut app = test_app(AppConfig::default(), History::default());
+        filter(&mut app, "f");
+        app.list_state.select(Some(2));
+        let name = app.filtered_entry(2).unwrap().name.clone();
+        app.toggle_favorite();
+        let row = app.list_state.selected().unwrap();
+        assert_eq!(app.filtered_entry(row).unwrap().name, name);
+    }
+
+    fn type_and_compare(app: &mut App, text: &str) {
+        for ch in text.chars() {
+            app.insert_search_char(ch);
+            let query = app.search_query.clone();
+            assert_eq!(filtered(app), clone_filter(app, &query), "query {:?}", query);
+        }
+    }
+
+    #[test]
+    fn narrowing_matches_a_full_rescan() {
+        let mut app = test_app(AppConfig::default(), hiding("Hidden Thing"));
+        type_and_compare(&mut app, "firefox dev notes.txt");
+        assert_eq!(app.launch_args, Some(strings(&["notes.txt"])));
+        for _ in 0.."dev notes.txt".len() {
+            app.backspace_search_char();
+            let query = app.search_query.clone();
+            assert_eq!(filtered(&app), clone_filter(&app, &query), "query {:?}", query);
+        }
+        type_and_compare(&mut app, "web");
+
+        app.set_search_query(String::new());
+        app.update_filter();
+        type_and_compare(&mut app, "mpv -");
+        type_and_compare(&mut app, "-fs x");
+
+        #[cfg(feature = "transliterate")]
+        {
+            let mut config = AppConfig::default();
+            config.features.transliterate_search = true;
+            let entries = ["Юпитер", "Фото", "Foot"]
+                .map(|name| AppEntry::new(name.to_string(), strings(&["true"]), EntryKind::Desktop));
+            let clock = Rc::new(crate::clock::ManualClock::new(UNIX_EPOCH));
+            let mut app = App::from_parts(config, None, clock, History::default(), entries.to_vec(), Vec::new());
+            for query in ["jupiter", "photo"] {
+                app.set_search_query(String::new());
+                app.update_filter();
+                type_and_compare(&mut app, query);
+                assert_eq!(filtered(&app).0.len(), 1, "query {:?}", query);
+            }
+        }
+    }
+
+    #[test]
+    fn narrowing_resets_when_entries_change() {
+        let mut app = test_app(AppConfig::default(), History::default());
+        type_and_compare(&mut app, "f");
+        assert!(app.narrowing.is_some());
+
+        // Both re-sort entries, so the cached indices have to be rebuilt from a full scan.
+        let assert_cache_fresh = |app: &App| {
+            let (query, candidates) = app.narrowing.clone().unwrap();
+            let mut rescan: Vec<usize> =
+                app.match_entries(&query, MatchTier::Full, false).iter().map(|&(_, idx)| idx).collect();
+            rescan.sort_unstable();
+            assert_eq!(candidates, rescan);
+        };
+        app.list_state.select(Some(1));
+        app.toggle_favorite();
+        assert_cache_fresh(&app);
+        type_and_compare(&mut app, "i");
+
+        app.toggle_hidden();
+        assert_eq!(app.history.hidden.len(), 1);
+        assert_cache_fresh(&app);
+        type_and_compare(&mut app, "r");
+
+        app.sort_entries();
+        assert!(app.narrowing.is_none());
+        type_and_compare(&mut app, "e");
+    }
+
+    #[test]
+    fn split_args_follows_shell_quoting() {
+        assert_eq!(split_args(r#"mpv "file with spaces.mkv""#), strings(&["mpv", "file with spaces.mkv"]));
+        assert_eq!(split_args("mpv 'it''s here.mkv' --fs"), strings(&["mpv", "its here.mkv", "--fs"]));
+        assert_eq!(split_args(r"mpv file\ with\ spaces.mkv"), strings(&["mpv", "file with spaces.mkv"]));
+        assert_eq!(split_args(r#"echo "a \"quoted\" \\ word""#), strings(&["echo", r#"a "quoted" \ word"#]));
+        assert_eq!(split_args(r"echo 'no \escapes'"), strings(&["echo", r"no \escapes"]));
+        assert_eq!(split_args(r#"code --dir="my project" x"#), strings(&["code", "--dir=my project", "x"]));
+        assert_eq!(split_args("  spaced   out  "), strings(&["spaced", "out"]));
+        assert_eq!(split_args(r#"echo """#), strings(&["echo", ""]));
+        assert_eq!(split_args("trailing\\"), strings(&["trailing\\"]));
+    }
+
+    #[test]
+    fn split_args_keeps_unclosed_quotes_literal() {
+        assert_eq!(split_args(r#"mpv "half typed"#), strings(&["mpv", "\"half", "typed"]));
+        assert_eq!(split_args("mpv 'x"), strings(&["mpv", "'x"]));
+        assert_eq!(split_args(r#"mpv "a b" "c"#), strings(&["mpv", "a b", "\"c"]));
+    }
+
+    #[test]
+    fn quoted_launch_args_arrive_as_one_arg() {
+        let mut app = test_app(AppConfig::default(), History::default());
+        let (rows, args) = filter(&mut app, r#"mpv "file with spaces.mkv""#);
+        assert_eq!(rows, vec![("mpv".to_string(), MatchTier::Partial)]);
+        assert_eq!(args, Some(strings(&["file with spaces.mkv"])));
+        let command = build_command(app.filtered_entry(0).unwrap(), args.as_deref(), None, &app.config.features);
+        assert_eq!(command.unwrap().args, strings(&["file with spaces.mkv"]));
+    }
+
+    fn grid_app(columns: usize) -> App {
+        let mut config = AppConfig::default();
+        config.general.columns = columns;
+        let mut app = test_app(config, History::default());
+        filter(&mut app, "");
+        app
+    }
+
+    fn moves(app: &mut App, start: usize, steps: &[i32], step: fn(&mut App, i32)) -> Vec<usize> {
+        app.list_state.select(Some(start));
+        steps
+            .iter()
+            .map(|&delta| {
+                step(app, delta);
+                app.list_state.selected().unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn grid_rows_stop_at_the_edges() {
+        let mut app = grid_app(3);
+        assert_eq!(app.filtered_len(), 8);
+        // Rows are [0 1 2] [3 4 5] [6 7].
+        assert_eq!(moves(&mut app, 1, &[1, 1, 1, -1, -1, -1], App::move_row), [4, 7, 7, 4, 1, 1]);
+        // Down from above the short last row lands on its last item.
+        assert_eq!(moves(&mut app, 5, &[1, 1, -1], App::move_row), [7, 7, 4]);
+        assert_eq!(moves(&mut app, 6, &[1], App::move_row), [6]);
+        // Left/Right step through the items and wrap at the ends, as in a single column.
+        assert_eq!(moves(&mut app, 2, &[1, -1, -1], App::move_selection), [3, 2, 1]);
+        assert_eq!(moves(&mut app, 7, &[1, -1], App::move_selection), [0, 7]);
+
+        app.list_state.select(None);
+        app.move_row(1);
+        assert_eq!(app.list_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn one_column_moves_like_the_plain_list() {
+        let steps = [1, 1, -1, -1, -1, 3, 5, -8, 1];
+        let mut grid = grid_app(1);
+        let mut list = grid_app(1);
+        for start in 0..grid.filtered_len() {
+            assert_eq!(
+                moves(&mut grid, start, &steps, App::move_row),
+                moves(&mut list, start, &steps, App::move_selection)
+            );
+        }
+        assert_eq!(moves(&mut grid, 7, &[1, -1], App::move_row), [0, 7]);
+
+        // File and script lists ignore `general.columns`.
+        let mut files = grid_app(3);
+        files.mode = AppMode::FileSelection;
+        assert_eq!(files.grid_columns(), 1);
+    }
+
+    #[test]
+    fn grid_scrolls_by_rows_to_the_selection() {
+        let mut app = grid_app(2);
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(60, 16)).unwrap();
+        let mut draw = |app: &mut App| {
+            terminal.draw(|f| crate::ui::draw(f, app)).unwrap();
+            (app.list_state.offset(), app.list_height)
+        };
+
+        app.list_state.select(Some(0));
+        let (offset, rows) = draw(&mut app);
+        assert!((1..4).contains(&rows), "the 4 grid rows should fit partly, got {} rows", rows);
+        assert_eq!(offset, 0);
+
+        app.list_state.select(Some(7));
+        let (offset, _) = draw(&mut app);
+        assert_eq!(offset, (4 - rows) * 2, "the last row should be at the bottom");
+        app.move_row(-1);
+        assert_eq!(draw(&mut app).0, offset, "moving within the visible rows should not scroll");
+
+        app.list_state.select(Some(1));
+        assert_eq!(draw(&mut app).0, 0);
+    }
+
+    fn watching_app() -> (App, Rc<crate::clock::ManualClock>) {
+        let mut config = AppConfig::default();
+        config.features.capture_launch_errors = true;
+        let clock = Rc::new(crate::clock::ManualClock::new(UNIX_EPOCH));
+        let mut app = App::from_parts(config, None, clock.clone(), History::default(), Vec::new(), Vec::new());
+        app.history.keep_in_memory();
+        (app, clock)
+    }
+
+    #[test]
+    fn watched_launches_finish_on_the_app_clock() {
+        let (mut app, clock) = watching_app();
+        let entry = entry(&["sleep", "5"]);
+        app.spawn_command("sleep", strings(&["5"]), &entry);
+        app.poll_tasks();
+        assert!(!app.should_quit, "LAUNCH_WATCH hasn't passed on the clock yet");
+        assert_eq!(app.status_message.as_deref(), Some("Starting Viewer…"));
+
+        clock.advance(LAUNCH_WATCH);
+        app.poll_tasks();
+        assert!(app.should_quit);
+        assert_eq!(app.history.get_count("Viewer"), 1);
+        for child in &mut app.children {
+            let _ = child.kill();
+        }
+    }
+
+    #[test]
+    fn watched_launches_report_early_failures() {
+        let (mut app, _clock) = watching_app();
+        let entry = entry(&["sh"]);
+        app.spawn_command("sh", strings(&["-c", "echo boom >&2; exit 3"]), &entry);
+        settle_launches(&mut app);
+        assert!(!app.should_quit);
+        assert_eq!(app.status_message.as_deref(), Some("Viewer exited with exit status: 3: boom"));
+        assert_eq!(app.history.get_count("Viewer"), 0);
+    }
+
+    fn clipboard_app(command: &str) -> App {
+        let mut config = AppConfig::default();
+        config.general.clipboard_command = Some(command.to_string());
+        test_app(config, History::default())
+    }
+
+    #[test]
+    fn clipboard_copy_does_not_wait_for_forked_daemons() {
+        let dir = std::env::temp_dir().join(format!("qst-clipboard-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let copied = dir.join("copied");
+        // Like xclip: read the selection, then leave a process behind holding stderr.
+        let command = format!("cat > '{}'; sleep 5 &", copied.display());
+        let started = std::time::Instant::now();
+        assert_eq!(clipboard_app(&command).copy_to_clipboard("hello"), Ok(command));
+        assert!(started.elapsed() < Duration::from_secs(3), "waited {:?}", started.elapsed());
+        assert_eq!(fs::read_to_string(&copied).unwrap(), "hello");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn clipboard_failures_carry_stderr() {
+        let app = clipboard_app("cat > /dev/null; echo 'Error: no display' >&2; exit 1");
+        assert_eq!(app.copy_to_clipboard("x"), Err("Error: no display".to_string()));
+        let app = clipboard_app("exit 2");
+        assert_eq!(app.copy_to_clipboard("x"), Err("exit 2 exited with exit status: 2".to_string()));
+    }
+
+    fn settle_launches(app: &mut App) {
+        for _ in 0..100 {
+            if app.watched_launches.is_empty() {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+            app.poll_tasks();
+        }
+        panic!("the watched launch never finished");
+    }
+
+    #[test]
+    fn failing_launch_wrappers_fall_back_to_a_plain_spawn() {
+        let (mut app, _clock) = watching_app();
+        let entry = entry(&["true"]);
+        let (log, stderr) = LaunchLog::start(SystemTime::now()).unwrap();
+        let mut wrapper = detached_command("sh", &strings(&["-c", "echo 'Failed to connect to bus' >&2; exit 1"]));
+        let child = wrapper.stderr(stderr).spawn().unwrap();
+        drop(wrapper);
+        app.watch_launch(child, Some(log), &entry, "true", Vec::new(), LaunchRoute::Wrapped(LaunchMethod::SystemdRun));
+        settle_launches(&mut app);
+
+        assert!(!app.should_quit, "qst should stay open to show the fallback");
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("systemd-run exited with exit status: 1: Failed to connect to bus; started Viewer directly")
+        );
+        assert_eq!(app.history.get_count("Viewer"), 1);
+    }
+
+    #[test]
+    fn running_launch_wrappers_count_as_launched() {
+        let (mut app, clock) = watching_app();
+        let entry = entry(&["sleep"]);
+        let child = detached_command("sleep", &strings(&["5"])).spawn().unwrap();
+        app.watch_launch(child, None, &entry, "sleep", strings(&["5"]), LaunchRoute::Wrapped(LaunchMethod::Uwsm));
+        clock.advance(LAUNCH_WATCH);
+        app.poll_tasks();
+        assert!(app.should_quit);
+        assert_eq!(app.history.get_count("Viewer"), 1);
+        for child in &mut app.children {
+            let _ = child.kill();
+        }
+    }
+
+    #[test]
+    fn scripts_are_told_apart_from_binaries() {
+        let dir = std::env::temp_dir().join(format!("qst-script-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let files: [(&str, &[u8], bool); 6] = [
+            ("backup", b"#!/bin/sh\necho hi\n", true),
+            ("deploy.sh", b"echo no shebang\n", true),
+            ("tool", b"\x7fELF\x02\x01\x01", false),
+            ("hash", b"#", false),
+            ("empty", b"", false),
+            ("notes.txt", b"# #! not at the start", false),
+        ];
+        for (name, contents, _) in files {
+            fs::write(dir.join(name), contents).unwrap();
+        }
+        let detected: Vec<bool> = files.iter().map(|(name, _, _)| is_script(&dir.join(name))).collect();
+        let missing = is_script(&dir.join("missing"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(detected, files.map(|(_, _, script)| script));
+        assert!(!missing);
+    }
+
+    #[test]
+    fn launches_inside_the_watch_window_are_all_recorded() {
+        let (mut app, clock) = watching_app();
+        app.spawn_command("sleep", strings(&["5"]), &entry(&["sleep"]));
+        let second = AppEntry::new("Other".to_string(), strings(&["sleep"]), EntryKind::Desktop);
+        app.spawn_command("sleep", strings(&["5"]), &second);
+        assert_eq!(app.watched_launches.len(), 2);
+
+        clock.advance(LAUNCH_WATCH);
+        app.poll_tasks();
+        assert!(app.watched_launches.is_empty());
+        assert_eq!((app.history.get_count("Viewer"), app.history.get_count("Other")), (1, 1));
+        for child in &mut app.children {
+            let _ = child.kill();
+        }
+    }
+
+    #[test]
+    fn launch_logs_keep_only_the_start_of_stderr() {
+        let (mut log, stderr) = LaunchLog::start(SystemTime::now()).unwrap();
+        let mut chatty = detached_command("sh", &strings(&["-c", "echo first; yes 'more output' | head -c 200000"]));
+        chatty.stdout(stderr.try_clone().unwrap()).stderr(stderr);
+        let status = chatty.spawn().unwrap().wait().unwrap();
+        drop(chatty);
+        assert!(status.success(), "the app shouldn't see a broken pipe");
+        log.drain.wait().unwrap();
+
+        assert_eq!(log.file.metadata().unwrap().len(), 4096);
+        assert_eq!(
+            launch_failure("Chatty", "exit status: 1", &read_launch_log(Some(&mut log.file))),
+            "Chatty exited with exit status: 1: first · more output · more output"
+        );
+    }
+
+    #[test]
+    fn hooks_see_the_launch() {
+        let hook = r#"printf '%s|%s|%s|%s' "$FLARE_APP_NAME" "$FLARE_EXEC" "$FLARE_ARGS" "$QST_APP_NAME""#;
+        let output = hook_command(hook, "My App", "/usr/bin/app", &strings(&["--new", "a b.txt"]))
+            .stdout(Stdio::piped())
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "My App|/usr/bin/app|--new 'a b.txt'|My App");
+    }
+
+    #[test]
+    fn failing_pre_launch_hooks_cancel_the_launch() {
+        let mut config = AppConfig::default();
+        config.general.abort_on_hook_failure = true;
+        config.general.pre_launch_hook = Some("exit 3".to_string());
+        let mut app = test_app(config, History::default());
+        assert!(!app.run_pre_launch_hook("Viewer", "viewer", &[]));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Pre-launch hook failed (exit status: 3); not starting Viewer")
+        );
+        app.config.general.pre_launch_hook = Some("test \"$FLARE_EXEC\" = viewer".to_string());
+        assert!(app.run_pre_launch_hook("Viewer", "viewer", &[]));
+    }
+
+    #[test]
+    fn waits_give_up_after_the_timeout() {
+        let mut child = Command::new("sleep").arg("5").spawn().unwrap();
+        let started = Instant::now();
+        assert!(wait_timeout(&mut child, Duration::from_millis(50)).unwrap().is_none());
+        assert!(started.elapsed() < Duration::from_secs(2));
+        child.kill().unwrap();
+        assert!(wait_timeout(&mut child, Duration::from_secs(2)).unwrap().is_some());
+    }
+
+    #[test]
+    fn kill_mode_reads_owners_once_per_snapshot() {
+        let mut app = test_app(AppConfig::default(), History::default());
+        filter(&mut app, "kill");
+        assert!(app.showing_processes());
+        let owner = process::current_uid().wrapping_add(1);
+        app.processes = Some(vec![Process {
+            pid: 4242,
+            name: "zzqst-test".to_string(),
+            cmdline: String::new(),
+            uid: owner,
+            rss_kb: 0,
+        }]);
+        app.user_names = HashMap::from([(owner, "someone".to_string())]);
+
+        filter(&mut app, "kill zzqst");
+        assert_eq!(app.script_items.len(), 1);
+        assert!(app.script_items[0].title.starts_with("[someone] zzqst-test"));
+
+        app.refresh_processes();
+        assert!(app.processes.as_deref().unwrap().iter().all(|proc| proc.pid != 4242));
+    }
+
+    #[cfg(feature = "transliterate")]
+    #[test]
+    fn transliterated_names_match_by_sound() {
+        let mut config = AppConfig::default();
+        config.features.transliterate_search = true;
+        let entries = ["Файрфокс", "Телеграм", "Foot", "Firefox"]
+            .map(|name| AppEntry::new(name.to_string(), strings(&["true"]), EntryKind::Desktop));
+        let clock = Rc::new(crate::clock::ManualClock::new(UNIX_EPOCH));
+        let mut app = App::from_parts(config, None, clock, History::default(), entries.to_vec(), Vec::new());
+
+        let names = |app: &mut App, query: &str| -> Vec<String> {
+            let mut names: Vec<String> = filter(app, query).0.into_iter().map(|(name, _)| name).collect();
+            names.sort();
+            names
+        };
+        assert_eq!(names(&mut app, "firefox"), ["Firefox", "Файрфокс"]);
+        assert_eq!(names(&mut app, "fajr"), ["Файрфокс"]);
+        assert_eq!(names(&mut app, "telegram"), ["Телеграм"]);
+        assert_eq!(names(&mut app, "foot"), ["Foot"]);
+
+        // And the other way round: Cyrillic queries find Latin names.
+        assert_eq!(names(&mut app, "файрфокс"), ["Firefox", "Файрфокс"]);
+        assert_eq!(names(&mut app, "фаерфокс"), ["Firefox", "Файрфокс"]);
+        assert_eq!(names(&mut app, "фут"), ["Foot"]);
+        assert_eq!(names(&mut app, "телеграм"), ["Телеграм"]);
+    }
+
+    #[test]
+    fn polling_reports_when_the_screen_changes() {
+        let (mut app, clock) = watching_app();
+        assert!(app.poll_tasks(), "favorites are reconciled on the first poll");
+        assert!(!app.poll_tasks());
+        assert!(!app.is_animating());
+
+        let entry = entry(&["sleep", "5"]);
+        app.spawn_command("sleep", strings(&["5"]), &entry);
+        assert!(app.is_animating());
+        assert!(!app.poll_tasks());
+        clock.advance(LAUNCH_WATCH);
+        assert!(app.poll_tasks());
+        assert!(!app.is_animating());
+        for child in &mut app.children {
+            let _ = child.kill();
+        }
+    }
+
+    #[test]
+    fn brackets_in_file_names_stay_literal() {
+        let file = |path: &str| FileEntry {
+            path: path.to_string(),
+            is_dir: false,
+            broken_link: false,
+            size: 0,
+            modified: None,
+        };
+        let listing = [file("photo[1].jpg"), file("photo1.jpg"), file("photo2.jpg")];
+        let names = |query: &str| -> Vec<String> {
+            FileQuery::parse(&format!("/nonexistent/{}", query))
+                .filter(&listing, false)
+                .into_iter()
+                .map(|file| file.path.trim_start_matches("/nonexistent/").to_string())
+                .collect()
+        };
+
+        assert!(!is_glob_pattern("photo["));
+        assert!(!is_glob_pattern("photo[]"));
+        assert!(is_glob_pattern("photo[12].jpg"));
+        assert_eq!(names("photo["), ["photo[1].jpg"]);
+        assert_eq!(names("photo[1].jpg"), ["photo[1].jpg", "photo1.jpg"]);
+        assert_eq!(names("photo[12].jpg"), ["photo1.jpg", "photo2.jpg"]);
+        assert_eq!(names("*.jpg").len(), 3);
+    }
+
+    #[test]
+    fn desktop_mime_types_back_up_the_default_app() {
+        let mut app = test_app(AppConfig::default(), History::default());
+        let mime = "application/x-qst-test";
+        assert!(app.default_app_for_mime(mime).is_none());
+
+        app.entries[5].mime_types = strings(&["text/plain", mime]);
+        assert_eq!(app.default_app_for_mime(mime).map(|entry| entry.name), Some("mpv".to_string()));
+        app.entries[6].mime_types = strings(&[mime]);
+        assert_eq!(app.default_app_for_mime(mime).map(|entry| entry.name), Some("mpv".to_string()));
+    }
+
+    #[test]
+    fn launch_arg_paths_are_listed_in_the_background() {
+        let dir = std::env::temp_dir().join(format!("qst-arg-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("notes.txt"), "").unwrap();
+        let mut app = test_app(AppConfig::default(), History::default());
+
+        filter(&mut app, &format!("firefox {}/no", dir.display()));
+        assert_eq!(app.mode, AppMode::AppSelection);
+        app.settle_tasks();
+        assert_eq!(app.mode, AppMode::FileSelection);
+        let paths: Vec<&str> = app.filtered_files.iter().map(|file| file.path.as_str()).collect();
+        assert_eq!(paths, [format!("{}/notes.txt", dir.display())]);
+
+        filter(&mut app, &format!("firefox {}/zz", dir.display()));
+        app.settle_tasks();
+        assert_eq!(app.mode, AppMode::AppSelection);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn recursive_hits_are_ranked_before_the_cap() {
+        let dir = std::env::temp_dir().join(format!("qst-rank-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        for i in 0..RECURSIVE_MAX_RESULTS + 100 {
+            fs::write(dir.join(format!("n_e_e_d_l_e_{}", i)), "").unwrap();
+        }
+        fs::write(dir.join("sub/needle"), "").unwrap();
+
+        let query = format!("{}/**/needle", dir.display());
+        let files = App::list_completions(&query, &FeaturesConfig::default(), FileSort::default(), false);
+        assert_eq!(files.len(), RECURSIVE_MAX_RESULTS);
+        assert_eq!(files[0].path, format!("{}/sub/needle", dir.display()));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn late_listings_leave_a_completion_cycle_alone() {
+        let dir = std::env::temp_dir().join(format!("qst-cycle-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["alpha", "beta", "gamma"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+        let mut app = test_app(AppConfig::default(), History::default());
+        filter(&mut app, &format!("{}/", dir.display()));
+        app.settle_tasks();
+        assert_eq!(app.filtered_files.len(), 3);
+
+        app.auto_complete();
+        let (query, files) = (app.search_query.clone(), app.filtered_files.len());
+        app.apply_task_output(TaskOutput::Listing(dir.clone(), None, Ok(Vec::new())));
+        app.apply_task_output(TaskOutput::Files(Vec::new()));
+        assert_eq!(app.search_query, query);
+        assert_eq!(app.filtered_files.len(), files);
+
+        app.auto_complete();
+        assert_ne!(app.search_query, query, "the cycle moves on to the next file");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn overrides_of_excluded_apps_are_not_reported() {
+        let mut config = AppConfig::default();
+        for name in ["Firefox", "Hidden Desktop App", "Nonexistent"] {
+            config.app_overrides.insert(name.to_string(), Default::default());
+        }
+        let mut app = test_app(config, History::default());
+        let excluded = AppEntry::new("Hidden Desktop App".to_string(), strings(&["true"]), EntryKind::Desktop);
+        app.excluded_entries.push((excluded, "OnlyShowIn=KDE".to_string()));
+        app.status_message = None;
+        app.check_app_overrides();
+        assert_eq!(app.status_message.as_deref(), Some("[app-overrides] matches no app: Nonexistent"));
+    }
+
+    #[test]
+    fn apps_failing_through_a_wrapper_run_once() {
+        let (mut app, _clock) = watching_app();
+        let dir = std::env::temp_dir().join(format!("qst-wrapped-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let runs = dir.join("runs");
+        let args = strings(&["-c", &format!("echo run >> '{}'; exit 3", runs.display())]);
+        let entry = entry(&["sh"]);
+        let (log, stderr) = LaunchLog::start(SystemTime::now()).unwrap();
+        let mut wrapper = detached_command("sh", &args);
+        let child = wrapper.stderr(stderr).spawn().unwrap();
+        drop(wrapper);
+        app.watch_launch(child, Some(log), &entry, "sh", args, LaunchRoute::Wrapped(LaunchMethod::Uwsm));
+        settle_launches(&mut app);
+        std::thread::sleep(Duration::from_millis(200));
+
+        assert_eq!(fs::read_to_string(&runs).unwrap(), "run\n");
+        assert_eq!(app.status_message.as_deref(), Some("Viewer exited with exit status: 3"));
+        assert_eq!(app.history.get_count("Viewer"), 0);
+        assert!(!app.should_quit);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fallback_launches_are_watched_too() {
+        let (mut app, _clock) = watching_app();
+        let entry = entry(&["false"]);
+        let (log, stderr) = LaunchLog::start(SystemTime::now()).unwrap();
+        let mut wrapper = detached_command("sh", &strings(&["-c", "echo 'Failed to connect to bus' >&2; exit 1"]));
+        let child = wrapper.stderr(stderr).spawn().unwrap();
+        drop(wrapper);
+        app.watch_launch(child, Some(log), &entry, "false", Vec::new(), LaunchRoute::Wrapped(LaunchMethod::SystemdRun));
+        settle_launches(&mut app);
+
+        assert_eq!(app.status_message.as_deref(), Some("Viewer exited with exit status: 1"));
+        assert_eq!(app.history.get_count("Viewer"), 0);
+    }
+
+    #[test]
+    fn failed_spawns_are_not_counted_and_repeat_with_a_hint() {
+        let (mut app, _clock) = watching_app();
+        let entry = AppEntry {
+            desktop_path: Some(PathBuf::from("/usr/share/applications/viewer.desktop")),
+            ..entry(&["qst-test-missing-binary"])
+        };
+        app.spawn_command("qst-test-missing-binary", Vec::new(), &entry);
+        let first = app.status_message.clone().unwrap();
+        assert!(first.starts_with("Failed to launch Viewer: "), "{}", first);
+        assert!(!first.contains("$EDITOR"));
+
+        app.spawn_command("qst-test-missing-binary", Vec::new(), &entry);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some(format!(
+                "{} (×2) — open /usr/share/applications/viewer.desktop in $EDITOR to fix its Exec line",
+                first
+            ))
+            .as_deref()
+        );
+        assert_eq!(app.history.get_count("Viewer"), 0);
+        assert!(!app.should_quit);
+    }
+}
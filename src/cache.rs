@@ -0,0 +1,192 @@
+use crate::app::AppEntry;
+use dirs::cache_dir;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+const CACHE_VERSION: u32 = 8;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileStamp {
+    pub secs: u64,
+    pub nanos: u32,
+}
+
+impl FileStamp {
+    pub fn of(path: &Path) -> Option<Self> {
+        let modified = fs::metadata(path).ok()?.modified().ok()?;
+        let since_epoch = modified.duration_since(UNIX_EPOCH).ok()?;
+        Some(Self {
+            secs: since_epoch.as_secs(),
+            nanos: since_epoch.subsec_nanos(),
+        })
+    }
+}
+
+// `entry` is None for files that parsed but should not be listed (NoDisplay, Hidden, bad Exec),
+// so they are not re-parsed on every start either.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedFile {
+    pub stamp: FileStamp,
+    pub entry: Option<AppEntry>,
+}
+
+// Names, comments and keywords are cached already localized, so the whole cache is dropped when
+// the languages read from LC_ALL, LC_MESSAGES and LANG differ from the ones it was built for.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EntryCache {
+    version: u32,
+    #[serde(default)]
+    locales: Vec<String>,
+    files: HashMap<String, CachedFile>,
+}
+
+impl EntryCache {
+    fn path() -> Option<PathBuf> {
+        let mut path = cache_dir()?;
+        path.push("qst");
+        path.push("entries.toml");
+        Some(path)
+    }
+
+    pub fn load(locales: &[String]) -> Self {
+        let Some(path) = Self::path() else {
+            return Self::empty(locales);
+        };
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return Self::empty(locales);
+        };
+        Self::parse(&contents, locales)
+    }
+
+    fn parse(contents: &str, locales: &[String]) -> Self {
+        match toml::from_str::<EntryCache>(contents) {
+            Ok(cache) if cache.version == CACHE_VERSION && cache.locales == locales => cache,
+            _ => Self::empty(locales),
+        }
+    }
+
+    pub fn empty(locales: &[String]) -> Self {
+        Self {
+            version: CACHE_VERSION,
+            locales: locales.to_vec(),
+            files: HashMap::new(),
+        }
+    }
+
+    pub fn clear() {
+        if let Some(path) = Self::path() {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    pub fn lookup(&self, path: &Path, stamp: &FileStamp) -> Option<&CachedFile> {
+        self.files
+            .get(path.to_string_lossy().as_ref())
+            .filter(|cached| &cached.stamp == stamp)
+    }
+
+    pub fn insert(&mut self, path: &Path, cached: CachedFile) {
+        self.files.insert(path.to_string_lossy().into_owned(), cached);
+    }
+
+    pub fn save(&self) {
+        let Some(path) = Self::path() else {
+            return;
+        };
+        let Some(dir) = path.parent() else {
+            return;
+        };
+        if fs::create_dir_all(dir).is_err() {
+            return;
+        }
+        let Ok(contents) = toml::to_string(self) else {
+            return;
+        };
+        let tmp = path.with_extension("toml.tmp");
+        if fs::write(&tmp, contents).is_ok() {
+            let _ = fs::rename(&tmp, &path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{EntryKind, scan_desktop_dir};
+    use std::time::{Duration, SystemTime};
+
+    fn write_desktop_file(path: &Path, name: &str, modified: SystemTime) {
+        fs::write(path, format!("[Desktop Entry]\nType=Application\nName={}\nExec=true\n", name)).unwrap();
+        fs::File::options().write(true).open(path).unwrap().set_modified(modified).unwrap();
+    }
+
+    fn names(entries: &[AppEntry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.name.as_str()).collect()
+    }
+
+    #[test]
+    fn edited_desktop_files_are_parsed_again() {
+        let dir = std::env::temp_dir().join(format!("qst-cache-edit-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("viewer.desktop");
+        let modified = SystemTime::now() - Duration::from_secs(60);
+        write_desktop_file(&path, "Old Viewer", modified);
+
+        let mut first = EntryCache::empty(&[]);
+        assert_eq!(names(&scan_desktop_dir(dir.clone(), &[], &EntryCache::empty(&[]), &mut first)), ["Old Viewer"]);
+        let stamp = FileStamp::of(&path).unwrap();
+        assert!(first.lookup(&path, &stamp).is_some());
+
+        // An unchanged file comes from the cache, whatever it says now.
+        let mut cached = EntryCache::empty(&[]);
+        write_desktop_file(&path, "Unseen Edit", modified);
+        assert_eq!(names(&scan_desktop_dir(dir.clone(), &[], &first, &mut cached)), ["Old Viewer"]);
+
+        write_desktop_file(&path, "New Viewer", modified + Duration::from_secs(30));
+        let stamp = FileStamp::of(&path).unwrap();
+        assert!(first.lookup(&path, &stamp).is_none());
+        let mut second = EntryCache::empty(&[]);
+        assert_eq!(names(&scan_desktop_dir(dir.clone(), &[], &first, &mut second)), ["New Viewer"]);
+        assert!(second.lookup(&path, &stamp).is_some());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn removed_desktop_files_leave_the_cache() {
+        let dir = std::env::temp_dir().join(format!("qst-cache-remove-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (kept, removed) = (dir.join("kept.desktop"), dir.join("removed.desktop"));
+        write_desktop_file(&kept, "Kept", SystemTime::now());
+        write_desktop_file(&removed, "Removed", SystemTime::now());
+
+        let mut first = EntryCache::empty(&[]);
+        assert_eq!(scan_desktop_dir(dir.clone(), &[], &EntryCache::empty(&[]), &mut first).len(), 2);
+        assert_eq!(first.files.len(), 2);
+
+        fs::remove_file(&removed).unwrap();
+        let mut second = EntryCache::empty(&[]);
+        assert_eq!(names(&scan_desktop_dir(dir.clone(), &[], &first, &mut second)), ["Kept"]);
+        assert_eq!(second.files.keys().collect::<Vec<_>>(), [&kept.to_string_lossy().into_owned()]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn a_different_locale_drops_the_cache() {
+        let english = vec!["en_US".to_string(), "en".to_string()];
+        let mut cache = EntryCache::empty(&english);
+        let path = Path::new("/usr/share/applications/firefox.desktop");
+        let stamp = FileStamp { secs: 1, nanos: 0 };
+        let entry = AppEntry::new("Firefox".to_string(), vec!["firefox".to_string()], EntryKind::Desktop);
+        cache.insert(path, CachedFile { stamp: stamp.clone(), entry: Some(entry) });
+        let contents = toml::to_string(&cache).unwrap();
+
+        assert!(EntryCache::parse(&contents, &english).lookup(path, &stamp).is_some());
+        let german = vec!["de_DE".to_string(), "de".to_string()];
+        assert!(EntryCache::parse(&contents, &german).lookup(path, &stamp).is_none());
+    }
+}
@@ -0,0 +1,186 @@
+// Command-line parsing. The whole command line is checked before the terminal is touched, so a
+// typo prints an error instead of leaving the terminal in raw mode.
+
+use crate::history::expand_home;
+use std::path::PathBuf;
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const HELP: &str = "\
+Qst - An Application Launcher
+Usage: qst [OPTIONS]
+       qst <COMMAND>
+
+Options:
+  --config <PATH> Use the config file at PATH (also $FLARE_CONFIG); it must exist
+  --refresh-cache Ignore the cached desktop entries and rescan everything
+  -q, --query <TEXT>
+                  Start with TEXT already typed into the search
+  --toggle        Close the running instance instead of opening a second one
+  --debug         Enable the match diagnostics overlay (toggle with F12)
+  --print, --print-exec
+                  Print the selected command line to stdout instead of running it
+  --print-name    Print the selected entry's name to stdout instead of running it
+  -h, --help      Print this help message
+  -V, --version   Print the version
+
+Instead of opening the launcher:
+  --gen-config    Generate a default config file at ~/.config/qst/config.toml
+                  or the --config path (Fails if file already exists)
+  --dump-default-config
+                  Print the commented default config to stdout
+  --check-config  Report problems in config.toml without starting; exits 1 if any
+  --list          Print the entries the launcher would list, one name per line
+    --filter <TEXT>
+                  Only those matching TEXT, in the order the search would rank them
+    --list-exec   Follow each name with a tab and the command it runs
+  --list-excluded List apps hidden by OnlyShowIn/NotShowIn and why
+  --clear-history Forget usage counts, keeping favorites
+  --simulate-time <SCRIPT>
+                  Replay a key script against a virtual clock and print the frames
+
+Commands:
+  history doctor  Check favorites against installed apps and report missing ones
+";
+
+// Flags that take a value, as `--flag value` or `--flag=value`.
+const VALUE_FLAGS: [&str; 5] = ["--config", "-q", "--query", "--simulate-time", "--filter"];
+
+// Something to do instead of opening the launcher. Only one can be given.
+pub enum Command {
+    GenConfig,
+    DumpDefaultConfig,
+    CheckConfig,
+    List,
+    ListExcluded,
+    ClearHistory,
+    HistoryDoctor,
+    SimulateTime(PathBuf),
+    Help,
+    Version,
+}
+
+#[derive(Default)]
+pub struct Cli {
+    pub command: Option<Command>,
+    pub config: Option<PathBuf>,
+    pub query: Option<String>,
+    // `--list` options.
+    pub filter: Option<String>,
+    pub list_exec: bool,
+    pub refresh_cache: bool,
+    pub toggle: bool,
+    pub debug: bool,
+    pub print: bool,
+    pub print_name: bool,
+    pub panic_test: bool,
+}
+
+impl Cli {
+    // `args` excludes the program name. The error is a one-line message for stderr.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut cli = Cli::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+                _ => (arg.clone(), None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("`{}` needs a value", flag))
+            };
+            let command = match flag.as_str() {
+                "--config" => {
+                    // The shell leaves the `~` in `--config=~/x.toml` alone.
+                    cli.config = Some(expand_home(&value()?));
+                    None
+                }
+                "-q" | "--query" => {
+                    cli.query = Some(value()?);
+                    None
+                }
+                "--refresh-cache" => {
+                    cli.refresh_cache = true;
+                    None
+                }
+                "--toggle" => {
+                    cli.toggle = true;
+                    None
+                }
+                "--debug" => {
+                    cli.debug = true;
+                    None
+                }
+                "--print" | "--print-exec" => {
+                    cli.print = true;
+                    None
+                }
+                "--print-name" => {
+                    cli.print_name = true;
+                    None
+                }
+                "--filter" => {
+                    cli.filter = Some(value()?);
+                    None
+                }
+                "--list-exec" => {
+                    cli.list_exec = true;
+                    None
+                }
+                "--panic-test" if cfg!(debug_assertions) => {
+                    cli.panic_test = true;
+                    None
+                }
+                "--gen-config" => Some(Command::GenConfig),
+                "--dump-default-config" => Some(Command::DumpDefaultConfig),
+                "--check-config" => Some(Command::CheckConfig),
+                "--list" => Some(Command::List),
+                "--list-excluded" => Some(Command::ListExcluded),
+                "--clear-history" => Some(Command::ClearHistory),
+                "--simulate-time" => Some(Command::SimulateTime(PathBuf::from(value()?))),
+                "-h" | "--help" => Some(Command::Help),
+                "-V" | "--version" => Some(Command::Version),
+                "history" => match args.next().as_deref() {
+                    Some("doctor") => Some(Command::HistoryDoctor),
+                    _ => return Err("`history` needs a subcommand: `history doctor`".to_string()),
+                },
+                _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+                _ => return Err(format!("unexpected argument `{}`", arg)),
+            };
+            if inline.is_some() && !VALUE_FLAGS.contains(&flag.as_str()) {
+                return Err(format!("`{}` doesn't take a value", flag));
+            }
+            if let Some(command) = command {
+                if cli.command.is_some() {
+                    return Err(format!("`{}` can't be combined with another command", arg));
+                }
+                cli.command = Some(command);
+            }
+        }
+        if (cli.filter.is_some() || cli.list_exec) && !matches!(cli.command, Some(Command::List)) {
+            return Err("`--filter` and `--list-exec` only go with `--list`".to_string());
+        }
+        Ok(cli)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli, String> {
+        Cli::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn config_paths_expand_the_home_dir() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(parse(&["--config=~/work.toml"]).unwrap().config, Some(home.join("work.toml")));
+        assert_eq!(parse(&["--config", "~/work.toml"]).unwrap().config, Some(home.join("work.toml")));
+        assert_eq!(parse(&["--config", "/etc/qst.toml"]).unwrap().config, Some(PathBuf::from("/etc/qst.toml")));
+        assert!(parse(&["--config"]).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use std::{
+    cell::Cell,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    // Monotonic time since the clock was created.
+    fn elapsed(&self) -> Duration;
+
+    fn unix_secs(&self) -> u64 {
+        self.now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+}
+
+pub struct SystemClock {
+    started_at: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+}
+
+// Only moves when `advance` is called, so replays render the same frames every time.
+pub struct ManualClock {
+    start: SystemTime,
+    offset: Cell<Duration>,
+}
+
+impl ManualClock {
+    pub fn new(start: SystemTime) -> Self {
+        Self {
+            start,
+            offset: Cell::new(Duration::ZERO),
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.offset.set(self.offset.get() + by);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        self.start + self.offset.get()
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.offset.get()
+    }
+}
@@ -0,0 +1,339 @@
+use crate::app::AppEntry;
+use serde_json::Value;
+use std::{
+    env,
+    io::{Read, Write},
+    os::unix::net::UnixStream,
+    path::PathBuf,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+// How long a Hyprland request may take before the compositor is treated as unavailable, so a
+// wedged compositor can't hang the UI.
+const IPC_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone)]
+pub struct Window {
+    pub id: String,
+    pub app_id: Option<String>,
+    pub class: Option<String>,
+    pub title: Option<String>,
+}
+
+pub trait Compositor {
+    fn windows(&self) -> Option<Vec<Window>>;
+    fn focus(&self, window: &Window) -> bool;
+}
+
+pub struct Sway {
+    msg_command: &'static str,
+}
+
+impl Compositor for Sway {
+    fn windows(&self) -> Option<Vec<Window>> {
+        let tree = command_output(self.msg_command, &["-t", "get_tree"])?;
+        parse_sway_tree(&tree)
+    }
+
+    fn focus(&self, window: &Window) -> bool {
+        let criteria = format!("[con_id={}] focus", window.id);
+        command_succeeds(self.msg_command, &[&criteria])
+    }
+}
+
+// Talks to Hyprland's request socket directly, the same one `hyprctl` uses.
+pub struct Hyprland {
+    socket: PathBuf,
+}
+
+impl Hyprland {
+    fn from_env() -> Option<Self> {
+        let signature = env::var("HYPRLAND_INSTANCE_SIGNATURE").ok()?;
+        // Hyprland 0.40 moved the socket from /tmp/hypr to $XDG_RUNTIME_DIR/hypr.
+        let candidates = env::var_os("XDG_RUNTIME_DIR")
+            .map(|dir| PathBuf::from(dir).join("hypr"))
+            .into_iter()
+            .chain([PathBuf::from("/tmp/hypr")]);
+        let socket = candidates
+            .map(|dir| dir.join(&signature).join(".socket.sock"))
+            .find(|path| path.exists())?;
+        Some(Self { socket })
+    }
+
+    fn request(&self, request: &str) -> Option<String> {
+        let mut stream = UnixStream::connect(&self.socket).ok()?;
+        stream.set_read_timeout(Some(IPC_TIMEOUT)).ok()?;
+        stream.set_write_timeout(Some(IPC_TIMEOUT)).ok()?;
+        stream.write_all(request.as_bytes()).ok()?;
+        let mut reply = String::new();
+        stream.read_to_string(&mut reply).ok()?;
+        Some(reply)
+    }
+}
+
+impl Compositor for Hyprland {
+    fn windows(&self) -> Option<Vec<Window>> {
+        let clients = self.request("j/clients")?;
+        parse_hyprland_clients(&clients)
+    }
+
+    fn focus(&self, window: &Window) -> bool {
+        let reply = self.request(&format!("dispatch focuswindow address:{}", window.id));
+        reply.is_some_and(|reply| reply.trim() == "ok")
+    }
+}
+
+pub fn detect() -> Option<Box<dyn Compositor>> {
+    if let Some(hyprland) = Hyprland::from_env() {
+        return Some(Box::new(hyprland));
+    }
+    if env::var_os("SWAYSOCK").is_some() {
+        return Some(Box::new(Sway { msg_command: "swaymsg" }));
+    }
+    if env::var_os("I3SOCK").is_some() {
+        return Some(Box::new(Sway { msg_command: "i3-msg" }));
+    }
+    None
+}
+
+// Any failure along the way (no compositor, IPC error, no match) returns false so the caller
+// launches normally.
+pub fn focus_running(entry: &AppEntry) -> bool {
+    let Some(compositor) = detect() else {
+        return false;
+    };
+    let Some(windows) = compositor.windows() else {
+        return false;
+    };
+    match find_window(&windows, entry) {
+        Some(window) => compositor.focus(window),
+        None => false,
+    }
+}
+
+// Open windows for the `win` mode, or a message saying why there are none to show.
+pub fn list_windows() -> Result<Vec<Window>, String> {
+    windows_of(detect().as_deref())
+}
+
+fn windows_of(compositor: Option<&dyn Compositor>) -> Result<Vec<Window>, String> {
+    let compositor = compositor.ok_or_else(|| "Window switching needs Hyprland, Sway or i3".to_string())?;
+    compositor
+        .windows()
+        .ok_or_else(|| "Could not read the window list from the compositor".to_string())
+}
+
+pub fn focus(window: &Window) -> bool {
+    detect().is_some_and(|compositor| compositor.focus(window))
+}
+
+pub fn find_window<'a>(windows: &'a [Window], entry: &AppEntry) -> Option<&'a Window> {
+    let mut candidates = Vec::new();
+    if let Some(wm_class) = &entry.wm_class {
+        candidates.push(wm_class.to_lowercase());
+    }
+    if let Some(app_id) = &entry.app_id {
+        let app_id = app_id.to_lowercase();
+        // Reverse-DNS ids like org.gnome.Nautilus often report only the last segment as class.
+        if let Some(short) = app_id.rsplit('.').next()
+            && short != app_id
+        {
+            candidates.push(short.to_string());
+        }
+        candidates.push(app_id);
+    }
+    if candidates.is_empty() {
+        return None;
+    }
+
+    windows.iter().find(|window| {
+        [&window.app_id, &window.class]
+            .into_iter()
+            .flatten()
+            .any(|value| candidates.contains(&value.to_lowercase()))
+    })
+}
+
+pub fn parse_sway_tree(json: &str) -> Option<Vec<Window>> {
+    let root: Value = serde_json::from_str(json).ok()?;
+    let mut windows = Vec::new();
+    collect_sway_windows(&root, &mut windows);
+    Some(windows)
+}
+
+fn collect_sway_windows(node: &Value, windows: &mut Vec<Window>) {
+    let app_id = node.get("app_id").and_then(Value::as_str).map(str::to_string);
+    let class = node
+        .get("window_properties")
+        .and_then(|props| props.get("class"))
+        .and_then(Value::as_str)
+        .map(str::to_string);
+
+    if (app_id.is_some() || class.is_some())
+        && let Some(id) = node.get("id").and_then(Value::as_u64)
+    {
+        windows.push(Window {
+            id: id.to_string(),
+            app_id,
+            class,
+            title: node.get("name").and_then(Value::as_str).map(str::to_string),
+        });
+    }
+
+    for key in ["nodes", "floating_nodes"] {
+        if let Some(children) = node.get(key).and_then(Value::as_array) {
+            for child in children {
+                collect_sway_windows(child, windows);
+            }
+        }
+    }
+}
+
+pub fn parse_hyprland_clients(json: &str) -> Option<Vec<Window>> {
+    let clients: Vec<Value> = serde_json::from_str(json).ok()?;
+    let windows = clients
+        .iter()
+        .filter_map(|client| {
+            let id = client.get("address").and_then(Value::as_str)?.to_string();
+            let class = client
+                .get("class")
+                .and_then(Value::as_str)
+                .filter(|class| !class.is_empty())
+                .map(str::to_string);
+            let initial_class = client
+                .get("initialClass")
+                .and_then(Value::as_str)
+                .filter(|class| !class.is_empty())
+                .map(str::to_string);
+            // Unmapped helper surfaces report no class at all.
+            if class.is_none() && initial_class.is_none() {
+                return None;
+            }
+            Some(Window {
+                id,
+                app_id: initial_class,
+                class,
+                title: client.get("title").and_then(Value::as_str).map(str::to_string),
+            })
+        })
+        .collect();
+    Some(windows)
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+fn command_succeeds(program: &str, args: &[&str]) -> bool {
+    Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SWAY_TREE: &str = include_str!("../tests/fixtures/sway-tree.json");
+    const I3_TREE: &str = include_str!("../tests/fixtures/i3-tree.json");
+    const HYPRLAND_CLIENTS: &str = include_str!("../tests/fixtures/hyprland-clients.json");
+
+    // Replays a captured reply instead of asking a running compositor.
+    struct Fixture(fn(&str) -> Option<Vec<Window>>, &'static str);
+
+    impl Compositor for Fixture {
+        fn windows(&self) -> Option<Vec<Window>> {
+            (self.0)(self.1)
+        }
+
+        fn focus(&self, _: &Window) -> bool {
+            false
+        }
+    }
+
+    // id, app id, class, title
+    type Summary<'a> = (&'a str, Option<&'a str>, Option<&'a str>, Option<&'a str>);
+
+    fn summary(windows: &[Window]) -> Vec<Summary<'_>> {
+        windows
+            .iter()
+            .map(|w| (w.id.as_str(), w.app_id.as_deref(), w.class.as_deref(), w.title.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn sway_tree_lists_tiled_floating_and_scratchpad_windows() {
+        let windows = windows_of(Some(&Fixture(parse_sway_tree, SWAY_TREE))).unwrap();
+        assert_eq!(
+            summary(&windows),
+            vec![
+                ("31", Some("org.gnome.TextEditor"), None, Some("scratch notes")),
+                ("6", Some("foot"), None, Some("~/src/qst — fish")),
+                ("7", Some("firefox"), None, Some("Mozilla Firefox")),
+                ("8", None, Some("steam"), Some("Steam")),
+            ]
+        );
+    }
+
+    #[test]
+    fn i3_tree_lists_x11_windows_by_class() {
+        let windows = windows_of(Some(&Fixture(parse_sway_tree, I3_TREE))).unwrap();
+        assert_eq!(
+            summary(&windows),
+            vec![
+                ("94371045397312", None, Some("Gimp-2.10"), Some("GNU Image Manipulation Program")),
+                ("94371045412880", None, Some("URxvt"), Some("user@host: ~")),
+            ]
+        );
+    }
+
+    #[test]
+    fn hyprland_clients_skip_classless_surfaces() {
+        let windows = windows_of(Some(&Fixture(parse_hyprland_clients, HYPRLAND_CLIENTS))).unwrap();
+        assert_eq!(
+            summary(&windows),
+            vec![
+                ("0x5581d5a3c2a0", Some("kitty"), Some("kitty"), Some("nvim ~/notes.md")),
+                (
+                    "0x5581d5b91e70",
+                    Some("firefox"),
+                    Some("org.mozilla.firefox"),
+                    Some("Hyprland Wiki — Mozilla Firefox")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn list_windows_explains_failures() {
+        assert_eq!(windows_of(None).unwrap_err(), "Window switching needs Hyprland, Sway or i3");
+        let garbled = Fixture(parse_hyprland_clients, "ok");
+        assert_eq!(windows_of(Some(&garbled)).unwrap_err(), "Could not read the window list from the compositor");
+    }
+
+    #[test]
+    fn find_window_matches_short_app_ids() {
+        let windows = parse_hyprland_clients(HYPRLAND_CLIENTS).unwrap();
+        let exec = vec!["firefox".to_string()];
+        let mut entry = AppEntry::new("Firefox".to_string(), exec, crate::app::EntryKind::Desktop);
+        entry.app_id = Some("org.mozilla.firefox".to_string());
+        assert_eq!(find_window(&windows, &entry).map(|w| w.id.as_str()), Some("0x5581d5b91e70"));
+        entry.app_id = Some("org.gnome.Nautilus".to_string());
+        assert!(find_window(&windows, &entry).is_none());
+    }
+}
@@ -1,15 +1,25 @@
+use crate::history::expand_home;
 use dirs::config_dir;
 use ratatui::{
     prelude::*,
     widgets::{Block, BorderType, Borders},
 };
-use serde::de::Deserializer;
-use serde::{Deserialize, Serialize};
-use std::fs;
+use serde::de::{Deserializer, Error as _};
+use serde::{Deserialize, Serialize, Serializer};
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::SystemTime,
+};
 
 pub struct ConfigLoadResult {
     pub config: AppConfig,
     pub warning: Option<String>,
+    // The file couldn't be used and `config` is the built-in default.
+    pub used_defaults: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,64 +27,436 @@ pub struct ConfigLoadResult {
 pub struct AppConfig {
     pub general: GeneralConfig,
     pub features: FeaturesConfig,
-    pub window: SectionConfig,
+    pub window: WindowConfig,
     pub outer_box: SectionConfig,
     pub qst_ascii: QstAsciiConfig,
-    pub input: SectionConfig,
+    pub input: InputConfig,
     #[serde(alias = "results")]
     pub list: ResultsConfig,
+    pub scrollbar: ScrollbarConfig,
+    pub preview: PreviewConfig,
     pub entry: EntryConfig,
     pub entry_selected: SectionConfig,
     pub text: TextConfig,
+    // Style of the launch counts shown with `features.show-usage-count`.
+    pub usage: SectionConfig,
+    // Style of favorite rows, including their favorite symbol.
+    pub favorite: SectionConfig,
+    pub clipboard: ClipboardConfig,
+    pub emoji: EmojiConfig,
+    pub kill: KillConfig,
+    pub windows: WindowsConfig,
+    pub power_menu: PowerMenuConfig,
+    pub app_overrides: BTreeMap<String, AppOverride>,
+    pub bookmarks: BTreeMap<String, String>,
 }
 
+// Written as config.toml on first run and by `--gen-config`: every default, with comments. It
+// has to parse back into `AppConfig::default()`, so keep it in step with assets/defaults.rs.
+pub const DEFAULT_CONFIG: &str = include_str!("../assets/config.toml");
+
+// Set once from `--config` before anything loads the config.
+static EXPLICIT_PATH: OnceLock<PathBuf> = OnceLock::new();
+
 impl AppConfig {
+    // Only `~/.config/qst/config.toml` is created when missing; a file named with `--config` or
+    // `$FLARE_CONFIG` has to exist, and the defaults are used with a warning if it doesn't.
     pub fn load() -> ConfigLoadResult {
         let default = Self::default();
         let mut warning = None;
-        let config = match config_dir() {
-            Some(mut dir) => {
-                dir.push("qst");
-                if fs::create_dir_all(&dir).is_err() {
-                    warning = Some("Unable to create ~/.config/qst, using defaults".into());
-                    default
-                } else {
-                    let config_path = dir.join("config.toml");
-                    if config_path.exists() {
-                        match fs::read_to_string(&config_path) {
-                            Ok(contents) => match toml::from_str::<AppConfig>(&contents) {
-                                Ok(parsed) => parsed,
-                                Err(err) => {
-                                    warning = Some(format!(
-                                        "Invalid config ({}). Falling back to defaults.",
-                                        err
-                                    ));
-                                    default
-                                }
-                            },
-                            Err(err) => {
-                                warning = Some(format!(
-                                    "Failed to read config ({}). Using defaults.",
-                                    err
-                                ));
-                                default
-                            }
-                        }
+        let mut used_defaults = true;
+        let config = match Self::explicit_path() {
+            Some(config_path) if !config_path.exists() => {
+                warning = Some(format!("Config file {} not found. Using defaults.", config_path.display()));
+                default
+            }
+            Some(config_path) => Self::load_file(&config_path, &mut warning, &mut used_defaults),
+            None => match config_dir() {
+                Some(mut dir) => {
+                    dir.push("qst");
+                    if fs::create_dir_all(&dir).is_err() {
+                        warning = Some("Unable to create ~/.config/qst, using defaults".into());
+                        default
                     } else {
-                        if let Ok(serialized) = toml::to_string_pretty(&default) {
-                            let _ = fs::write(&config_path, serialized);
+                        let config_path = dir.join("config.toml");
+                        if config_path.exists() {
+                            Self::load_file(&config_path, &mut warning, &mut used_defaults)
+                        } else {
+                            let _ = fs::write(&config_path, DEFAULT_CONFIG);
+                            used_defaults = false;
+                            default
                         }
-                        default
                     }
                 }
+                None => {
+                    warning = Some("Could not locate configuration directory. Using defaults.".into());
+                    default
+                }
+            },
+        };
+        ConfigLoadResult {
+            config,
+            warning,
+            used_defaults,
+        }
+    }
+
+    fn load_file(config_path: &Path, warning: &mut Option<String>, used_defaults: &mut bool) -> Self {
+        match fs::read_to_string(config_path) {
+            Ok(contents) => match Self::parse(&contents, config_path) {
+                Ok((parsed, warnings)) => {
+                    *warning = Some(warnings.join(" · ")).filter(|warnings| !warnings.is_empty());
+                    *used_defaults = false;
+                    parsed
+                }
+                Err(err) => {
+                    *warning = Some(format!(
+                        "Invalid config ({}). Falling back to defaults.",
+                        err
+                    ));
+                    Self::default()
+                }
+            },
+            Err(err) => {
+                *warning = Some(format!(
+                    "Failed to read config ({}). Using defaults.",
+                    err
+                ));
+                Self::default()
             }
+        }
+    }
+
+    pub fn set_explicit_path(path: PathBuf) {
+        let _ = EXPLICIT_PATH.set(path);
+    }
+
+    // `--config <path>`, else the config named in the environment.
+    pub fn explicit_path() -> Option<PathBuf> {
+        EXPLICIT_PATH.get().cloned().or_else(|| env_config_path(|name| std::env::var_os(name)))
+    }
+
+    // Everything `--check-config` can find short of keybinding syntax: TOML errors, unknown keys,
+    // values of the wrong type, a missing theme and colors `parse_color` rejects. The config is
+    // returned when it parsed, for further checks.
+    pub fn check(contents: &str, path: &Path) -> (Option<Self>, Vec<String>) {
+        let mut problems = Vec::new();
+        let mut config = match Self::parse(contents, path) {
+            Ok((config, warnings)) => {
+                problems.extend(warnings);
+                config
+            }
+            Err(err) => {
+                problems.push(err.to_string().trim_end().to_string());
+                return (None, problems);
+            }
+        };
+        for (field, color) in config.color_fields() {
+            if parse_color(color).is_none() {
+                problems.push(format!("{}: unknown color \"{}\"", field, color));
+            }
+        }
+        (Some(config), problems)
+    }
+
+    fn color_fields(&mut self) -> Vec<(String, &mut String)> {
+        let sections = [
+            ("window", &mut self.window.section),
+            ("outer-box", &mut self.outer_box),
+            ("qst-ascii", &mut self.qst_ascii.section),
+            ("input", &mut self.input.section),
+            ("list", &mut self.list.section),
+            ("scrollbar", &mut self.scrollbar.section),
+            ("preview", &mut self.preview.section),
+            ("entry-selected", &mut self.entry_selected),
+            ("text", &mut self.text.section),
+            ("usage", &mut self.usage),
+            ("favorite", &mut self.favorite),
+        ];
+        let mut fields: Vec<(String, &mut String)> = Vec::new();
+        for (name, section) in sections {
+            let keys = [("fg", &mut section.fg), ("bg", &mut section.bg), ("border-color", &mut section.border_color)];
+            for (key, colors) in keys {
+                fields.extend(colors.iter_mut().map(|color| (format!("{}.{}", name, key), color)));
+            }
+        }
+        fields.extend(self.entry.fg.iter_mut().map(|color| ("entry.fg".to_string(), color)));
+        fields.extend(self.entry.bg.iter_mut().map(|color| ("entry.bg".to_string(), color)));
+        let gradient = self.qst_ascii.gradient_colors.iter_mut();
+        fields.extend(gradient.map(|color| ("qst-ascii.gradient-colors".to_string(), color)));
+        fields.extend(self.input.prompt_fg.as_mut().map(|color| ("input.prompt-fg".to_string(), color)));
+        fields.extend(self.power_menu.fg.as_mut().map(|color| ("power-menu.fg".to_string(), color)));
+        fields.extend(self.window.backdrop.as_mut().map(|color| ("window.backdrop".to_string(), color)));
+        fields
+    }
+
+    // Terminals have no alpha, so `#RRGGBBAA` colors are mixed with the window background (black
+    // if it isn't an RGB color) once, when the config is loaded.
+    fn blend_alpha_colors(&mut self) {
+        let background = match self.window.section.bg.first().and_then(|color| parse_color(color)) {
+            Some(Color::Rgb(r, g, b)) => [r, g, b],
+            _ => [0, 0, 0],
+        };
+        for (_, color) in self.color_fields() {
+            let Some((rgb, alpha)) = parse_hex_alpha(color) else {
+                continue;
+            };
+            let mix = |channel: usize| {
+                let blended = rgb[channel] as u32 * alpha as u32 + background[channel] as u32 * (255 - alpha as u32);
+                (blended / 255) as u8
+            };
+            *color = format!("#{:02x}{:02x}{:02x}", mix(0), mix(1), mix(2));
+        }
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        Self::explicit_path().or_else(|| config_dir().map(|dir| dir.join("qst").join("config.toml")))
+    }
+
+    // Modification time of the config file, polled by `features.auto-reload-config`.
+    pub fn modified() -> Option<SystemTime> {
+        fs::metadata(Self::path()?).and_then(|metadata| metadata.modified()).ok()
+    }
+
+    // The file is merged over the serialized defaults, with `general.theme` and then its includes
+    // in between, so a section only needs the keys it changes. A value of the wrong type is dropped with a warning
+    // instead of failing the whole file, and so is a key that changes nothing because no field
+    // reads it. Only a TOML syntax error is fatal.
+    fn parse(contents: &str, path: &Path) -> Result<(Self, Vec<String>), toml::de::Error> {
+        let mut overlay: toml::Table = toml::from_str(contents)?;
+        rename_aliases(&mut overlay);
+        let mut warnings = Vec::new();
+        let dir = path.parent().unwrap_or(Path::new("."));
+        let mut seen = vec![fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())];
+        merge_includes(&mut overlay, dir, &mut seen, &mut warnings);
+        let theme = overlay
+            .get("general")
+            .and_then(|general| general.get("theme"))
+            .and_then(toml::Value::as_str)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string);
+        if let Some(name) = theme {
+            match load_theme(&name) {
+                Ok(mut theme) => {
+                    rename_aliases(&mut theme);
+                    merge_tables(&mut theme, overlay);
+                    overlay = theme;
+                }
+                Err(message) => warnings.push(message),
+            }
+        }
+
+        let defaults = toml::Table::try_from(Self::default()).unwrap_or_default();
+        let mut leaves = Vec::new();
+        collect_leaves(&overlay, &mut Vec::new(), &mut leaves);
+        let mut table = defaults.clone();
+        merge_tables(&mut table, overlay);
+        if table.clone().try_into::<Self>().is_err() {
+            // Apply one value at a time to find the ones that don't fit their field.
+            table = defaults.clone();
+            for (path, value) in &leaves {
+                let mut candidate = table.clone();
+                insert_at(&mut candidate, path, value.clone());
+                match candidate.clone().try_into::<Self>() {
+                    Ok(_) => table = candidate,
+                    Err(err) => warnings.push(format!("{}: {} (ignored)", path.join("."), err.message().trim_end())),
+                }
+            }
+        }
+        let mut config: Self = table.clone().try_into()?;
+
+        // Optional fields are missing from the defaults too, so a key the defaults lack only counts
+        // as unknown when leaving it out gives the same config.
+        let shown = format!("{:?}", config);
+        let mut unknown: Vec<&[String]> = Vec::new();
+        for (path, _) in &leaves {
+            let absent = (1..=path.len()).find(|&len| value_at(&defaults, &path[..len]).is_none());
+            if let Some(len) = absent
+                && !unknown.contains(&&path[..len])
+            {
+                unknown.push(&path[..len]);
+            }
+        }
+        for path in unknown {
+            let mut without = table.clone();
+            if remove_at(&mut without, path).is_none() {
+                continue;
+            }
+            if without.try_into::<Self>().is_ok_and(|other| format!("{:?}", other) == shown) {
+                warnings.push(format!("unknown key `{}`", path.join(".")));
+            }
+        }
+
+        config.blend_alpha_colors();
+        Ok((config, warnings))
+    }
+}
+
+const BUILTIN_THEMES: [(&str, &str); 4] = [
+    ("catppuccin-mocha", include_str!("../assets/themes/catppuccin-mocha.toml")),
+    ("gruvbox", include_str!("../assets/themes/gruvbox.toml")),
+    ("nord", include_str!("../assets/themes/nord.toml")),
+    ("dracula", include_str!("../assets/themes/dracula.toml")),
+];
+
+// `~/.config/qst/themes/<name>.toml`, falling back to the built-in theme of that name.
+fn load_theme(name: &str) -> Result<toml::Table, String> {
+    let user_theme = config_dir().map(|dir| dir.join("qst").join("themes").join(format!("{}.toml", name)));
+    let contents = match user_theme.and_then(|path| fs::read_to_string(path).ok()) {
+        Some(contents) => contents,
+        None => match BUILTIN_THEMES.iter().find(|(builtin, _)| *builtin == name) {
+            Some((_, contents)) => contents.to_string(),
             None => {
-                warning = Some("Could not locate configuration directory. Using defaults.".into());
-                default
+                let builtins: Vec<&str> = BUILTIN_THEMES.iter().map(|(builtin, _)| *builtin).collect();
+                return Err(format!(
+                    "Theme \"{}\" not found in ~/.config/qst/themes or the built-in themes ({}).",
+                    name,
+                    builtins.join(", ")
+                ));
             }
+        },
+    };
+    toml::from_str(&contents).map_err(|err| format!("Invalid theme \"{}\" ({}).", name, err))
+}
+
+// Copies `overlay` into `base`, descending into tables so a key set in both keeps `overlay`'s
+// value without dropping `base`'s other keys.
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                merge_tables(base_table, overlay_table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+// Merges the files a top-level `include` names underneath `table`, in order so later ones win.
+// Names are relative to `dir`, the including file's directory, and an included file may include
+// others. `seen` holds the files being loaded, so a cycle is reported instead of followed.
+fn merge_includes(table: &mut toml::Table, dir: &Path, seen: &mut Vec<PathBuf>, warnings: &mut Vec<String>) {
+    let Some(include) = table.remove("include") else {
+        return;
+    };
+    let names: Vec<String> = match include {
+        toml::Value::String(name) => vec![name],
+        toml::Value::Array(names) if names.iter().all(toml::Value::is_str) => {
+            names.iter().filter_map(toml::Value::as_str).map(str::to_string).collect()
+        }
+        _ => {
+            warnings.push("include: expected a file name or a list of them (ignored)".to_string());
+            return;
+        }
+    };
+
+    let mut merged = toml::Table::new();
+    for name in names {
+        let path = dir.join(expand_home(&name));
+        let Ok(path) = fs::canonicalize(&path) else {
+            warnings.push(format!("include \"{}\": no such file (ignored)", name));
+            continue;
         };
-        ConfigLoadResult { config, warning }
+        if seen.contains(&path) {
+            warnings.push(format!("include \"{}\": includes itself (ignored)", name));
+            continue;
+        }
+        let parsed = fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|contents| toml::from_str::<toml::Table>(&contents).map_err(|err| err.to_string()));
+        let mut included = match parsed {
+            Ok(included) => included,
+            Err(err) => {
+                warnings.push(format!("include \"{}\": {} (ignored)", name, err.trim_end()));
+                continue;
+            }
+        };
+        rename_aliases(&mut included);
+        seen.push(path.clone());
+        merge_includes(&mut included, path.parent().unwrap_or(dir), seen, warnings);
+        seen.pop();
+        merge_tables(&mut merged, included);
+    }
+    merge_tables(&mut merged, std::mem::take(table));
+    *table = merged;
+}
+
+// Old spellings of keys, renamed before merging so a file using one doesn't set the field twice
+// alongside the defaults.
+const SECTION_ALIASES: [(&str, &str); 4] = [
+    ("border-gradient-angle", "border-angle"),
+    ("visable", "visible"),
+    ("applications-title", "apps-title"),
+    ("directories-title", "files-title"),
+];
+
+fn rename_aliases(table: &mut toml::Table) {
+    rename_key(table, "results", "list");
+    for (_, section) in table.iter_mut() {
+        let Some(section) = section.as_table_mut() else {
+            continue;
+        };
+        for (alias, key) in SECTION_ALIASES {
+            rename_key(section, alias, key);
+        }
+    }
+}
+
+fn rename_key(table: &mut toml::Table, alias: &str, key: &str) {
+    if let Some(value) = table.remove(alias) {
+        table.entry(key).or_insert(value);
+    }
+}
+
+// Every non-table value in `table` with its key path; empty tables count as values.
+fn collect_leaves(table: &toml::Table, path: &mut Vec<String>, leaves: &mut Vec<(Vec<String>, toml::Value)>) {
+    for (key, value) in table {
+        path.push(key.clone());
+        match value {
+            toml::Value::Table(inner) if !inner.is_empty() => collect_leaves(inner, path, leaves),
+            value => leaves.push((path.clone(), value.clone())),
+        }
+        path.pop();
+    }
+}
+
+fn value_at<'a>(table: &'a toml::Table, path: &[String]) -> Option<&'a toml::Value> {
+    let (last, parents) = path.split_last()?;
+    let mut table = table;
+    for key in parents {
+        table = table.get(key)?.as_table()?;
+    }
+    table.get(last)
+}
+
+// Sets the value at `path`, creating (or replacing non-table values with) tables on the way.
+fn insert_at(table: &mut toml::Table, path: &[String], value: toml::Value) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut table = table;
+    for key in parents {
+        let entry = table.entry(key.clone()).or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if !entry.is_table() {
+            *entry = toml::Value::Table(toml::Table::new());
+        }
+        let Some(inner) = entry.as_table_mut() else {
+            return;
+        };
+        table = inner;
     }
+    table.insert(last.clone(), value);
+}
+
+fn remove_at(table: &mut toml::Table, path: &[String]) -> Option<toml::Value> {
+    let (last, parents) = path.split_last()?;
+    let mut table = table;
+    for key in parents {
+        table = table.get_mut(key)?.as_table_mut()?;
+    }
+    table.remove(last)
 }
 
 impl Default for AppConfig {
@@ -83,8 +465,124 @@ impl Default for AppConfig {
     }
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct WindowConfig {
+    #[serde(flatten)]
+    pub section: SectionConfig,
+
+    // Unset sizes take the whole terminal; larger ones are clamped to it.
+    pub width: Option<Dimension>,
+    pub height: Option<Dimension>,
+    pub anchor: Anchor,
+    // Fills the terminal around a smaller window.
+    pub backdrop: Option<String>,
+}
+
+impl WindowConfig {
+    pub fn area(&self, screen: Rect) -> Rect {
+        let width = self.width.map_or(screen.width, |width| width.resolve(screen.width));
+        let height = self.height.map_or(screen.height, |height| height.resolve(screen.height));
+        let free_x = screen.width - width;
+        let free_y = screen.height - height;
+        let (x, y) = match self.anchor {
+            Anchor::Center => (free_x / 2, free_y / 2),
+            Anchor::Top => (free_x / 2, 0),
+            Anchor::Bottom => (free_x / 2, free_y),
+            Anchor::Left => (0, free_y / 2),
+            Anchor::Right => (free_x, free_y / 2),
+            Anchor::TopLeft => (0, 0),
+            Anchor::TopRight => (free_x, 0),
+            Anchor::BottomLeft => (0, free_y),
+            Anchor::BottomRight => (free_x, free_y),
+        };
+        Rect::new(screen.x + x, screen.y + y, width, height)
+    }
+}
+
+// A size in cells (`60`) or as a share of the terminal (`"50%"`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dimension {
+    Cells(u16),
+    Percent(u16),
+}
+
+impl Dimension {
+    fn resolve(self, total: u16) -> u16 {
+        let size = match self {
+            Dimension::Cells(cells) => cells,
+            Dimension::Percent(percent) => (total as u32 * percent.min(100) as u32 / 100) as u16,
+        };
+        size.max(1).min(total)
+    }
+}
+
+impl Serialize for Dimension {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Dimension::Cells(cells) => serializer.serialize_u16(*cells),
+            Dimension::Percent(percent) => serializer.serialize_str(&format!("{}%", percent)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Dimension {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum DimensionInput {
+            Cells(u16),
+            Text(String),
+        }
+
+        match DimensionInput::deserialize(deserializer)? {
+            DimensionInput::Cells(cells) => Ok(Dimension::Cells(cells)),
+            DimensionInput::Text(text) => {
+                let trimmed = text.trim();
+                let parsed = match trimmed.strip_suffix('%') {
+                    Some(percent) => percent.trim().parse().map(Dimension::Percent),
+                    None => trimmed.parse().map(Dimension::Cells),
+                };
+                parsed.map_err(|_| D::Error::custom(format!("invalid size {:?}, expected cells or a percentage", text)))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Anchor {
+    #[default]
+    Center,
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default, rename_all = "kebab-case")]
+pub struct InputConfig {
+    #[serde(flatten)]
+    pub section: SectionConfig,
+
+    // Shown dimmed while the query is empty; never part of the query itself.
+    pub placeholder: Option<String>,
+    // Drawn before the query, e.g. "run ❯ ". Setting it also turns on the per-mode prompts, which
+    // default to "files ❯ ", "scripts ❯ " and "open with ❯ ".
+    pub prompt: Option<String>,
+    pub files_prompt: Option<String>,
+    pub scripts_prompt: Option<String>,
+    pub open_with_prompt: Option<String>,
+    pub prompt_fg: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
 pub struct ResultsConfig {
     #[serde(flatten)]
     pub section: SectionConfig,
@@ -95,12 +593,40 @@ pub struct ResultsConfig {
     pub files_title: Option<String>,
 }
 
-impl Default for ResultsConfig {
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ScrollbarConfig {
+    #[serde(flatten)]
+    pub section: SectionConfig,
+
+    pub track_symbol: Option<String>,
+    pub thumb_symbol: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PreviewConfig {
+    #[serde(flatten)]
+    pub section: SectionConfig,
+
+    pub width_percent: u16,
+    pub max_lines: usize,
+    pub max_entries: usize,
+    pub max_bytes: u64,
+}
+
+impl Default for PreviewConfig {
     fn default() -> Self {
         Self {
-            section: SectionConfig::default(),
-            apps_title: None,
-            files_title: None,
+            section: SectionConfig {
+                title: Some(String::from(" Preview ")),
+                visible: Some(false),
+                ..SectionConfig::default()
+            },
+            width_percent: 40,
+            max_lines: 50,
+            max_entries: 50,
+            max_bytes: 64 * 1024,
         }
     }
 }
@@ -179,31 +705,158 @@ pub struct PaddingConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default, rename_all = "kebab-case")]
 pub struct GeneralConfig {
+    pub theme: Option<String>,
     pub rounded_corners: bool,
     pub show_borders: bool,
     pub highlight_symbol: Option<String>,
     pub favorite_symbol: Option<String>,
+    // Marks where a name or path too long for its row was cut.
+    pub ellipsis: Option<String>,
     pub favorite_key: Option<String>,
+    pub favorite_up_key: Option<String>,
+    pub favorite_down_key: Option<String>,
+    pub numbered_favorites: bool,
+    pub columns: usize,
+    pub layout: ListLayout,
+    pub max_visible_entries: usize,
+    pub hide_key: Option<String>,
     pub jump_to_top_key: Option<String>,
     pub jump_to_bottom_key: Option<String>,
+    pub debug_overlay_key: Option<String>,
+    pub half_page_down_key: Option<String>,
+    pub half_page_up_key: Option<String>,
+    pub file_sort_key: Option<String>,
+    pub hidden_files_key: Option<String>,
+    pub bookmark_key: Option<String>,
+    pub open_with_key: Option<String>,
+    pub delete_component_key: Option<String>,
+    pub parent_dir_key: Option<String>,
+    pub mark_key: Option<String>,
+    pub recursive_key: Option<String>,
+    pub open_dir_key: Option<String>,
+    pub keep_open_key: Option<String>,
+    pub run_detached_key: Option<String>,
+    pub copy_key: Option<String>,
+    pub force_kill_key: Option<String>,
+    pub process_refresh_key: Option<String>,
+    pub window_switcher_key: Option<String>,
+    pub print_key: Option<String>,
+    pub clear_history_key: Option<String>,
+    pub reload_config_key: Option<String>,
+    pub help_key: Option<String>,
+    pub dismiss_warning_key: Option<String>,
+    pub paste_key: Option<String>,
+    pub tick_rate_ms: u64,
+    pub desktop_environment: Option<String>,
     pub clipboard_command: Option<String>,
+    pub paste_command: Option<String>,
+    pub file_opener: Option<String>,
+    pub dir_opener: Option<String>,
+    pub terminal: Option<String>,
+    pub history_path: Option<String>,
+    pub launch_method: LaunchMethod,
+    pub pre_launch_hook: Option<String>,
+    pub post_launch_hook: Option<String>,
+    pub abort_on_hook_failure: bool,
 }
 
 impl Default for GeneralConfig {
     fn default() -> Self {
         Self {
+            theme: None,
             rounded_corners: true,
             show_borders: true,
             highlight_symbol: Some(String::from(">> ")),
             favorite_symbol: Some(String::from("★ ")),
+            ellipsis: Some(String::from("…")),
             favorite_key: Some(String::from("alt+f")),
+            favorite_up_key: Some(String::from("alt+shift+up")),
+            favorite_down_key: Some(String::from("alt+shift+down")),
+            numbered_favorites: false,
+            columns: 1,
+            layout: ListLayout::Top,
+            max_visible_entries: 0,
+            hide_key: Some(String::from("alt+h")),
             jump_to_top_key: Some(String::from("alt+up")),
             jump_to_bottom_key: Some(String::from("alt+down")),
+            debug_overlay_key: None,
+            half_page_down_key: None,
+            half_page_up_key: None,
+            file_sort_key: None,
+            hidden_files_key: Some(String::from("ctrl+h")),
+            bookmark_key: Some(String::from("alt+b")),
+            open_with_key: Some(String::from("ctrl+o")),
+            delete_component_key: Some(String::from("ctrl+backspace")),
+            parent_dir_key: Some(String::from("alt+left")),
+            mark_key: Some(String::from("ctrl+space")),
+            recursive_key: Some(String::from("alt+r")),
+            open_dir_key: Some(String::from("ctrl+enter")),
+            keep_open_key: Some(String::from("alt+enter")),
+            run_detached_key: Some(String::from("shift+enter")),
+            copy_key: Some(String::from("ctrl+y")),
+            force_kill_key: Some(String::from("alt+enter")),
+            process_refresh_key: Some(String::from("ctrl+r")),
+            window_switcher_key: Some(String::from("alt+w")),
+            print_key: Some(String::from("ctrl+p")),
+            clear_history_key: Some(String::from("alt+c")),
+            reload_config_key: Some(String::from("ctrl+r")),
+            help_key: Some(String::from("f1")),
+            dismiss_warning_key: Some(String::from("alt+x")),
+            paste_key: Some(String::from("ctrl+v")),
+            tick_rate_ms: 80,
+            desktop_environment: None,
             clipboard_command: None,
+            paste_command: None,
+            file_opener: None,
+            dir_opener: None,
+            terminal: None,
+            history_path: None,
+            launch_method: LaunchMethod::Spawn,
+            pre_launch_hook: None,
+            post_launch_hook: None,
+            abort_on_hook_failure: false,
         }
     }
 }
 
+impl GeneralConfig {
+    // Every keybinding setting by its config name, for `--check-config`.
+    pub fn key_bindings(&self) -> Vec<(&'static str, Option<&str>)> {
+        vec![
+            ("favorite-key", self.favorite_key.as_deref()),
+            ("favorite-up-key", self.favorite_up_key.as_deref()),
+            ("favorite-down-key", self.favorite_down_key.as_deref()),
+            ("hide-key", self.hide_key.as_deref()),
+            ("jump-to-top-key", self.jump_to_top_key.as_deref()),
+            ("jump-to-bottom-key", self.jump_to_bottom_key.as_deref()),
+            ("debug-overlay-key", self.debug_overlay_key.as_deref()),
+            ("half-page-down-key", self.half_page_down_key.as_deref()),
+            ("half-page-up-key", self.half_page_up_key.as_deref()),
+            ("file-sort-key", self.file_sort_key.as_deref()),
+            ("hidden-files-key", self.hidden_files_key.as_deref()),
+            ("bookmark-key", self.bookmark_key.as_deref()),
+            ("open-with-key", self.open_with_key.as_deref()),
+            ("delete-component-key", self.delete_component_key.as_deref()),
+            ("parent-dir-key", self.parent_dir_key.as_deref()),
+            ("mark-key", self.mark_key.as_deref()),
+            ("recursive-key", self.recursive_key.as_deref()),
+            ("open-dir-key", self.open_dir_key.as_deref()),
+            ("keep-open-key", self.keep_open_key.as_deref()),
+            ("run-detached-key", self.run_detached_key.as_deref()),
+            ("copy-key", self.copy_key.as_deref()),
+            ("force-kill-key", self.force_kill_key.as_deref()),
+            ("process-refresh-key", self.process_refresh_key.as_deref()),
+            ("window-switcher-key", self.window_switcher_key.as_deref()),
+            ("print-key", self.print_key.as_deref()),
+            ("clear-history-key", self.clear_history_key.as_deref()),
+            ("reload-config-key", self.reload_config_key.as_deref()),
+            ("help-key", self.help_key.as_deref()),
+            ("dismiss-warning-key", self.dismiss_warning_key.as_deref()),
+            ("paste-key", self.paste_key.as_deref()),
+        ]
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default, rename_all = "kebab-case")]
 pub struct FeaturesConfig {
@@ -213,6 +866,37 @@ pub struct FeaturesConfig {
     pub dirs_first: bool,
     pub show_duplicates: bool,
     pub recent_first: bool,
+    pub include_path_binaries: bool,
+    pub transliterate_search: bool,
+    pub cache_entries: bool,
+    pub focus_if_running: bool,
+    pub show_missing_favorites: bool,
+    pub search_keywords: bool,
+    pub ignore_show_in: bool,
+    pub exclude: Vec<String>,
+    pub single_instance: bool,
+    pub file_details: bool,
+    pub file_sort: FileSort,
+    pub show_hidden_files: bool,
+    pub recent_files_limit: usize,
+    pub internal_mime_resolution: bool,
+    pub url_open: bool,
+    pub power_menu: bool,
+    pub history_max_entries: usize,
+    pub history_max_age_days: u64,
+    pub show_usage_count: bool,
+    pub show_match_count: bool,
+    pub detailed_entries: bool,
+    pub auto_reload_config: bool,
+    pub backspace_path_components: bool,
+    pub max_file_results: usize,
+    pub recursive_depth: usize,
+    pub ignore_dirs: Vec<String>,
+    pub expand_env_vars: bool,
+    pub undefined_env_vars: UndefinedEnvVars,
+    pub close_on_launch: bool,
+    pub capture_launch_errors: bool,
+    pub run_scripts_in_terminal: bool,
 }
 
 impl Default for FeaturesConfig {
@@ -224,6 +908,155 @@ impl Default for FeaturesConfig {
             dirs_first: true,
             show_duplicates: false,
             recent_first: true,
+            include_path_binaries: false,
+            transliterate_search: false,
+            cache_entries: true,
+            focus_if_running: false,
+            show_missing_favorites: false,
+            search_keywords: true,
+            ignore_show_in: false,
+            exclude: Vec::new(),
+            single_instance: false,
+            file_details: false,
+            file_sort: FileSort::Name,
+            show_hidden_files: false,
+            recent_files_limit: 100,
+            internal_mime_resolution: false,
+            url_open: true,
+            power_menu: false,
+            history_max_entries: 0,
+            history_max_age_days: 0,
+            show_usage_count: false,
+            show_match_count: false,
+            detailed_entries: false,
+            auto_reload_config: false,
+            backspace_path_components: true,
+            max_file_results: 500,
+            recursive_depth: 2,
+            ignore_dirs: vec![String::from(".git"), String::from("node_modules")],
+            expand_env_vars: false,
+            undefined_env_vars: UndefinedEnvVars::Literal,
+            close_on_launch: true,
+            capture_launch_errors: false,
+            run_scripts_in_terminal: true,
+        }
+    }
+}
+
+// `list-command` prints one history entry per line; `select-command` gets the chosen line
+// shell-quoted in place of `{selection}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ClipboardConfig {
+    pub prefix: String,
+    pub list_command: String,
+    pub select_command: String,
+}
+
+impl Default for ClipboardConfig {
+    fn default() -> Self {
+        Self {
+            prefix: String::from("clip"),
+            list_command: String::from("cliphist list"),
+            select_command: String::from("printf '%s' {selection} | cliphist decode | wl-copy"),
+        }
+    }
+}
+
+// `prefix` followed by a space lists running processes; an empty prefix turns the mode off.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct KillConfig {
+    pub prefix: String,
+}
+
+impl Default for KillConfig {
+    fn default() -> Self {
+        Self {
+            prefix: String::from("kill"),
+        }
+    }
+}
+
+// `prefix` followed by a space lists open windows on Hyprland, Sway or i3.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct WindowsConfig {
+    pub prefix: String,
+}
+
+impl Default for WindowsConfig {
+    fn default() -> Self {
+        Self {
+            prefix: String::from("win"),
+        }
+    }
+}
+
+// Launch tweaks for one app, keyed by its name or desktop file id in `[app-overrides]`. `exec`
+// replaces the Exec line (field codes included); the args go around whichever command is used.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct AppOverride {
+    pub env: BTreeMap<String, String>,
+    pub prepend_args: Vec<String>,
+    pub append_args: Vec<String>,
+    pub exec: Option<String>,
+}
+
+// Session actions listed as apps when `features.power-menu` is on. Each runs through `sh -c`
+// after a second Enter; an empty command leaves that action out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PowerMenuConfig {
+    pub fg: Option<String>,
+    pub count_usage: bool,
+    pub shutdown: String,
+    pub reboot: String,
+    pub suspend: String,
+    pub lock: String,
+    pub logout: String,
+}
+
+impl Default for PowerMenuConfig {
+    fn default() -> Self {
+        Self {
+            fg: Some(String::from("red")),
+            count_usage: false,
+            shutdown: String::from("systemctl poweroff"),
+            reboot: String::from("systemctl reboot"),
+            suspend: String::from("systemctl suspend"),
+            lock: String::from("loginctl lock-session"),
+            logout: String::from("loginctl terminate-session \"$XDG_SESSION_ID\""),
+        }
+    }
+}
+
+impl PowerMenuConfig {
+    pub fn actions(&self) -> [(&'static str, &str); 5] {
+        [
+            ("Shutdown", &self.shutdown),
+            ("Reboot", &self.reboot),
+            ("Suspend", &self.suspend),
+            ("Lock", &self.lock),
+            ("Logout", &self.logout),
+        ]
+    }
+}
+
+// `type-command` types the glyph (shell-quoted in place of `{glyph}`) instead of copying it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct EmojiConfig {
+    pub prefix: String,
+    pub type_command: Option<String>,
+}
+
+impl Default for EmojiConfig {
+    fn default() -> Self {
+        Self {
+            prefix: String::from(":"),
+            type_command: None,
         }
     }
 }
@@ -247,6 +1080,10 @@ pub struct SectionConfig {
     #[serde(alias = "visable")]
     pub visible: Option<bool>,
     pub title_alignment: Option<TextAlignment>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub underline: Option<bool>,
+    pub dim: Option<bool>,
 }
 
 impl SectionConfig {
@@ -262,6 +1099,19 @@ impl SectionConfig {
         if let Some(color) = self.bg.first().and_then(|v| parse_color(v)) {
             style = style.bg(color);
         }
+        // `false` is kept as a removal so a section can switch off a modifier it would inherit.
+        for (flag, modifier) in [
+            (self.bold, Modifier::BOLD),
+            (self.italic, Modifier::ITALIC),
+            (self.underline, Modifier::UNDERLINED),
+            (self.dim, Modifier::DIM),
+        ] {
+            style = match flag {
+                Some(true) => style.add_modifier(modifier),
+                Some(false) => style.remove_modifier(modifier),
+                None => style,
+            };
+        }
         style
     }
 
@@ -273,7 +1123,7 @@ impl SectionConfig {
         self.borders.unwrap_or(general.show_borders)
     }
 
-    pub fn block_with_title<'a>(&self, general: &GeneralConfig, title: &'a str) -> Block<'a> {
+    pub fn block_with_title<'a>(&self, general: &GeneralConfig, title: impl Into<Line<'a>>) -> Block<'a> {
         let mut block = Block::default().title(title);
 
         block = block.title_alignment(self.title_alignment.unwrap_or(TextAlignment::Left).into());
@@ -336,6 +1186,10 @@ impl Default for SectionConfig {
             borders: None,
             visible: None,
             title_alignment: None,
+            bold: None,
+            italic: None,
+            underline: None,
+            dim: None,
         }
     }
 }
@@ -374,6 +1228,91 @@ impl Default for TextConfig {
     }
 }
 
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self {
+            section: SectionConfig {
+                title: Some(String::from(" Search ")),
+                border_color: vec![String::from("#6464ff")],
+                ..SectionConfig::default()
+            },
+            placeholder: Some(String::from("Type to search, / for files, ~ for home…")),
+            prompt: None,
+            files_prompt: None,
+            scripts_prompt: None,
+            open_with_prompt: None,
+            prompt_fg: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileSort {
+    #[default]
+    Name,
+    Modified,
+    Size,
+}
+
+impl FileSort {
+    pub fn next(self) -> Self {
+        match self {
+            FileSort::Name => FileSort::Modified,
+            FileSort::Modified => FileSort::Size,
+            FileSort::Size => FileSort::Name,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FileSort::Name => "name",
+            FileSort::Modified => "modified",
+            FileSort::Size => "size",
+        }
+    }
+}
+
+// What `$VAR` turns into when `features.expand-env-vars` is on and the variable isn't set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UndefinedEnvVars {
+    #[default]
+    Literal,
+    Empty,
+}
+
+// How apps are started: directly, or inside their own systemd scope so they don't share qst's
+// cgroup and outlive whatever unit started it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LaunchMethod {
+    #[default]
+    Spawn,
+    SystemdRun,
+    Uwsm,
+}
+
+impl LaunchMethod {
+    pub fn label(self) -> &'static str {
+        match self {
+            LaunchMethod::Spawn => "spawn",
+            LaunchMethod::SystemdRun => "systemd-run",
+            LaunchMethod::Uwsm => "uwsm",
+        }
+    }
+}
+
+// `Bottom` puts the input under the list, which then grows upward from it with the best match
+// next to the input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ListLayout {
+    #[default]
+    Top,
+    Bottom,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum TextAlignment {
@@ -392,10 +1331,41 @@ impl From<TextAlignment> for Alignment {
     }
 }
 
+// `#RRGGBBAA` as its color and alpha.
+// A non-empty `$FLARE_CONFIG`, else `$QST_CONFIG`, with `~/` expanded.
+fn env_config_path(var: impl Fn(&str) -> Option<OsString>) -> Option<PathBuf> {
+    let path = ["FLARE_CONFIG", "QST_CONFIG"].into_iter().find_map(|name| var(name).filter(|path| !path.is_empty()))?;
+    Some(match path.to_str() {
+        Some(path) => expand_home(path),
+        None => PathBuf::from(path),
+    })
+}
+
+fn parse_hex_alpha(value: &str) -> Option<([u8; 3], u8)> {
+    let hex = value.trim().strip_prefix('#').filter(|hex| hex.len() == 8)?;
+    let channel = |idx: usize| u8::from_str_radix(hex.get(idx..idx + 2)?, 16).ok();
+    Some(([channel(0)?, channel(2)?, channel(4)?], channel(6)?))
+}
+
+// Accepts `#RGB`, `#RRGGBB`, `#RRGGBBAA`, `rgb(r, g, b)`, palette indices `0`-`255` and the
+// basic color names.
 pub fn parse_color(value: &str) -> Option<Color> {
     let trimmed = value.trim();
-    if let Some(hex) = trimmed.strip_prefix('#') {
-        if hex.len() == 6 {
+    if let Ok(index) = trimmed.parse::<u8>() {
+        return Some(Color::Indexed(index));
+    }
+    if let Some(channels) = trimmed.strip_prefix("rgb(").and_then(|rest| rest.strip_suffix(')')) {
+        let channels: Vec<u8> = channels.split(',').map(|channel| channel.trim().parse().ok()).collect::<Option<_>>()?;
+        let [r, g, b] = channels[..] else {
+            return None;
+        };
+        return Some(Color::Rgb(r, g, b));
+    }
+    if let Some(hex) = trimmed.strip_prefix('#').filter(|hex| hex.is_ascii()) {
+        if hex.len() == 3 {
+            let channel = |idx: usize| u8::from_str_radix(hex.get(idx..idx + 1)?, 16).ok().map(|value| value * 17);
+            return Some(Color::Rgb(channel(0)?, channel(1)?, channel(2)?));
+        } else if hex.len() == 6 {
             let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
             let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
             let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
@@ -434,11 +1404,28 @@ pub fn parse_color(value: &str) -> Option<Color> {
     }
 }
 
+// Palette indices may be written as bare numbers (`fg = 141`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum ColorInput {
+    Name(String),
+    Index(u8),
+}
+
+impl From<ColorInput> for String {
+    fn from(value: ColorInput) -> Self {
+        match value {
+            ColorInput::Name(name) => name,
+            ColorInput::Index(index) => index.to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 enum ColorStopsInput {
-    Single(String),
-    Multiple(Vec<String>),
+    Single(ColorInput),
+    Multiple(Vec<ColorInput>),
 }
 
 fn deserialize_color_stops<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
@@ -448,8 +1435,155 @@ where
     let value = Option::<ColorStopsInput>::deserialize(deserializer)?;
     Ok(match value {
         None => Vec::new(),
-        Some(ColorStopsInput::Single(single)) => vec![single],
-        Some(ColorStopsInput::Multiple(list)) => list,
+        Some(ColorStopsInput::Single(single)) => vec![single.into()],
+        Some(ColorStopsInput::Multiple(list)) => list.into_iter().map(String::from).collect(),
     })
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(contents: &str) -> (AppConfig, Vec<String>) {
+        AppConfig::parse(contents, Path::new("/nonexistent/qst/config.toml")).unwrap()
+    }
+
+    fn debug(config: &AppConfig) -> String {
+        format!("{:?}", config)
+    }
+
+    #[test]
+    fn misspelled_key_warns_and_changes_nothing() {
+        let (config, warnings) = parse("[input]\nborder-colr = \"#ff0000\"\ntitle = \" Find \"\n");
+        assert_eq!(warnings, ["unknown key `input.border-colr`"]);
+        assert_eq!(config.input.section.title.as_deref(), Some(" Find "));
+        assert_eq!(config.input.section.border_color, AppConfig::default().input.section.border_color);
+    }
+
+    #[test]
+    fn bad_color_value_is_dropped_and_the_rest_applies() {
+        let contents = "[input]\nborder-color = true\ntitle = \" Find \"\n\n[list]\nfg = \"#ffffff\"\n";
+        let (config, warnings) = parse(contents);
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].starts_with("input.border-color: ") && warnings[0].ends_with("(ignored)"));
+        assert_eq!(config.input.section.border_color, ["#6464ff"]);
+        assert_eq!(config.input.section.title.as_deref(), Some(" Find "));
+        assert_eq!(config.list.section.fg, ["#ffffff"]);
+
+        let contents = "[input]\nborder-color = \"blurple\"\ntitle = \" Find \"\n";
+        let (config, problems) = AppConfig::check(contents, Path::new("x"));
+        assert_eq!(problems, ["input.border-color: unknown color \"blurple\""]);
+        assert_eq!(config.unwrap().input.section.title.as_deref(), Some(" Find "));
+    }
+
+    #[test]
+    fn partial_section_inherits_defaults() {
+        let (mut config, warnings) = parse("[input]\ntitle = \" Find \"\n\n[features]\ndirs-first = false\n");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(config.input.section.title.as_deref(), Some(" Find "));
+        assert!(!config.features.dirs_first);
+
+        let defaults = AppConfig::default();
+        config.input.section.title = defaults.input.section.title.clone();
+        config.features.dirs_first = defaults.features.dirs_first;
+        assert_eq!(debug(&config), debug(&defaults));
+    }
+
+    #[test]
+    fn toml_syntax_errors_are_fatal() {
+        assert!(AppConfig::parse("[input\ntitle = 1", Path::new("x")).is_err());
+    }
+
+    #[test]
+    fn default_config_template_parses_to_the_defaults() {
+        let (config, warnings) = parse(DEFAULT_CONFIG);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(debug(&config), debug(&AppConfig::default()));
+        let (_, problems) = AppConfig::check(DEFAULT_CONFIG, Path::new("config.toml"));
+        assert!(problems.is_empty(), "{:?}", problems);
+    }
+
+    #[test]
+    fn builtin_themes_parse() {
+        for (name, contents) in BUILTIN_THEMES {
+            let (config, problems) = AppConfig::check(contents, Path::new("theme.toml"));
+            assert!(config.is_some() && problems.is_empty(), "{}: {:?}", name, problems);
+        }
+    }
+
+    #[test]
+    fn default_config_template_takes_the_theme_colors() {
+        let contents = DEFAULT_CONFIG.replace("# theme = \"nord\"", "theme = \"nord\"");
+        let (config, warnings) = parse(&contents);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(config.text.section.fg, vec!["#eceff4".to_string()]);
+        assert_eq!(config.entry_selected.bg, vec!["#88c0d0".to_string()]);
+        assert_eq!(config.qst_ascii.gradient_colors, vec!["#88c0d0".to_string(), "#81a1c1".to_string()]);
+    }
+
+    #[test]
+    fn default_config_round_trips() {
+        let serialized = toml::to_string(&AppConfig::default()).unwrap();
+        let (config, warnings) = parse(&serialized);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(debug(&config), debug(&AppConfig::default()));
+        assert_eq!(toml::to_string(&config).unwrap(), serialized);
+    }
+
+    #[test]
+    fn parse_color_accepts_the_documented_forms() {
+        let cases = [
+            ("#fff", Some(Color::Rgb(255, 255, 255))),
+            ("#1a2", Some(Color::Rgb(0x11, 0xaa, 0x22))),
+            ("#1e1e2e", Some(Color::Rgb(0x1e, 0x1e, 0x2e))),
+            ("  #1E1E2E ", Some(Color::Rgb(0x1e, 0x1e, 0x2e))),
+            ("#ff000080", Some(Color::Rgb(128, 0, 0))),
+            ("#ffffff00", Some(Color::Rgb(0, 0, 0))),
+            ("#102030ff", Some(Color::Rgb(0x10, 0x20, 0x30))),
+            ("rgb(1, 2, 3)", Some(Color::Rgb(1, 2, 3))),
+            ("rgb(255,0,255)", Some(Color::Rgb(255, 0, 255))),
+            ("0", Some(Color::Indexed(0))),
+            ("141", Some(Color::Indexed(141))),
+            ("255", Some(Color::Indexed(255))),
+            ("Red", Some(Color::Red)),
+            ("grey", Some(Color::Gray)),
+            ("light-blue", Some(Color::LightBlue)),
+            ("", None),
+            ("256", None),
+            ("-1", None),
+            ("#ff", None),
+            ("#fffff", None),
+            ("#ggg", None),
+            ("#12345g", None),
+            ("#aé123", None),
+            ("ff0000", None),
+            ("rgb(1, 2)", None),
+            ("rgb(1, 2, 3, 4)", None),
+            ("rgb(256, 0, 0)", None),
+            ("rgb(1, 2, 3", None),
+            ("blurple", None),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(parse_color(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn config_path_comes_from_flare_config_first() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(var, _)| *var == name).map(|(_, value)| OsString::from(value))
+        };
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(env_config_path(env(&[])), None);
+        assert_eq!(env_config_path(env(&[("QST_CONFIG", "/etc/qst.toml")])), Some(PathBuf::from("/etc/qst.toml")));
+        assert_eq!(
+            env_config_path(env(&[("QST_CONFIG", "/etc/qst.toml"), ("FLARE_CONFIG", "~/flare.toml")])),
+            Some(home.join("flare.toml"))
+        );
+        assert_eq!(
+            env_config_path(env(&[("FLARE_CONFIG", ""), ("QST_CONFIG", "/etc/qst.toml")])),
+            Some(PathBuf::from("/etc/qst.toml"))
+        );
+    }
+}
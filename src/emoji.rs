@@ -0,0 +1,30 @@
+use crate::app::fuzzy_score;
+
+// (glyph, name, keywords); multi-codepoint sequences are stored whole so they copy intact.
+static TABLE: &[(&str, &str, &str)] = include!("../assets/emoji.rs");
+
+// Every word of the query has to fuzzy-match the name or the keywords, with name hits weighted
+// higher. An empty query lists the table in order.
+pub fn search(query: &str, limit: usize) -> Vec<(&'static str, &'static str)> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    let mut scored: Vec<(i64, usize)> = TABLE
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, (_, name, keywords))| {
+            let mut total = 0;
+            for word in &words {
+                let by_name = fuzzy_score(word, name).map(|score| score * 2);
+                let by_keyword = fuzzy_score(word, keywords);
+                total += by_name.max(by_keyword)?;
+            }
+            Some((total, idx))
+        })
+        .collect();
+    scored.sort_by_key(|&(score, idx)| (std::cmp::Reverse(score), idx));
+
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(_, idx)| (TABLE[idx].0, TABLE[idx].1))
+        .collect()
+}
@@ -1,46 +1,208 @@
-use dirs::config_dir;
+use crate::app::{AppEntry, EntryKind};
+use crate::config::FileSort;
+use dirs::{config_dir, state_dir};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct History {
     pub usage: HashMap<String, u64>,
+    // Unix seconds of each app's latest launch.
+    #[serde(default)]
+    pub last_used: HashMap<String, u64>,
     #[serde(default)]
     pub favorites: Vec<String>,
+    #[serde(default)]
+    pub favorite_records: HashMap<String, FavoriteRecord>,
+    #[serde(default)]
+    pub hidden: Vec<String>,
+    #[serde(default)]
+    pub file_sort: Option<FileSort>,
+    #[serde(default)]
+    pub bookmarks: BTreeMap<String, String>,
+    // Changes are written once, by `save_if_dirty`, rather than on every keypress.
+    #[serde(skip)]
+    dirty: bool,
+    // `general.history-path`, when the config sets one.
+    #[serde(skip)]
+    custom_path: Option<PathBuf>,
+    // Set by `keep_in_memory`: changes still apply but are never written.
+    #[serde(skip)]
+    in_memory: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FavoriteRecord {
+    #[serde(default)]
+    pub app_id: Option<String>,
+    #[serde(default)]
+    pub last_seen: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FavoriteReport {
+    pub reattached: Vec<(String, String)>,
+    pub missing: Vec<(String, Option<u64>)>,
 }
 
 impl History {
-    pub fn load() -> Self {
-        if let Some(mut path) = config_dir() {
-            path.push("qst");
-            path.push("history.toml");
-            if path.exists() {
-                if let Ok(content) = fs::read_to_string(&path) {
-                    if let Ok(history) = toml::from_str(&content) {
-                        return history;
-                    }
-                }
+    // History is state, so it lives in `$XDG_STATE_HOME/qst` (`~/.local/state/qst`).
+    fn path() -> Option<PathBuf> {
+        state_dir().map(|dir| dir.join("qst").join("history.toml"))
+    }
+
+    // Where history was kept before it moved to the state dir. It is read when the new file
+    // doesn't exist yet and left alone afterwards.
+    fn legacy_path() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join("qst").join("history.toml"))
+    }
+
+    // A history.toml that doesn't parse is moved to history.toml.bak instead of being overwritten
+    // by the next save, and the returned warning says so. The legacy file is never moved.
+    // `custom_path` (`general.history-path`, `~/` allowed) replaces the usual location and its
+    // legacy fallback, so separate configs can keep separate histories.
+    pub fn load(custom_path: Option<&str>) -> (Self, Option<String>) {
+        let custom_path = custom_path.filter(|path| !path.is_empty()).map(expand_home);
+        let (history, warning) = Self::read(custom_path.as_ref());
+        (Self { custom_path, ..history }, warning)
+    }
+
+    fn read(custom_path: Option<&PathBuf>) -> (Self, Option<String>) {
+        let primary = custom_path.cloned().or_else(Self::path);
+        let legacy = if custom_path.is_some() { None } else { Self::legacy_path() };
+        let Some(path) = primary
+            .clone()
+            .filter(|path| path.exists())
+            .or_else(|| legacy.filter(|path| path.exists()))
+        else {
+            return (Self::default(), None);
+        };
+        let Ok(content) = fs::read_to_string(&path) else {
+            return (Self::default(), None);
+        };
+        match toml::from_str(&content) {
+            Ok(history) => (history, None),
+            Err(err) => {
+                let reason = err.message().replace('\n', " ");
+                let backup = path.with_extension("toml.bak");
+                let moved = primary.as_ref() == Some(&path) && fs::rename(&path, &backup).is_ok();
+                let warning = if moved {
+                    format!("Unreadable history ({}); moved it to {}", reason, backup.display())
+                } else {
+                    format!("Unreadable history ({}); starting with an empty one", reason)
+                };
+                (Self::default(), Some(warning))
             }
         }
-        Self::default()
     }
 
-    pub fn save(&self) {
-        if let Some(mut path) = config_dir() {
-            path.push("qst");
-            if fs::create_dir_all(&path).is_ok() {
-                path.push("history.toml");
-                if let Ok(content) = toml::to_string(self) {
-                    let _ = fs::write(path, content);
-                }
-            }
+    // Written to a temporary file next to history.toml and renamed over it, so a crash mid-write
+    // leaves the previous version intact.
+    fn save(&mut self) {
+        let Some(path) = self.custom_path.clone().or_else(Self::path) else {
+            return;
+        };
+        let Ok(content) = toml::to_string(self) else {
+            return;
+        };
+        let tmp = path.with_extension("toml.tmp");
+        let written = path.parent().is_some_and(|dir| fs::create_dir_all(dir).is_ok())
+            && fs::File::create(&tmp)
+                .and_then(|mut file| {
+                    file.write_all(content.as_bytes())?;
+                    file.sync_all()
+                })
+                .is_ok();
+        if written && fs::rename(&tmp, &path).is_ok() {
+            self.dirty = false;
+        } else {
+            let _ = fs::remove_file(&tmp);
         }
     }
 
-    pub fn increment(&mut self, app_name: &str) {
+    // Stops this history from ever being saved, for replays that must not touch the real file.
+    pub fn keep_in_memory(&mut self) {
+        self.in_memory = true;
+    }
+
+    pub fn save_if_dirty(&mut self) {
+        if self.dirty && !self.in_memory {
+            self.save();
+        }
+    }
+
+    pub fn increment(&mut self, app_name: &str, now: u64) {
         *self.usage.entry(app_name.to_string()).or_insert(0) += 1;
-        self.save();
+        self.last_used.insert(app_name.to_string(), now);
+        self.dirty = true;
+    }
+
+    // Forgets usage counts and times; favorites, hidden apps and bookmarks stay.
+    pub fn clear_usage(&mut self) {
+        self.usage.clear();
+        self.last_used.clear();
+        self.dirty = true;
+    }
+
+    // `--clear-history`: clears the history file at `custom_path` (or the usual one) without
+    // starting the launcher. Returns the warning `load` gave, if any.
+    pub fn clear_usage_at(custom_path: Option<&str>) -> Option<String> {
+        let (mut history, warning) = Self::load(custom_path);
+        history.clear_usage();
+        history.save_if_dirty();
+        warning
+    }
+
+    // Drops usage for apps missing from `installed` that weren't used in the last
+    // `max_age_days`, then keeps only the `max_entries` most used. Favorites are never dropped
+    // and don't count towards the limit; 0 turns either rule off.
+    pub fn prune(
+        &mut self,
+        installed: &HashSet<&str>,
+        now: u64,
+        max_entries: usize,
+        max_age_days: u64,
+    ) {
+        let mut names: Vec<String> = self
+            .usage
+            .keys()
+            .chain(self.last_used.keys())
+            .filter(|name| !self.is_favorite(name))
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let mut dropped: Vec<String> = Vec::new();
+
+        if max_age_days > 0 {
+            let cutoff = now.saturating_sub(max_age_days * 86_400);
+            names.retain(|name| {
+                let last_used = self.last_used.get(name).copied().unwrap_or(0);
+                let stale = !installed.contains(name.as_str()) && last_used < cutoff;
+                if stale {
+                    dropped.push(name.clone());
+                }
+                !stale
+            });
+        }
+        if max_entries > 0 && names.len() > max_entries {
+            names.sort_by(|a, b| {
+                let rank = |name: &String| (self.get_count(name), self.last_used.get(name).copied().unwrap_or(0));
+                rank(b).cmp(&rank(a)).then_with(|| a.cmp(b))
+            });
+            dropped.extend(names.split_off(max_entries));
+        }
+
+        for name in &dropped {
+            self.usage.remove(name);
+            self.last_used.remove(name);
+        }
+        if !dropped.is_empty() {
+            self.dirty = true;
+        }
     }
 
     pub fn get_count(&self, app_name: &str) -> u64 {
@@ -50,13 +212,262 @@ impl History {
     pub fn toggle_favorite(&mut self, app_name: &str) {
         if let Some(pos) = self.favorites.iter().position(|x| x == app_name) {
             self.favorites.remove(pos);
+            self.favorite_records.remove(app_name);
         } else {
             self.favorites.push(app_name.to_string());
         }
-        self.save();
+        self.dirty = true;
+    }
+
+    // Moves a favorite `delta` places within `favorites`, which is also their display order.
+    pub fn move_favorite(&mut self, app_name: &str, delta: isize) -> bool {
+        let Some(pos) = self.favorites.iter().position(|x| x == app_name) else {
+            return false;
+        };
+        let target = pos.saturating_add_signed(delta).min(self.favorites.len() - 1);
+        if target == pos {
+            return false;
+        }
+        let name = self.favorites.remove(pos);
+        self.favorites.insert(target, name);
+        self.dirty = true;
+        true
+    }
+
+    pub fn favorite_slot(&self, app_name: &str) -> Option<usize> {
+        self.favorites.iter().position(|x| x == app_name)
+    }
+
+    // Returns whether the app is hidden after the toggle.
+    pub fn toggle_hidden(&mut self, app_name: &str) -> bool {
+        let hidden = if let Some(pos) = self.hidden.iter().position(|x| x == app_name) {
+            self.hidden.remove(pos);
+            false
+        } else {
+            self.hidden.push(app_name.to_string());
+            true
+        };
+        self.dirty = true;
+        hidden
+    }
+
+    pub fn set_file_sort(&mut self, sort: FileSort) {
+        self.file_sort = Some(sort);
+        self.dirty = true;
+    }
+
+    pub fn add_bookmark(&mut self, name: &str, target: &str) {
+        self.bookmarks.insert(name.to_string(), target.to_string());
+        self.dirty = true;
+    }
+
+    pub fn is_hidden(&self, app_name: &str) -> bool {
+        self.hidden.iter().any(|x| x == app_name)
     }
 
     pub fn is_favorite(&self, app_name: &str) -> bool {
         self.favorites.contains(&app_name.to_string())
     }
+
+    // Matches favorites against the scanned entries: present ones get their appid and last-seen
+    // time refreshed, renamed ones are re-attached by appid, and the rest are reported as missing
+    // but kept so a reinstall brings the star back.
+    pub fn reconcile_favorites(&mut self, entries: &[AppEntry], now: u64) -> FavoriteReport {
+        let mut report = FavoriteReport::default();
+        let mut changed = false;
+
+        for idx in 0..self.favorites.len() {
+            let name = self.favorites[idx].clone();
+            let present = entries
+                .iter()
+                .find(|entry| entry.name == name && entry.kind != EntryKind::Missing);
+
+            if let Some(entry) = present {
+                let record = self.favorite_records.entry(name).or_default();
+                if entry.app_id.is_some() {
+                    record.app_id = entry.app_id.clone();
+                }
+                record.last_seen = Some(now);
+                changed = true;
+                continue;
+            }
+
+            let record = self.favorite_records.get(&name).cloned().unwrap_or_default();
+            let renamed = record.app_id.as_ref().and_then(|app_id| {
+                entries
+                    .iter()
+                    .find(|entry| entry.app_id.as_ref() == Some(app_id))
+                    .filter(|entry| !self.favorites.contains(&entry.name))
+            });
+
+            match renamed {
+                Some(entry) => {
+                    let new_name = entry.name.clone();
+                    self.favorites[idx] = new_name.clone();
+                    self.favorite_records.remove(&name);
+                    self.favorite_records.insert(
+                        new_name.clone(),
+                        FavoriteRecord {
+                            app_id: record.app_id,
+                            last_seen: Some(now),
+                        },
+                    );
+                    if let Some(count) = self.usage.remove(&name) {
+                        *self.usage.entry(new_name.clone()).or_insert(0) += count;
+                    }
+                    if let Some(last_used) = self.last_used.remove(&name) {
+                        let entry = self.last_used.entry(new_name.clone()).or_insert(0);
+                        *entry = (*entry).max(last_used);
+                    }
+                    report.reattached.push((name, new_name));
+                    changed = true;
+                }
+                None => report.missing.push((name, record.last_seen)),
+            }
+        }
+
+        if changed {
+            self.dirty = true;
+        }
+        report
+    }
+
+    pub fn last_seen(&self, app_name: &str) -> Option<u64> {
+        self.favorite_records.get(app_name).and_then(|record| record.last_seen)
+    }
+}
+
+// Formats a unix timestamp as a UTC `YYYY-MM-DD` date.
+pub fn format_date(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = 86_400;
+    const NOW: u64 = 1_700_000_000;
+
+    // `(name, count, days since last use)` for each app.
+    fn history(usage: &[(&str, u64, u64)], favorites: &[&str]) -> History {
+        let mut history = History::default();
+        for &(name, count, days_ago) in usage {
+            history.usage.insert(name.to_string(), count);
+            history.last_used.insert(name.to_string(), NOW - days_ago * DAY);
+        }
+        history.favorites = favorites.iter().map(|name| name.to_string()).collect();
+        history
+    }
+
+    fn names(history: &History) -> Vec<&str> {
+        let mut names: Vec<&str> = history.usage.keys().chain(history.last_used.keys()).map(String::as_str).collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
+    #[test]
+    fn prune_drops_old_uninstalled_apps() {
+        let mut history = history(
+            &[("Gone", 9, 40), ("Installed", 1, 400), ("Recent", 2, 3), ("Pinned", 1, 400)],
+            &["Pinned"],
+        );
+        history.last_used.insert("Timeless".to_string(), 0);
+        let installed = HashSet::from(["Installed"]);
+        history.prune(&installed, NOW, 0, 30);
+        assert_eq!(names(&history), ["Installed", "Pinned", "Recent"]);
+        assert!(history.dirty);
+    }
+
+    #[test]
+    fn prune_keeps_the_most_used() {
+        let mut history = history(
+            &[("A", 5, 1), ("B", 5, 2), ("C", 7, 9), ("D", 1, 0), ("E", 5, 2), ("Fav", 0, 900)],
+            &["Fav"],
+        );
+        history.prune(&HashSet::new(), NOW, 3, 0);
+        // Equal counts go to the more recent, then by name; the favorite doesn't take a slot.
+        assert_eq!(names(&history), ["A", "B", "C", "Fav"]);
+        assert_eq!(history.favorites, ["Fav"]);
+    }
+
+    #[test]
+    fn prune_applies_age_before_the_limit() {
+        let mut history = history(&[("Old", 50, 100), ("New", 1, 1), ("Newer", 2, 0)], &[]);
+        history.prune(&HashSet::new(), NOW, 2, 30);
+        assert_eq!(names(&history), ["New", "Newer"]);
+    }
+
+    #[test]
+    fn prune_with_both_rules_off_changes_nothing() {
+        let mut history = history(&[("A", 1, 4000), ("B", 2, 0)], &[]);
+        history.prune(&HashSet::new(), NOW, 0, 0);
+        assert_eq!(names(&history), ["A", "B"]);
+        assert!(!history.dirty);
+
+        history.prune(&HashSet::from(["A", "B"]), NOW, 5, 1);
+        assert!(!history.dirty);
+    }
+
+    #[test]
+    fn clear_history_keeps_favorites_hidden_apps_and_bookmarks() {
+        let dir = std::env::temp_dir().join(format!("qst-history-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("history.toml");
+        let mut before = history(&[("Firefox", 12, 1), ("Foot", 3, 2)], &["Foot"]);
+        before.hidden.push("Htop".to_string());
+        before.bookmarks.insert("src".to_string(), "~/src".to_string());
+        before.favorite_records.insert("Foot".to_string(), FavoriteRecord::default());
+        fs::write(&path, toml::to_string(&before).unwrap()).unwrap();
+
+        let path_str = path.to_str().unwrap();
+        assert_eq!(History::clear_usage_at(Some(path_str)), None);
+        let (after, warning) = History::load(Some(path_str));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(warning, None);
+        assert!(after.usage.is_empty() && after.last_used.is_empty());
+        assert_eq!(after.favorites, ["Foot"]);
+        assert!(after.favorite_records.contains_key("Foot"));
+        assert_eq!(after.hidden, ["Htop"]);
+        assert_eq!(after.bookmarks.get("src").map(String::as_str), Some("~/src"));
+    }
+
+    #[test]
+    fn in_memory_history_is_never_saved() {
+        let dir = std::env::temp_dir().join(format!("qst-memory-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("history.toml");
+        fs::write(&path, toml::to_string(&history(&[("Foot", 3, 2)], &[])).unwrap()).unwrap();
+
+        let (mut history, _) = History::load(path.to_str());
+        history.keep_in_memory();
+        history.increment("Foot", NOW);
+        history.toggle_favorite("Foot");
+        history.save_if_dirty();
+        assert_eq!(history.get_count("Foot"), 4);
+        let (on_disk, _) = History::load(path.to_str());
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(on_disk.get_count("Foot"), 3);
+        assert!(on_disk.favorites.is_empty());
+    }
 }
@@ -0,0 +1,128 @@
+use std::{
+    env, fs,
+    io::{ErrorKind, Read, Write},
+    os::unix::net::{UnixListener, UnixStream},
+    path::PathBuf,
+    time::Duration,
+};
+
+// How long `poll` waits for a client that connected to finish its message.
+const READ_TIMEOUT: Duration = Duration::from_millis(100);
+
+pub enum InstanceMessage {
+    Quit,
+    Focus,
+}
+
+pub enum Acquire {
+    Owned(InstanceLock),
+    Running(UnixStream),
+    Unavailable,
+}
+
+// Holds `$XDG_RUNTIME_DIR/qst.sock` for the lifetime of the first instance; later instances
+// connect to it to ask that instance to quit or come forward.
+pub struct InstanceLock {
+    listener: UnixListener,
+    path: PathBuf,
+}
+
+impl InstanceLock {
+    fn socket_path() -> Option<PathBuf> {
+        let mut path = PathBuf::from(env::var_os("XDG_RUNTIME_DIR")?);
+        path.push("qst.sock");
+        Some(path)
+    }
+
+    pub fn acquire() -> Acquire {
+        match Self::socket_path() {
+            Some(path) => Self::acquire_at(path),
+            None => Acquire::Unavailable,
+        }
+    }
+
+    fn acquire_at(path: PathBuf) -> Acquire {
+        match UnixStream::connect(&path) {
+            Ok(stream) => return Acquire::Running(stream),
+            // Nobody is listening: the file is left over from a crashed instance.
+            Err(err) if err.kind() == ErrorKind::ConnectionRefused => {
+                let _ = fs::remove_file(&path);
+            }
+            Err(_) => {}
+        }
+
+        let Ok(listener) = UnixListener::bind(&path) else {
+            return Acquire::Unavailable;
+        };
+        if listener.set_nonblocking(true).is_err() {
+            return Acquire::Unavailable;
+        }
+        Acquire::Owned(Self { listener, path })
+    }
+
+    // A client that connects and then stalls is dropped after READ_TIMEOUT rather than blocking
+    // the UI.
+    pub fn poll(&self) -> Option<InstanceMessage> {
+        let (stream, _) = self.listener.accept().ok()?;
+        stream.set_nonblocking(false).ok()?;
+        stream.set_read_timeout(Some(READ_TIMEOUT)).ok()?;
+        let mut message = String::new();
+        stream.take(64).read_to_string(&mut message).ok()?;
+        match message.trim() {
+            "quit" => Some(InstanceMessage::Quit),
+            "focus" => Some(InstanceMessage::Focus),
+            _ => None,
+        }
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+pub fn send(mut stream: UnixStream, message: InstanceMessage) {
+    let text = match message {
+        InstanceMessage::Quit => "quit",
+        InstanceMessage::Focus => "focus",
+    };
+    let _ = stream.write_all(text.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn lock(name: &str) -> InstanceLock {
+        let path = env::temp_dir().join(format!("qst-{}-{}.sock", name, std::process::id()));
+        match InstanceLock::acquire_at(path) {
+            Acquire::Owned(lock) => lock,
+            _ => panic!("couldn't bind the test socket"),
+        }
+    }
+
+    #[test]
+    fn poll_reads_messages_from_later_instances() {
+        let lock = lock("messages");
+        assert!(lock.poll().is_none());
+        let Acquire::Running(stream) = InstanceLock::acquire_at(lock.path.clone()) else {
+            panic!("the second instance should find the first");
+        };
+        send(stream, InstanceMessage::Quit);
+        assert!(matches!(lock.poll(), Some(InstanceMessage::Quit)));
+        send(UnixStream::connect(&lock.path).unwrap(), InstanceMessage::Focus);
+        assert!(matches!(lock.poll(), Some(InstanceMessage::Focus)));
+    }
+
+    #[test]
+    fn poll_gives_up_on_stalled_clients() {
+        let lock = lock("stalled");
+        let mut stream = UnixStream::connect(&lock.path).unwrap();
+        stream.write_all(b"qu").unwrap();
+        let started = Instant::now();
+        assert!(lock.poll().is_none());
+        assert!(started.elapsed() < READ_TIMEOUT * 10, "waited {:?}", started.elapsed());
+    }
+}
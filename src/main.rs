@@ -1,57 +1,148 @@
 mod app;
+mod cache;
+mod cli;
+mod clock;
+mod compositor;
+mod emoji;
 mod config;
 mod history;
+mod preview;
+mod process;
+mod recent;
+mod instance;
+mod mime;
+mod simulate;
+mod tasks;
+mod translit;
 mod ui;
 
-use crate::{app::App, config::AppConfig, ui::draw};
-use anyhow::Result;
+use crate::{
+    app::{App, AppMode, EntryKind, program_exists, split_args},
+    cache::EntryCache,
+    cli::{Cli, Command, HELP, VERSION},
+    config::{AppConfig, DEFAULT_CONFIG},
+    history::{History, format_date},
+    instance::{Acquire, InstanceLock, InstanceMessage},
+    ui::draw,
+};
+use anyhow::{Context, Result};
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    cursor::Show,
+    event::{
+        self, DisableBracketedPaste, EnableBracketedPaste, Event, KeyCode, KeyEvent, KeyEventKind,
+        KeyboardEnhancementFlags, KeyModifiers, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::prelude::*;
 use std::io;
 use std::env;
-use std::fs;
-use dirs::config_dir;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+// With `--print` the UI is drawn on /dev/tty so stdout only carries the printed command.
+static DRAW_ON_TTY: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn request_shutdown(_signal: libc::c_int) {
+    SHUTDOWN_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+// Raw mode and the alternate screen are undone on drop, so `?` and early returns restore the
+// terminal as well as the normal quit path. Terminals with the kitty keyboard protocol are asked
+// to disambiguate escape codes, which is what tells ctrl+enter and shift+enter apart from Enter;
+// others ignore the request.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        enable_raw_mode()?;
+        let guard = TerminalGuard;
+        let flags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES;
+        execute!(terminal_output()?, EnterAlternateScreen, EnableBracketedPaste, PushKeyboardEnhancementFlags(flags))?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}
+
+fn terminal_output() -> io::Result<Box<dyn Write>> {
+    if DRAW_ON_TTY.load(Ordering::SeqCst) {
+        Ok(Box::new(OpenOptions::new().write(true).open("/dev/tty")?))
+    } else {
+        Ok(Box::new(io::stdout()))
+    }
+}
+
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    if let Ok(mut output) = terminal_output() {
+        let _ = execute!(output, PopKeyboardEnhancementFlags, DisableBracketedPaste, LeaveAlternateScreen, Show);
+    }
+}
+
+// Background tasks catch their own panics and the UI carries on, so only a panic on the main
+// thread leaves the terminal.
+fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if std::thread::current().name() == Some("main") {
+            restore_terminal();
+        }
+        default_hook(info);
+    }));
+}
+
+fn install_signal_handlers() {
+    let handler = request_shutdown as extern "C" fn(libc::c_int);
+    unsafe {
+        libc::signal(libc::SIGTERM, handler as libc::sighandler_t);
+        libc::signal(libc::SIGINT, handler as libc::sighandler_t);
+        libc::signal(libc::SIGHUP, handler as libc::sighandler_t);
+    }
+}
 
 fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-    if args.len() > 1 {
-        match args[1].as_str() {
-            "--gen-config" => {
-                if let Some(mut path) = config_dir() {
-                    path.push("qst");
-                    if fs::create_dir_all(&path).is_err() {
-                        eprintln!("Error: Unable to create configuration directory: {:?}", path);
+    let cli = match Cli::parse(env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(message) => {
+            eprintln!("qst: {message}");
+            eprintln!("Try `qst --help` for the options.");
+            std::process::exit(2);
+        }
+    };
+    if let Some(path) = &cli.config {
+        AppConfig::set_explicit_path(path.clone());
+    }
+    if let Some(command) = &cli.command {
+        match command {
+            Command::GenConfig => {
+                if let Some(path) = AppConfig::path() {
+                    if let Some(dir) = path.parent().filter(|dir| fs::create_dir_all(dir).is_err()) {
+                        eprintln!("Error: Unable to create configuration directory: {:?}", dir);
                         std::process::exit(1);
                     }
-                    path.push("config.toml");
 
                     if path.exists() {
                         eprintln!("Error: Configuration file already exists at {:?}", path);
                         std::process::exit(1);
                     }
 
-                    // We generate the TOML directly from the default struct 
-                    // which is now defined in assets/defaults.rs
-                    let default_config_struct = AppConfig::default();
-                    match toml::to_string_pretty(&default_config_struct) {
-                        Ok(serialized) => {
-                            match fs::write(&path, serialized) {
-                                Ok(_) => {
-                                    println!("Successfully generated default configuration at {:?}", path);
-                                    std::process::exit(0);
-                                }
-                                Err(e) => {
-                                    eprintln!("Error writing configuration file: {}", e);
-                                    std::process::exit(1);
-                                }
-                            }
+                    match fs::write(&path, DEFAULT_CONFIG) {
+                        Ok(_) => {
+                            println!("Successfully generated default configuration at {:?}", path);
+                            std::process::exit(0);
                         }
                         Err(e) => {
-                            eprintln!("Error serializing default configuration: {}", e);
+                            eprintln!("Error writing configuration file: {}", e);
                             std::process::exit(1);
                         }
                     }
@@ -60,64 +151,159 @@ fn main() -> Result<()> {
                     std::process::exit(1);
                 }
             }
-            "-h" | "--help" => {
-                println!("Qst - An Application Launcher");
-                println!("Usage: qst [OPTIONS]");
-                println!("");
-                println!("Options:");
-                println!("  --gen-config    Generate a default config file at ~/.config/qst/config.toml");
-                println!("                  (Fails if file already exists)");
-                println!("  -h, --help      Print this help message");
+            Command::DumpDefaultConfig => {
+                print!("{}", DEFAULT_CONFIG);
                 std::process::exit(0);
             }
-            _ => {
+            Command::ClearHistory => {
+                let config = AppConfig::load().config;
+                if let Some(warning) = History::clear_usage_at(config.general.history_path.as_deref()) {
+                    eprintln!("{warning}");
+                }
+                println!("Cleared usage history; favorites were kept");
+                std::process::exit(0);
+            }
+            Command::CheckConfig => {
+                let ok = check_config();
+                std::process::exit(if ok { 0 } else { 1 });
+            }
+            Command::List => {
+                list_entries(cli.filter.as_deref(), cli.list_exec);
+                std::process::exit(0);
+            }
+            Command::ListExcluded => {
+                list_excluded();
+                std::process::exit(0);
+            }
+            Command::HistoryDoctor => {
+                history_doctor();
+                std::process::exit(0);
+            }
+            Command::SimulateTime(script) => {
+                if let Err(err) = simulate::run(script) {
+                    eprintln!("Error: {err}");
+                    std::process::exit(1);
+                }
+                std::process::exit(0);
+            }
+            Command::Help => {
+                print!("{}", HELP);
+                std::process::exit(0);
+            }
+            Command::Version => {
+                println!("qst {}", VERSION);
+                std::process::exit(0);
             }
         }
     }
 
-    let load_result = AppConfig::load();
-    if let Some(warning) = &load_result.warning {
-        eprintln!("{warning}");
+    if cli.refresh_cache {
+        EntryCache::clear();
     }
 
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
+    if let Some(path) = AppConfig::explicit_path().filter(|path| !path.exists()) {
+        eprintln!("Error: config file {} does not exist", path.display());
+        std::process::exit(1);
+    }
+    let load_result = AppConfig::load();
+
+    let toggle = cli.toggle;
+    let instance_lock = if toggle || load_result.config.features.single_instance {
+        match InstanceLock::acquire() {
+            Acquire::Owned(lock) => Some(lock),
+            Acquire::Running(stream) => {
+                let message = if toggle { InstanceMessage::Quit } else { InstanceMessage::Focus };
+                instance::send(stream, message);
+                return Ok(());
+            }
+            Acquire::Unavailable => None,
+        }
+    } else {
+        None
+    };
+
+    let print_only = cli.print || cli.print_name;
+    DRAW_ON_TTY.store(print_only, Ordering::SeqCst);
+
+    install_panic_hook();
+    install_signal_handlers();
+    let guard = TerminalGuard::enter()?;
+    let backend = CrosstermBackend::new(terminal_output()?);
     let mut terminal = Terminal::new(backend)?;
 
+    if cli.panic_test {
+        panic!("--panic-test: the terminal should be restored before this message");
+    }
+
     let mut app = App::new(load_result.config, load_result.warning);
+    app.debug = cli.debug;
+    app.print_only = print_only;
+    app.print_name = cli.print_name;
+    if let Some(query) = cli.query {
+        app.set_search_query(query);
+        app.update_filter();
+    }
 
+    // A terminal that can't be read from ends the loop like a quit, so history is still saved and
+    // the terminal restored before the error is reported.
+    let mut failure = None;
+    let (mut redraw, mut animating) = (true, false);
     loop {
-        terminal.draw(|f| draw(f, &mut app))?;
+        if SHUTDOWN_REQUESTED.load(Ordering::SeqCst) {
+            break;
+        }
 
-        if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
-                if matches_key(&key, app.config.general.jump_to_top_key.as_deref().unwrap_or("alt+up")) {
-                    app.select_first();
-                    continue;
-                }
-                if matches_key(&key, app.config.general.jump_to_bottom_key.as_deref().unwrap_or("alt+down")) {
-                    app.select_last();
-                    continue;
+        if let Some(lock) = &instance_lock {
+            match lock.poll() {
+                Some(InstanceMessage::Quit) => break,
+                Some(InstanceMessage::Focus) => {
+                    app.status_message = Some("qst is already open here".to_string());
+                    redraw = true;
                 }
+                None => {}
+            }
+        }
 
-                match key.code {
-                    KeyCode::Esc => app.should_quit = true,
-                    KeyCode::Enter => app.launch_selected(),
-                    KeyCode::Up => app.move_selection(-1),
-                    KeyCode::Down => app.move_selection(1),
-                    KeyCode::Left => app.move_search_cursor_left(),
-                    KeyCode::Right => app.move_search_cursor_right(),
-                    _ if matches_key(&key, app.config.general.favorite_key.as_deref().unwrap_or("alt+f")) => {
-                        app.toggle_favorite();
-                    }
-                    KeyCode::Backspace => app.backspace_search_char(),
-                    KeyCode::Char(c) => app.insert_search_char(c),
-                    KeyCode::Tab => app.auto_complete(),
-                    _ => {}
-                }
+        redraw |= app.poll_tasks();
+        // A watched launch finishes here, between key presses.
+        if app.should_quit {
+            break;
+        }
+        redraw |= app.reload_config_if_changed();
+        // One more frame once the spinner stops, so its last one doesn't linger.
+        let was_animating = std::mem::replace(&mut animating, app.is_animating());
+        if redraw || animating || was_animating {
+            terminal.draw(|f| draw(f, &mut app))?;
+            redraw = false;
+        }
+
+        // A signal interrupting the poll is an empty tick; SIGTERM and friends are seen at the top.
+        let tick_rate = Duration::from_millis(app.config.general.tick_rate_ms.max(10));
+        let event = match event::poll(tick_rate).and_then(|ready| ready.then(event::read).transpose()) {
+            Ok(Some(event)) => event,
+            Ok(None) => continue,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => {
+                failure = Some(err);
+                break;
             }
+        };
+        redraw = true;
+        if let Event::Resize(_, _) = event {
+            terminal.autoresize()?;
+            continue;
+        }
+
+        if let Event::Paste(text) = &event
+            && !app.show_help
+        {
+            app.paste_text(text);
+        }
+
+        if let Event::Key(key) = event
+            && key.kind == KeyEventKind::Press
+        {
+            handle_key(&mut app, &key);
         }
 
         if app.should_quit {
@@ -125,47 +311,392 @@ fn main() -> Result<()> {
         }
     }
 
-    disable_raw_mode()?;
-    execute!(io::stdout(), LeaveAlternateScreen)?;
+    app.history.save_if_dirty();
+    drop(terminal);
+    drop(guard);
+    // Back on the normal screen, so the warning ends up in the scrollback.
+    if let Some(warning) = app.config_warning.as_ref().filter(|_| app.cancelled || !app.should_quit) {
+        eprintln!("{warning}");
+    }
+    if let Some(err) = failure {
+        return Err(err).context("reading terminal events");
+    }
+    match app.printed_command {
+        Some(command) => println!("{}", command),
+        // Like dmenu, quitting `--print` without choosing anything is a failure for scripts.
+        None if print_only => std::process::exit(1),
+        None => {}
+    }
     Ok(())
 }
 
-fn matches_key(key: &event::KeyEvent, config_str: &str) -> bool {
-    let config_str = config_str.to_lowercase();
-    let parts: Vec<&str> = config_str.split('+').collect();
-    
-    let mut required_modifiers = KeyModifiers::empty();
-    let mut required_code = None;
+// The list the launcher would show for `filter` (all entries without one), in its order.
+fn list_entries(filter: Option<&str>, exec: bool) {
+    let load_result = AppConfig::load();
+    let mut app = App::new(load_result.config, None);
+    app.wait_for_scan();
+    app.set_search_query(filter.unwrap_or_default().to_string());
+    app.update_filter();
 
-    for part in parts {
-        match part {
-            "ctrl" | "control" => required_modifiers.insert(KeyModifiers::CONTROL),
-            "alt" | "option" => required_modifiers.insert(KeyModifiers::ALT),
-            "shift" => required_modifiers.insert(KeyModifiers::SHIFT),
-            "super" | "cmd" | "win" | "meta" => required_modifiers.insert(KeyModifiers::SUPER),
-            "enter" | "return" => required_code = Some(KeyCode::Enter),
-            "esc" | "escape" => required_code = Some(KeyCode::Esc),
-            "backspace" => required_code = Some(KeyCode::Backspace),
-            "tab" => required_code = Some(KeyCode::Tab),
-            "space" => required_code = Some(KeyCode::Char(' ')),
-            "up" => required_code = Some(KeyCode::Up),
-            "down" => required_code = Some(KeyCode::Down),
-            "left" => required_code = Some(KeyCode::Left),
-            "right" => required_code = Some(KeyCode::Right),
-            s if s.len() == 1 => required_code = Some(KeyCode::Char(s.chars().next().unwrap())),
-            s if s.starts_with('f') && s.len() > 1 => {
-                 if let Ok(n) = s[1..].parse::<u8>() {
-                     required_code = Some(KeyCode::F(n));
-                 }
-            }
-            _ => {}
+    for &index in &app.filtered_entries {
+        let entry = &app.entries[index];
+        if entry.kind == EntryKind::Missing {
+            continue;
+        }
+        match app.resolved_command(entry).filter(|_| exec) {
+            Some(command) => println!("{}\t{}", entry.name, command),
+            None => println!("{}", entry.name),
         }
     }
+}
+
+fn list_excluded() {
+    let load_result = AppConfig::load();
+    let mut app = App::new(load_result.config, None);
+    app.wait_for_scan();
+
+    for (entry, reason) in &app.excluded_entries {
+        let path = entry
+            .desktop_path
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        println!("{}\t{}\t{}", entry.name, reason, path);
+    }
+}
 
-    if let Some(code) = required_code {
-        if key.code != code {
+// Prints what's wrong with config.toml; returns whether it was clean.
+fn check_config() -> bool {
+    let Some(path) = AppConfig::path() else {
+        eprintln!("Error: Could not determine configuration directory.");
+        return false;
+    };
+    println!("checking {}", path.display());
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound && AppConfig::explicit_path().is_none() => {
+            println!("no config file, the defaults are used");
+            return true;
+        }
+        Err(err) => {
+            println!("error: {}", err);
             return false;
         }
+    };
+
+    let (config, mut problems) = AppConfig::check(&contents, &path);
+    if let Some(config) = config {
+        for (name, binding) in config.general.key_bindings() {
+            if let Some(spec) = binding.filter(|spec| !is_valid_key_spec(spec)) {
+                problems.push(format!("general.{}: can't parse key \"{}\"", name, spec));
+            }
+        }
+        let openers = [("file-opener", &config.general.file_opener), ("dir-opener", &config.general.dir_opener)];
+        for (name, opener) in openers {
+            // Split the way `opener_command` does, so a quoted path with spaces is one program.
+            let program = opener.as_deref().and_then(|opener| split_args(opener).into_iter().next());
+            if let Some(program) = program.filter(|program| !program.contains('$') && !program_exists(program)) {
+                problems.push(format!("general.{}: `{}` isn't installed", name, program));
+            }
+        }
+    }
+    for problem in &problems {
+        println!("error: {}", problem);
+    }
+    if problems.is_empty() {
+        println!("config is valid");
+    }
+    problems.is_empty()
+}
+
+fn history_doctor() {
+    let load_result = AppConfig::load();
+    if let Some(warning) = &load_result.warning {
+        eprintln!("{warning}");
+    }
+
+    let mut app = App::new(load_result.config, None);
+    app.wait_for_scan();
+    let report = &app.favorite_report;
+
+    println!("{} favorites checked", app.history.favorites.len());
+    for (old_name, new_name) in &report.reattached {
+        println!("re-attached: {old_name} -> {new_name}");
+    }
+    for (name, last_seen) in &report.missing {
+        match last_seen {
+            Some(secs) => println!("missing: {name} (last seen {})", format_date(*secs)),
+            None => println!("missing: {name} (never seen)"),
+        }
+    }
+    if report.reattached.is_empty() && report.missing.is_empty() {
+        println!("all favorites are installed");
+    }
+    app.history.save_if_dirty();
+}
+
+pub(crate) fn handle_key(app: &mut App, key: &KeyEvent) {
+    if !matches!(key.code, KeyCode::Tab | KeyCode::BackTab) {
+        app.end_completion_cycle();
+    }
+    // While the help overlay is open it takes every key; Esc closes it instead of quitting.
+    let help_key = matches_key(key, app.config.general.help_key.as_deref().unwrap_or("f1"));
+    if app.show_help {
+        if help_key || key.code == KeyCode::Esc {
+            app.show_help = false;
+        }
+        return;
+    }
+    if help_key {
+        app.show_help = true;
+        return;
+    }
+    if app.config_warning.is_some()
+        && matches_key(key, app.config.general.dismiss_warning_key.as_deref().unwrap_or("alt+x"))
+    {
+        app.config_warning = None;
+        return;
+    }
+    if matches_key(key, app.config.general.paste_key.as_deref().unwrap_or("ctrl+v")) {
+        app.paste_from_clipboard();
+        return;
+    }
+    // Checked before `alt+up`/`alt+down`, which would also match with shift held.
+    if matches_key(key, app.config.general.favorite_up_key.as_deref().unwrap_or("alt+shift+up")) {
+        app.move_favorite(-1);
+        return;
+    }
+    if matches_key(key, app.config.general.favorite_down_key.as_deref().unwrap_or("alt+shift+down")) {
+        app.move_favorite(1);
+        return;
+    }
+    if let KeyCode::Char(digit @ '1'..='9') = key.code
+        && key.modifiers == KeyModifiers::ALT
+    {
+        app.launch_favorite(digit as usize - '0' as usize);
+        return;
+    }
+    let clear_history_key = app.config.general.clear_history_key.as_deref().unwrap_or("alt+c");
+    if matches_key(key, clear_history_key) {
+        let clear_history_key = clear_history_key.to_string();
+        app.clear_history(&clear_history_key);
+        return;
+    }
+    app.cancel_clear_history();
+    // Vertical keys follow the screen: with the list drawn bottom-up, "down" is towards the first item.
+    let down = app.downward();
+    if matches_key(key, app.config.general.jump_to_top_key.as_deref().unwrap_or("alt+up")) {
+        if down > 0 { app.select_first() } else { app.select_last() }
+        return;
+    }
+    if matches_key(key, app.config.general.jump_to_bottom_key.as_deref().unwrap_or("alt+down")) {
+        if down > 0 { app.select_last() } else { app.select_first() }
+        return;
+    }
+    if matches_key(key, app.config.general.half_page_down_key.as_deref().unwrap_or("ctrl+d")) {
+        app.move_page(0.5 * down as f32);
+        return;
+    }
+    if matches_key(key, app.config.general.half_page_up_key.as_deref().unwrap_or("ctrl+u")) {
+        app.move_page(-0.5 * down as f32);
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.file_sort_key.as_deref().unwrap_or("ctrl+s"))
+    {
+        app.cycle_file_sort();
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.hidden_files_key.as_deref().unwrap_or("ctrl+h"))
+    {
+        app.toggle_hidden_files();
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.bookmark_key.as_deref().unwrap_or("alt+b"))
+    {
+        app.bookmark_current_dir();
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.open_with_key.as_deref().unwrap_or("ctrl+o"))
+    {
+        app.open_with_selected();
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.delete_component_key.as_deref().unwrap_or("ctrl+backspace"))
+    {
+        app.delete_path_component();
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.parent_dir_key.as_deref().unwrap_or("alt+left"))
+    {
+        app.open_parent_dir();
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.mark_key.as_deref().unwrap_or("ctrl+space"))
+    {
+        app.toggle_mark();
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.recursive_key.as_deref().unwrap_or("alt+r"))
+    {
+        app.toggle_recursive_search();
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.run_detached_key.as_deref().unwrap_or("shift+enter"))
+    {
+        app.launch(App::launch_detached, false);
+        return;
+    }
+    if app.mode == AppMode::FileSelection
+        && matches_key(key, app.config.general.open_dir_key.as_deref().unwrap_or("ctrl+enter"))
+    {
+        app.launch(App::launch_typed, false);
+        return;
+    }
+    if matches_key(key, app.config.general.copy_key.as_deref().unwrap_or("ctrl+y")) {
+        app.copy_selected();
+        return;
+    }
+    if app.showing_processes()
+        && matches_key(key, app.config.general.force_kill_key.as_deref().unwrap_or("alt+enter"))
+    {
+        app.kill_selected(true);
+        return;
+    }
+    if !app.showing_processes()
+        && matches_key(key, app.config.general.keep_open_key.as_deref().unwrap_or("alt+enter"))
+    {
+        app.launch(App::launch_selected, true);
+        return;
+    }
+    if app.showing_processes()
+        && matches_key(key, app.config.general.process_refresh_key.as_deref().unwrap_or("ctrl+r"))
+    {
+        app.refresh_processes();
+        return;
+    }
+    if matches_key(key, app.config.general.reload_config_key.as_deref().unwrap_or("ctrl+r")) {
+        app.reload_config();
+        return;
+    }
+    if matches_key(key, app.config.general.window_switcher_key.as_deref().unwrap_or("alt+w")) {
+        app.open_window_switcher();
+        return;
+    }
+    if matches_key(key, app.config.general.print_key.as_deref().unwrap_or("ctrl+p")) {
+        app.print_selected();
+        return;
+    }
+    if app.debug && matches_key(key, app.config.general.debug_overlay_key.as_deref().unwrap_or("f12")) {
+        app.show_debug_overlay = !app.show_debug_overlay;
+        return;
+    }
+
+    match key.code {
+        KeyCode::Esc if matches!(app.mode, AppMode::OpenWith(_)) => app.close_open_with(),
+        KeyCode::Esc => {
+            app.cancelled = true;
+            app.should_quit = true;
+        }
+        KeyCode::Enter => app.launch(App::launch_selected, false),
+        KeyCode::Up => app.move_row(-down),
+        KeyCode::Down => app.move_row(down),
+        KeyCode::PageUp => app.move_page(-down as f32),
+        KeyCode::PageDown => app.move_page(down as f32),
+        KeyCode::Left if app.grid_columns() > 1 => app.move_selection(-1),
+        KeyCode::Right if app.grid_columns() > 1 => app.move_selection(1),
+        KeyCode::Left => app.move_search_cursor_left(),
+        KeyCode::Right => app.move_search_cursor_right(),
+        _ if matches_key(key, app.config.general.favorite_key.as_deref().unwrap_or("alt+f")) => {
+            app.toggle_favorite();
+        }
+        _ if matches_key(key, app.config.general.hide_key.as_deref().unwrap_or("alt+h")) => {
+            app.toggle_hidden();
+        }
+        KeyCode::Backspace => app.backspace_search_char(),
+        KeyCode::Char(c) => app.insert_search_char(c),
+        KeyCode::BackTab => app.auto_complete_previous(),
+        KeyCode::Tab if key.modifiers.contains(KeyModifiers::SHIFT) => app.auto_complete_previous(),
+        KeyCode::Tab => app.auto_complete(),
+        _ => {}
+    }
+}
+
+enum KeyPart {
+    Modifier(KeyModifiers),
+    Code(KeyCode),
+}
+
+fn parse_key_part(part: &str) -> Option<KeyPart> {
+    let code = match part {
+        "ctrl" | "control" => return Some(KeyPart::Modifier(KeyModifiers::CONTROL)),
+        "alt" | "option" => return Some(KeyPart::Modifier(KeyModifiers::ALT)),
+        "shift" => return Some(KeyPart::Modifier(KeyModifiers::SHIFT)),
+        "super" | "cmd" | "win" | "meta" => return Some(KeyPart::Modifier(KeyModifiers::SUPER)),
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "backspace" => KeyCode::Backspace,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        s if s.chars().count() == 1 => KeyCode::Char(s.chars().next()?),
+        s if s.starts_with('f') && s.len() > 1 => KeyCode::F(s[1..].parse().ok()?),
+        _ => return None,
+    };
+    Some(KeyPart::Code(code))
+}
+
+// Unknown parts are skipped, so a typo leaves the rest of the binding in effect.
+fn parse_key_spec(spec: &str) -> (KeyModifiers, Option<KeyCode>) {
+    let spec = spec.to_lowercase();
+    let mut required_modifiers = KeyModifiers::empty();
+    let mut required_code = None;
+
+    for part in spec.split('+') {
+        match parse_key_part(part) {
+            Some(KeyPart::Modifier(modifier)) => required_modifiers.insert(modifier),
+            Some(KeyPart::Code(code)) => required_code = Some(code),
+            None => {}
+        }
+    }
+
+    (required_modifiers, required_code)
+}
+
+// Every part is known and there's exactly one key besides the modifiers.
+fn is_valid_key_spec(spec: &str) -> bool {
+    let spec = spec.to_lowercase();
+    let parts: Option<Vec<KeyPart>> = spec.split('+').map(parse_key_part).collect();
+    parts.is_some_and(|parts| parts.iter().filter(|part| matches!(part, KeyPart::Code(_))).count() == 1)
+}
+
+// Builds the key event a binding string like "ctrl+d" describes, for replayed input.
+pub(crate) fn key_event_from_spec(spec: &str) -> Option<KeyEvent> {
+    let (modifiers, code) = parse_key_spec(spec);
+    code.map(|code| KeyEvent::new(code, modifiers))
+}
+
+fn matches_key(key: &KeyEvent, config_str: &str) -> bool {
+    let (required_modifiers, required_code) = parse_key_spec(config_str);
+
+    if let Some(code) = required_code
+        && key.code != code
+    {
+        return false;
     }
     
     key.modifiers.contains(required_modifiers)
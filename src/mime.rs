@@ -0,0 +1,123 @@
+use crate::app::glob_match;
+use dirs::{config_dir, data_dir};
+use std::{env, fs, path::{Path, PathBuf}};
+
+// `$XDG_DATA_HOME` followed by `$XDG_DATA_DIRS`, most important first.
+fn data_dirs() -> Vec<PathBuf> {
+    let system = env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+    data_dir()
+        .into_iter()
+        .chain(system.split(':').filter(|dir| !dir.is_empty()).map(PathBuf::from))
+        .collect()
+}
+
+// Guesses a MIME type from the file name using shared-mime-info's `globs2`, the same table
+// `xdg-mime` uses. Directories are `inode/directory` so they resolve to the file manager.
+pub fn mime_type(path: &Path) -> Option<String> {
+    if path.is_dir() {
+        return Some("inode/directory".to_string());
+    }
+    let name = path.file_name()?.to_str()?;
+    let lowered = name.to_lowercase();
+
+    let mut best: Option<(u32, usize, String)> = None;
+    for dir in data_dirs() {
+        let Ok(contents) = fs::read_to_string(dir.join("mime/globs2")) else {
+            continue;
+        };
+        for line in contents.lines().filter(|line| !line.starts_with('#')) {
+            let mut fields = line.split(':');
+            let (Some(weight), Some(mime), Some(glob)) = (fields.next(), fields.next(), fields.next()) else {
+                continue;
+            };
+            let case_sensitive = fields.any(|flags| flags.split(',').any(|flag| flag == "cs"));
+            let matched = if case_sensitive {
+                glob_match(glob, name)
+            } else {
+                glob_match(&glob.to_lowercase(), &lowered)
+            };
+            if !matched {
+                continue;
+            }
+
+            let weight = weight.parse().unwrap_or(50);
+            let better = match &best {
+                Some((best_weight, best_len, _)) => (weight, glob.len()) > (*best_weight, *best_len),
+                None => true,
+            };
+            if better {
+                best = Some((weight, glob.len(), mime.to_string()));
+            }
+        }
+    }
+    best.map(|(_, _, mime)| mime)
+}
+
+// Desktop ids (`org.gnome.Nautilus.desktop`) registered for `mime` in the `mimeapps.list` files,
+// defaults before added associations, user files before system ones.
+pub fn associated_apps(mime: &str) -> Vec<String> {
+    let mut lists: Vec<PathBuf> = config_dir().into_iter().map(|dir| dir.join("mimeapps.list")).collect();
+    if let Ok(dirs) = env::var("XDG_CONFIG_DIRS") {
+        lists.extend(dirs.split(':').filter(|dir| !dir.is_empty()).map(|dir| Path::new(dir).join("mimeapps.list")));
+    } else {
+        lists.push(PathBuf::from("/etc/xdg/mimeapps.list"));
+    }
+    lists.extend(data_dirs().into_iter().map(|dir| dir.join("applications/mimeapps.list")));
+
+    let contents: Vec<String> = lists.iter().filter_map(|path| fs::read_to_string(path).ok()).collect();
+    let mut apps = Vec::new();
+    for section in ["Default Applications", "Added Associations"] {
+        for list in &contents {
+            for app in section_values(list, section, mime) {
+                if !apps.contains(&app) {
+                    apps.push(app);
+                }
+            }
+        }
+    }
+    apps
+}
+
+// Desktop ids `update-desktop-database` recorded for `mime` in `applications/mimeinfo.cache`, for
+// types no mimeapps.list mentions.
+pub fn cached_apps(mime: &str) -> Vec<String> {
+    let mut apps = Vec::new();
+    for dir in data_dirs() {
+        let Ok(contents) = fs::read_to_string(dir.join("applications/mimeinfo.cache")) else {
+            continue;
+        };
+        for app in section_values(&contents, "MIME Cache", mime) {
+            if !apps.contains(&app) {
+                apps.push(app);
+            }
+        }
+    }
+    apps
+}
+
+fn section_values(contents: &str, section: &str, key: &str) -> Vec<String> {
+    let mut in_section = false;
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_section = line == format!("[{}]", section);
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+        if let Some((name, value)) = line.split_once('=')
+            && name.trim() == key
+        {
+            return value
+                .split(';')
+                .map(str::trim)
+                .filter(|app| !app.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+    }
+    Vec::new()
+}
@@ -0,0 +1,101 @@
+use crate::{config::PreviewConfig, history::format_date};
+use std::{
+    fs::{self, File},
+    io::Read,
+    os::unix::fs::PermissionsExt,
+    path::Path,
+    time::UNIX_EPOCH,
+};
+
+// Reads at most `max-bytes` of a file, so previewing a multi-gigabyte log stays instant.
+pub fn build(path: &Path, config: &PreviewConfig) -> Vec<String> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(err) => return vec![format!("cannot read: {}", err)],
+    };
+
+    if metadata.is_dir() {
+        return directory_preview(path, config.max_entries);
+    }
+
+    if metadata.is_file() {
+        let mut buffer = Vec::new();
+        if let Ok(file) = File::open(path) {
+            let _ = file.take(config.max_bytes).read_to_end(&mut buffer);
+        }
+
+        if buffer.contains(&0) {
+            return vec![format!("binary file, {}", human_size(metadata.len()))];
+        }
+        if let Some(text) = valid_utf8_prefix(&buffer) {
+            return text.lines().take(config.max_lines).map(|line| line.replace('\t', "    ")).collect();
+        }
+    }
+
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|d| format_date(d.as_secs()))
+        .unwrap_or_else(|| "-".to_string());
+    vec![
+        format!("size: {}", human_size(metadata.len())),
+        format!("modified: {}", modified),
+        format!("permissions: {:o}", metadata.permissions().mode() & 0o7777),
+    ]
+}
+
+fn directory_preview(path: &Path, max_entries: usize) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(path) else {
+        return vec!["cannot read directory".to_string()];
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| {
+            let mut name = entry.file_name().to_string_lossy().into_owned();
+            if entry.path().is_dir() {
+                name.push('/');
+            }
+            name
+        })
+        .collect();
+    names.sort_by(|a, b| b.ends_with('/').cmp(&a.ends_with('/')).then_with(|| a.cmp(b)));
+
+    let total = names.len();
+    names.truncate(max_entries);
+    if total > names.len() {
+        names.push(format!("… {} more", total - names.len()));
+    }
+    if names.is_empty() {
+        names.push("(empty)".to_string());
+    }
+    names
+}
+
+// A byte cap can cut a multi-byte character in half; only text that is invalid before the last
+// few bytes counts as binary.
+fn valid_utf8_prefix(buffer: &[u8]) -> Option<&str> {
+    match std::str::from_utf8(buffer) {
+        Ok(text) => Some(text),
+        Err(err) if buffer.len() - err.valid_up_to() < 4 && err.error_len().is_none() => {
+            std::str::from_utf8(&buffer[..err.valid_up_to()]).ok()
+        }
+        Err(_) => None,
+    }
+}
+
+pub fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
@@ -0,0 +1,80 @@
+use std::{collections::HashMap, fs, io};
+
+#[derive(Debug, Clone)]
+pub struct Process {
+    pub pid: i32,
+    pub name: String,
+    pub cmdline: String,
+    pub uid: u32,
+    pub rss_kb: u64,
+}
+
+// Snapshot of `/proc`, sorted by name. Kernel threads have an empty cmdline and are left out, as
+// are processes that exit while being read.
+pub fn list() -> Vec<Process> {
+    let Ok(dir) = fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    let own_pid = std::process::id() as i32;
+    let mut processes: Vec<Process> = dir
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str()?.parse::<i32>().ok())
+        .filter(|&pid| pid != own_pid)
+        .filter_map(read)
+        .collect();
+    processes.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()).then(a.pid.cmp(&b.pid)));
+    processes
+}
+
+fn read(pid: i32) -> Option<Process> {
+    let cmdline = fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+    if cmdline.is_empty() {
+        return None;
+    }
+    let cmdline = String::from_utf8_lossy(&cmdline)
+        .split('\0')
+        .filter(|arg| !arg.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let field = |key: &str| {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix(key))
+            .map(|value| value.trim().to_string())
+    };
+    let name = field("Name:")?;
+    let uid = field("Uid:")?.split_whitespace().next()?.parse().ok()?;
+    let rss_kb = field("VmRSS:")
+        .and_then(|value| value.split_whitespace().next()?.parse().ok())
+        .unwrap_or(0);
+
+    Some(Process { pid, name, cmdline, uid, rss_kb })
+}
+
+// Login names by uid, for marking processes owned by someone else.
+pub fn user_names() -> HashMap<u32, String> {
+    fs::read_to_string("/etc/passwd")
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split(':');
+            let name = fields.next()?;
+            let uid = fields.nth(1)?.parse().ok()?;
+            Some((uid, name.to_string()))
+        })
+        .collect()
+}
+
+pub fn current_uid() -> u32 {
+    unsafe { libc::getuid() }
+}
+
+pub fn signal(pid: i32, signal: libc::c_int) -> io::Result<()> {
+    if unsafe { libc::kill(pid, signal) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
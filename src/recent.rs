@@ -0,0 +1,77 @@
+use dirs::data_dir;
+use std::{fs, path::PathBuf};
+
+// Reads the `recently-used.xbel` that GTK apps keep and returns up to `limit` local files that
+// still exist, most recently used first. Only the `<bookmark>` tags are looked at, so this does
+// not need a full XML parser.
+pub fn load(limit: usize) -> Result<Vec<PathBuf>, String> {
+    let path = data_dir()
+        .map(|dir| dir.join("recently-used.xbel"))
+        .ok_or_else(|| "Could not locate the data directory".to_string())?;
+    let contents = fs::read_to_string(&path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+    if !contents.contains("<xbel") {
+        return Err(format!("{} is not an xbel file", path.display()));
+    }
+
+    let mut bookmarks: Vec<(String, PathBuf)> = contents
+        .split("<bookmark ")
+        .skip(1)
+        .filter_map(|chunk| {
+            // Keep the space the split consumed so every attribute is preceded by one.
+            let tag = format!(" {}", &chunk[..chunk.find('>')?]);
+            let href = unescape(attribute(&tag, "href")?);
+            let file = PathBuf::from(percent_decode(href.strip_prefix("file://")?));
+            // ISO 8601 timestamps, so the later one is also the larger string.
+            let used = [attribute(&tag, "modified"), attribute(&tag, "visited")]
+                .into_iter()
+                .flatten()
+                .max()
+                .unwrap_or_default()
+                .to_string();
+            Some((used, file))
+        })
+        .collect();
+
+    bookmarks.sort_by(|a, b| b.0.cmp(&a.0));
+    Ok(bookmarks
+        .into_iter()
+        .map(|(_, file)| file)
+        .filter(|file| file.exists())
+        .take(limit)
+        .collect())
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!(" {}=\"", name))? + name.len() + 3;
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| std::str::from_utf8(hex).ok());
+        match (bytes[i], hex.and_then(|hex| u8::from_str_radix(hex, 16).ok())) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
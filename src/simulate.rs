@@ -0,0 +1,115 @@
+use crate::{app::App, clock::{Clock, ManualClock}, config::AppConfig, handle_key, key_event_from_spec, ui::draw};
+use anyhow::{Context, Result, bail};
+use ratatui::{Terminal, backend::TestBackend};
+use std::{
+    fs,
+    path::Path,
+    rc::Rc,
+    time::{Duration, UNIX_EPOCH},
+};
+
+const DEFAULT_START_SECS: u64 = 1_700_000_000;
+
+// Replays a scenario against the user's config and installed apps with a clock that only moves
+// when told to, printing the screen on every `dump`. One directive per line, `#` for comments:
+//
+//   start <unix-secs>     wall-clock time the scenario begins at (before any other directive)
+//   size <cols>x<rows>    screen size, default 80x24
+//   key <binding>         press a key using config syntax: `down`, `ctrl+d`, `alt+f`, `enter`
+//   type <text>           type each character of <text>
+//   paste <text>          paste <text> in one go, as bracketed paste delivers it; `\n` is a newline
+//   advance <ms>          move the clock forward
+//   dump                  print the current screen
+//
+// Nothing is launched: `key enter` prints the command it would run, as `--print` does. History
+// is read as usual but never written, so replays don't change usage counts.
+pub fn run(path: &Path) -> Result<()> {
+    let script = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let lines: Vec<(usize, &str)> = script
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    let mut start_secs = DEFAULT_START_SECS;
+    let mut directives = lines.as_slice();
+    if let Some(((line_no, line), rest)) = directives.split_first()
+        && let Some(value) = line.strip_prefix("start ")
+    {
+        start_secs = value
+            .trim()
+            .parse()
+            .with_context(|| format!("line {line_no}: bad start time"))?;
+        directives = rest;
+    }
+
+    let clock = Rc::new(ManualClock::new(UNIX_EPOCH + Duration::from_secs(start_secs)));
+    let load_result = AppConfig::load();
+    let mut app = App::with_clock(load_result.config, load_result.warning, clock.clone());
+    app.print_only = true;
+    app.history.keep_in_memory();
+    app.wait_for_scan();
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24))?;
+
+    for &(line_no, line) in directives {
+        let (command, argument) = line.split_once(' ').unwrap_or((line, ""));
+        match command {
+            "size" => {
+                let (cols, rows) = argument
+                    .split_once('x')
+                    .and_then(|(c, r)| Some((c.trim().parse().ok()?, r.trim().parse().ok()?)))
+                    .with_context(|| format!("line {line_no}: expected <cols>x<rows>"))?;
+                terminal = Terminal::new(TestBackend::new(cols, rows))?;
+            }
+            "key" => {
+                let Some(key) = key_event_from_spec(argument.trim()) else {
+                    bail!("line {line_no}: unknown key `{}`", argument.trim());
+                };
+                handle_key(&mut app, &key);
+                app.settle_tasks();
+            }
+            "type" => {
+                for ch in argument.chars() {
+                    app.insert_search_char(ch);
+                    app.settle_tasks();
+                }
+            }
+            "paste" => {
+                app.paste_text(&argument.replace("\\n", "\n"));
+                app.settle_tasks();
+            }
+            "advance" => {
+                let ms: u64 = argument
+                    .trim()
+                    .parse()
+                    .with_context(|| format!("line {line_no}: expected milliseconds"))?;
+                clock.advance(Duration::from_millis(ms));
+                app.poll_tasks();
+            }
+            "dump" => {
+                terminal.draw(|f| draw(f, &mut app))?;
+                let buffer = terminal.backend().buffer();
+                println!("--- line {} (+{}ms) ---", line_no, clock.elapsed().as_millis());
+                for y in 0..buffer.area.height {
+                    let row: String = (0..buffer.area.width)
+                        .map(|x| buffer[(x, y)].symbol())
+                        .collect();
+                    println!("{}", row.trim_end());
+                }
+            }
+            _ => bail!("line {line_no}: unknown directive `{command}`"),
+        }
+
+        if app.should_quit {
+            if let Some(command) = &app.printed_command {
+                println!("--- printed ---\n{}", command);
+            }
+            break;
+        }
+    }
+
+    Ok(())
+}
+
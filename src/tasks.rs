@@ -0,0 +1,268 @@
+use crate::app::{AppEntry, FileEntry, ScriptOutput};
+use std::{
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
+    time::SystemTime,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskKind {
+    Scan,
+    Script,
+    Files,
+    Clipboard,
+}
+
+pub enum TaskOutput {
+    Entries(Vec<AppEntry>),
+    Excluded(Vec<(AppEntry, String)>),
+    Script(String, Result<ScriptOutput, String>),
+    Files(Vec<FileEntry>),
+    // Everything in a directory, for `App`'s listing cache; paths are relative to it.
+    Listing(PathBuf, Option<SystemTime>, Result<Vec<FileEntry>, String>),
+    Clipboard(Result<Vec<String>, String>),
+}
+
+enum TaskMessage {
+    Output(TaskKind, u64, TaskOutput),
+    Done(TaskKind, u64),
+}
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+pub struct TaskSink {
+    kind: TaskKind,
+    generation: u64,
+    latest: Arc<AtomicU64>,
+    sender: Sender<TaskMessage>,
+}
+
+impl TaskSink {
+    pub fn is_cancelled(&self) -> bool {
+        self.latest.load(Ordering::SeqCst) != self.generation
+    }
+
+    // Returns false once the task has been superseded so long-running work can stop early.
+    pub fn post(&self, output: TaskOutput) -> bool {
+        if self.is_cancelled() {
+            return false;
+        }
+        self.sender
+            .send(TaskMessage::Output(self.kind, self.generation, output))
+            .is_ok()
+    }
+}
+
+pub struct TaskPool {
+    jobs: Sender<Job>,
+    sender: Sender<TaskMessage>,
+    messages: Receiver<TaskMessage>,
+    generations: HashMap<TaskKind, Arc<AtomicU64>>,
+    pending: HashMap<TaskKind, u64>,
+}
+
+impl TaskPool {
+    pub fn new() -> Self {
+        let workers = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(2)
+            .clamp(2, 4);
+
+        let (jobs, job_receiver) = mpsc::channel::<Job>();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+        for _ in 0..workers {
+            let job_receiver = Arc::clone(&job_receiver);
+            thread::spawn(move || {
+                loop {
+                    let job = match job_receiver.lock() {
+                        Ok(receiver) => receiver.recv(),
+                        Err(_) => return,
+                    };
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => return,
+                    }
+                }
+            });
+        }
+
+        let (sender, messages) = mpsc::channel();
+        Self {
+            jobs,
+            sender,
+            messages,
+            generations: HashMap::new(),
+            pending: HashMap::new(),
+        }
+    }
+
+    pub fn spawn_task<F>(&mut self, kind: TaskKind, task: F)
+    where
+        F: FnOnce(&TaskSink) + Send + 'static,
+    {
+        let latest = Arc::clone(self.generations.entry(kind).or_default());
+        let generation = latest.fetch_add(1, Ordering::SeqCst) + 1;
+        self.pending.insert(kind, generation);
+
+        let sink = TaskSink {
+            kind,
+            generation,
+            latest,
+            sender: self.sender.clone(),
+        };
+        // A job superseded while it was queued never starts, and one that panics still reports
+        // `Done`, so `wait` can't block on it forever.
+        let job: Job = Box::new(move || {
+            if !sink.is_cancelled() {
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| task(&sink)));
+            }
+            let _ = sink.sender.send(TaskMessage::Done(kind, generation));
+        });
+
+        if self.jobs.send(job).is_err() {
+            self.pending.remove(&kind);
+        }
+    }
+
+    pub fn cancel(&mut self, kind: TaskKind) {
+        if self.pending.remove(&kind).is_some()
+            && let Some(latest) = self.generations.get(&kind)
+        {
+            latest.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    pub fn is_pending(&self, kind: TaskKind) -> bool {
+        self.pending.contains_key(&kind)
+    }
+
+    pub fn drain(&mut self) -> Vec<TaskOutput> {
+        let mut outputs = Vec::new();
+        while let Ok(message) = self.messages.try_recv() {
+            self.accept(message, &mut outputs);
+        }
+        outputs
+    }
+
+    // Blocks until the outstanding task of `kind` finishes, returning everything received meanwhile.
+    pub fn wait(&mut self, kind: TaskKind) -> Vec<TaskOutput> {
+        let mut outputs = Vec::new();
+        while self.is_pending(kind) {
+            match self.messages.recv() {
+                Ok(message) => self.accept(message, &mut outputs),
+                Err(_) => {
+                    self.pending.remove(&kind);
+                }
+            }
+        }
+        outputs
+    }
+
+    fn accept(&mut self, message: TaskMessage, outputs: &mut Vec<TaskOutput>) {
+        match message {
+            TaskMessage::Output(kind, generation, output) => {
+                if self.pending.get(&kind) == Some(&generation) {
+                    outputs.push(output);
+                }
+            }
+            TaskMessage::Done(kind, generation) => {
+                if self.pending.get(&kind) == Some(&generation) {
+                    self.pending.remove(&kind);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+
+    #[test]
+    fn wait_returns_when_a_task_panics() {
+        let mut pool = TaskPool::new();
+        pool.spawn_task(TaskKind::Script, |_| panic!("task panicked on purpose"));
+        assert!(pool.wait(TaskKind::Script).is_empty());
+        assert!(!pool.is_pending(TaskKind::Script));
+
+        pool.spawn_task(TaskKind::Script, |sink| {
+            sink.post(TaskOutput::Files(Vec::new()));
+        });
+        assert_eq!(pool.wait(TaskKind::Script).len(), 1, "workers should survive the panic");
+    }
+
+    #[test]
+    fn superseded_tasks_never_start() {
+        let mut pool = TaskPool::new();
+        // The pool has at most four workers; keep them all busy so the next jobs queue up.
+        let mut gates = Vec::new();
+        for kind in [TaskKind::Scan, TaskKind::Files, TaskKind::Clipboard, TaskKind::Script] {
+            let (gate, blocked) = mpsc::channel::<()>();
+            gates.push(gate);
+            pool.spawn_task(kind, move |_| {
+                let _ = blocked.recv();
+            });
+        }
+
+        let stale_ran = Arc::new(AtomicBool::new(false));
+        let ran = Arc::clone(&stale_ran);
+        pool.spawn_task(TaskKind::Files, move |_| ran.store(true, Ordering::SeqCst));
+        pool.spawn_task(TaskKind::Files, |sink| {
+            sink.post(TaskOutput::Files(Vec::new()));
+        });
+        drop(gates);
+
+        assert_eq!(pool.wait(TaskKind::Files).len(), 1);
+        for kind in [TaskKind::Scan, TaskKind::Clipboard, TaskKind::Script] {
+            pool.wait(kind);
+        }
+        assert!(!stale_ran.load(Ordering::SeqCst));
+    }
+
+    fn clipboard_lines(outputs: Vec<TaskOutput>) -> Vec<Vec<String>> {
+        outputs
+            .into_iter()
+            .map(|output| match output {
+                TaskOutput::Clipboard(Ok(lines)) => lines,
+                _ => panic!("expected clipboard lines"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn older_tasks_finishing_late_are_discarded() {
+        let mut pool = TaskPool::new();
+        let (started, old_started) = mpsc::channel::<()>();
+        let (release, released) = mpsc::channel::<()>();
+        let (finished, old_finished) = mpsc::channel::<bool>();
+        pool.spawn_task(TaskKind::Clipboard, move |sink| {
+            let _ = started.send(());
+            let _ = released.recv();
+            let posted = sink.post(TaskOutput::Clipboard(Ok(vec!["old".to_string()])));
+            // An output that got past the check just before the newer task was spawned.
+            let stale = TaskOutput::Clipboard(Ok(vec!["older".to_string()]));
+            let _ = sink.sender.send(TaskMessage::Output(sink.kind, sink.generation, stale));
+            let _ = finished.send(posted);
+        });
+        // Superseded while still queued it would never start; let it get going first.
+        old_started.recv().unwrap();
+        pool.spawn_task(TaskKind::Clipboard, |sink| {
+            sink.post(TaskOutput::Clipboard(Ok(vec!["new".to_string()])));
+        });
+
+        assert_eq!(clipboard_lines(pool.wait(TaskKind::Clipboard)), [["new"]]);
+        release.send(()).unwrap();
+        assert!(!old_finished.recv().unwrap(), "a superseded task's post should be refused");
+        // Its stale output was queued before it signalled, so this drain sees and drops it.
+        assert!(clipboard_lines(pool.drain()).is_empty());
+        assert!(!pool.is_pending(TaskKind::Clipboard));
+    }
+}
@@ -0,0 +1,170 @@
+// ASCII folding for search: strips Latin diacritics and transliterates Cyrillic and Greek.
+
+#[cfg(feature = "transliterate")]
+pub fn fold(input: &str) -> Option<String> {
+    let mut folded = String::with_capacity(input.len());
+    for ch in input.chars().flat_map(char::to_lowercase) {
+        match fold_char(ch) {
+            Some(replacement) => folded.push_str(replacement),
+            None => folded.push(ch),
+        }
+    }
+
+    if folded == input.to_lowercase() {
+        None
+    } else {
+        Some(folded)
+    }
+}
+
+#[cfg(not(feature = "transliterate"))]
+pub fn fold(_input: &str) -> Option<String> {
+    None
+}
+
+// Consonants of a folded name, with `x` as "ks", `c` and `q` as "k", `ph` as "f" and repeats
+// collapsed. Transliteration spells sounds rather than letters ("Файрфокс" folds to "fayrfoks"),
+// so "firefox" and "fayrfoks" only agree once both are cut down to "frfks".
+pub fn skeleton(folded: &str) -> String {
+    let mut skeleton = String::with_capacity(folded.len());
+    let mut push = |ch: char| {
+        if !skeleton.ends_with(ch) {
+            skeleton.push(ch);
+        }
+    };
+    let mut chars = folded.chars().flat_map(char::to_lowercase).peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'j' | 'w' => {}
+            'p' if chars.peek() == Some(&'h') => {
+                chars.next();
+                push('f');
+            }
+            'x' => {
+                push('k');
+                push('s');
+            }
+            'c' | 'q' => push('k'),
+            ch if ch.is_ascii_alphanumeric() => push(ch),
+            _ => {}
+        }
+    }
+    skeleton
+}
+
+#[cfg(feature = "transliterate")]
+fn fold_char(ch: char) -> Option<&'static str> {
+    let replacement = match ch {
+        // Latin with diacritics
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' | 'ģ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ķ' => "k",
+        'ł' | 'ļ' | 'ľ' => "l",
+        'ñ' | 'ń' | 'ň' | 'ņ' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' | 'ŕ' => "r",
+        'ś' | 'š' | 'ş' | 'ș' => "s",
+        'ß' => "ss",
+        'ť' | 'ţ' | 'ț' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+
+        // Cyrillic
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' | 'ґ' => "g",
+        'д' => "d",
+        'е' | 'ё' | 'э' => "e",
+        'є' => "ye",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'і' => "i",
+        'ї' => "yi",
+        'й' | 'ы' => "y",
+        'ј' => "j",
+        'к' => "k",
+        'л' => "l",
+        'љ' => "lj",
+        'м' => "m",
+        'н' => "n",
+        'њ' => "nj",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'ћ' => "c",
+        'ђ' => "dj",
+        'у' | 'ў' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'џ' => "dz",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' | 'ь' => "",
+        'ю' => "yu",
+        'я' => "ya",
+
+        // Greek
+        'α' | 'ά' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' | 'έ' => "e",
+        'ζ' => "z",
+        'η' | 'ή' => "i",
+        'θ' => "th",
+        'ι' | 'ί' | 'ϊ' | 'ΐ' => "i",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' | 'ό' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' | 'ύ' | 'ϋ' | 'ΰ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        'ω' | 'ώ' => "o",
+
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+#[cfg(all(test, feature = "transliterate"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_cyrillic_greek_and_diacritics() {
+        assert_eq!(fold("Файрфокс").as_deref(), Some("fayrfoks"));
+        assert_eq!(fold("Θέμα").as_deref(), Some("thema"));
+        assert_eq!(fold("Café").as_deref(), Some("cafe"));
+        assert_eq!(fold("firefox"), None);
+    }
+
+    #[test]
+    fn skeletons_agree_across_spellings() {
+        assert_eq!(skeleton("firefox"), "frfks");
+        assert_eq!(skeleton(&fold("Файрфокс").unwrap()), "frfks");
+        assert_eq!(skeleton("telegram"), skeleton(&fold("Телеграмм").unwrap()));
+        assert_eq!(skeleton("photos"), "fts");
+        assert_eq!(skeleton("aye"), "");
+    }
+}
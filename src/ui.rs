@@ -1,374 +1,534 @@
 use crate::{
-    app::{App, AppMode},
-    config::TextAlignment,
+    app::{App, AppEntry, AppMode, BOOKMARK_PREFIX, EntryKind, MatchField, MatchTier, RECENT_PREFIX},
+    config::{AppConfig, FileSort, GeneralConfig, ListLayout, SectionConfig, TextAlignment},
+    history::format_date,
+    preview::human_size,
 };
 use ratatui::{
     prelude::*,
     text::{Line, Span},
-    widgets::{Clear, List, ListItem, Paragraph},
+    widgets::{
+        Block, BorderType, Borders, Clear, List, ListDirection, ListItem, Paragraph, Scrollbar, ScrollbarOrientation,
+        ScrollbarState,
+    },
 };
-use std::f32::consts::PI;
+use std::{f32::consts::PI, path::Path};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+// Named screen regions for one frame. Sections that are hidden or empty are `None`.
+struct ScreenLayout {
+    // Where qst draws: the terminal, or the part of it `[window]` sizes and anchors.
+    bounds: Rect,
+    window: Option<Rect>,
+    outer_box: Option<Rect>,
+    ascii: Option<Rect>,
+    input: Option<Rect>,
+    status: Option<Rect>,
+    warning: Option<Rect>,
+    list: Rect,
+    preview: Option<Rect>,
+}
 
-pub fn draw(f: &mut Frame, app: &mut App) {
-    let area = f.area();
-    let config = &app.config;
-    let general = &config.general;
+#[derive(Clone, Copy, PartialEq)]
+enum Slot {
+    Warning,
+    Ascii,
+    Input,
+    Status,
+    List,
+    Spacer,
+}
 
-    f.render_widget(Clear, area);
+impl ScreenLayout {
+    fn compute(area: Rect, app: &App) -> Self {
+        let config = &app.config;
+        let general = &config.general;
+
+        let bounds = config.window.area(area);
+        let mut working_area = bounds;
+        let window = config.window.section.is_visible().then(|| {
+            working_area = config.window.section.block(general, "").inner(bounds);
+            bounds
+        });
+
+        let outer_box = config.outer_box.is_visible().then(|| {
+            let outer = working_area;
+            working_area = config.outer_box.block(general, "").inner(outer);
+            outer
+        });
+
+        let ascii_height = config.qst_ascii.section.is_visible().then(|| {
+            let p = &config.qst_ascii.padding;
+            app.qst_ascii.lines().count() as u16 + p.top + p.bottom
+        });
+        let input_height = config.input.section.is_visible().then_some(3);
+        let status_height = app.status_message.is_some().then_some(3);
+
+        // With `max-visible-entries` the list stops at that many rows and an empty spacer takes
+        // the rest, on the side away from the input.
+        let list_cap = (general.max_visible_entries > 0).then(|| {
+            let border = if config.list.section.is_visible() {
+                config.list.section.border_offset(general) * 2
+            } else {
+                0
+            };
+            (general.max_visible_entries as u16).saturating_mul(entry_height(app)).saturating_add(border)
+        });
+        let list_constraint = list_cap.map_or(Constraint::Min(1), Constraint::Length);
+        let spacer = list_cap.map(|_| Constraint::Min(0));
+
+        let ascii_slot = (Slot::Ascii, ascii_height.map(Constraint::Length));
+        let input_slot = (Slot::Input, input_height.map(Constraint::Length));
+        let status_slot = (Slot::Status, status_height.map(Constraint::Length));
+        let warning_slot = (Slot::Warning, app.config_warning.is_some().then_some(Constraint::Length(1)));
+        let list_slot = (Slot::List, Some(list_constraint));
+        let spacer_slot = (Slot::Spacer, spacer);
+        let slots: Vec<(Slot, Constraint)> = match general.layout {
+            ListLayout::Top => vec![warning_slot, ascii_slot, input_slot, status_slot, list_slot, spacer_slot],
+            ListLayout::Bottom => vec![warning_slot, ascii_slot, spacer_slot, list_slot, status_slot, input_slot],
+        }
+        .into_iter()
+        .filter_map(|(slot, constraint)| Some((slot, constraint?)))
+        .collect();
+
+        let chunks = Layout::vertical(slots.iter().map(|&(_, constraint)| constraint)).split(working_area);
+        let find = |wanted: Slot| slots.iter().position(|&(slot, _)| slot == wanted).map(|idx| chunks[idx]);
+        let ascii = find(Slot::Ascii);
+        let input = find(Slot::Input);
+        let status = find(Slot::Status);
+        let warning = find(Slot::Warning);
+        let mut list = find(Slot::List).unwrap_or(working_area);
+
+        let mut preview = None;
+        if app.mode == AppMode::FileSelection && config.preview.section.is_visible() {
+            let width = config.preview.width_percent.clamp(10, 90);
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(100 - width), Constraint::Percentage(width)])
+                .split(list);
+            list = columns[0];
+            preview = Some(columns[1]);
+        }
 
-    let mut working_area = area;
-    if config.window.is_visible() {
-        let block = config.window.block(general, "");
-        let inner = block.inner(area);
-        f.render_widget(block, area);
-        apply_section_border_colors(f, area, &config.window, general);
-        working_area = inner;
+        Self {
+            bounds,
+            window,
+            outer_box,
+            ascii,
+            input,
+            status,
+            warning,
+            list,
+            preview,
+        }
     }
+}
 
-    if config.outer_box.is_visible() {
-        let block = config.outer_box.block(general, "");
-        let inner = block.inner(working_area);
-        f.render_widget(block, working_area);
-        apply_section_border_colors(f, working_area, &config.outer_box, general);
-        working_area = inner;
+pub fn draw(f: &mut Frame, app: &mut App) {
+    let layout = ScreenLayout::compute(f.area(), app);
+    let config = &app.config;
+
+    if let Some(color) = config.window.backdrop.as_deref().and_then(crate::config::parse_color) {
+        f.render_widget(Block::default().style(Style::default().bg(color)), f.area());
     }
+    f.render_widget(Clear, layout.bounds);
 
-    let mut constraints = Vec::new();
-    
-    let qst_lines = app.qst_ascii.lines().count() as u16;
+    if let Some(area) = layout.window {
+        render_frame_section(f, area, &config.window.section, &config.general);
+    }
+    if let Some(area) = layout.outer_box {
+        render_frame_section(f, area, &config.outer_box, &config.general);
+    }
+    if let Some(area) = layout.ascii {
+        render_ascii(f, area, config, &app.qst_ascii);
+    }
+    if let Some(area) = layout.input {
+        render_input(f, area, config, input_prompt(app), &app.search_query, app.search_cursor);
+    }
+    if let (Some(area), Some(message)) = (layout.status, &app.status_message) {
+        render_status(f, area, message);
+    }
+    if let (Some(area), Some(warning)) = (layout.warning, &app.config_warning) {
+        let dismiss_key = config.general.dismiss_warning_key.as_deref().unwrap_or("alt+x");
+        render_warning(f, area, warning, dismiss_key, config.general.ellipsis.as_deref().unwrap_or("…"));
+    }
 
-    if config.qst_ascii.section.is_visible() {
-        let p = &config.qst_ascii.padding;
-        constraints.push(Constraint::Length(qst_lines + p.top + p.bottom)); 
+    render_list(f, layout.list, app);
+    if let Some(area) = layout.preview {
+        render_preview(f, area, app);
     }
 
-    if config.input.is_visible() {
-        constraints.push(Constraint::Length(3));
+    if app.show_debug_overlay && app.mode == AppMode::AppSelection && app.grid_columns() == 1 {
+        let inner = list_inner_area(layout.list, &app.config);
+        draw_debug_overlay(f, app, inner);
     }
-    if app.status_message.is_some() {
-        constraints.push(Constraint::Length(3));
+    if app.show_help {
+        draw_help_overlay(f, app, layout.list);
     }
-    constraints.push(Constraint::Min(1));
+}
 
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints(constraints)
-        .split(working_area);
+fn render_frame_section(f: &mut Frame, area: Rect, section: &SectionConfig, general: &GeneralConfig) {
+    f.render_widget(section.block(general, ""), area);
+    apply_section_border_colors(f, area, section, general);
+}
 
-    let mut chunk_index = 0;
+fn render_ascii(f: &mut Frame, chunk: Rect, config: &AppConfig, qst_ascii: &str) {
+    let qst_lines = qst_ascii.lines().count() as u16;
+    let p = &config.qst_ascii.padding;
+    let inner_area = Rect {
+        x: chunk.x + p.left,
+        y: chunk.y + p.top,
+        width: chunk.width.saturating_sub(p.left + p.right),
+        height: chunk.height.saturating_sub(p.top + p.bottom),
+    };
 
-    if config.qst_ascii.section.is_visible() {
-        let chunk = chunks[chunk_index];
-        chunk_index += 1;
-        
-        let p = &config.qst_ascii.padding;
-        let inner_area = Rect {
-            x: chunk.x + p.left,
-            y: chunk.y + p.top,
-            width: chunk.width.saturating_sub(p.left + p.right),
-            height: chunk.height.saturating_sub(p.top + p.bottom),
-        };
+    let ascii_colors = parse_gradient_colors(&config.qst_ascii.gradient_colors);
 
-        let ascii_colors = parse_gradient_colors(&config.qst_ascii.gradient_colors);
-
-        let mut widget = if ascii_colors.len() > 1 {
-             let width = app
-                 .qst_ascii
-                 .lines()
-                 .map(|line| line.chars().count() as u16)
-                 .max()
-                 .unwrap_or(1)
-                 .max(1);
-             let height = qst_lines.max(1);
-
-             let lines: Vec<Line> = app
-                 .qst_ascii
-                 .lines()
-                 .enumerate()
-                 .map(|(y, line)| {
-                     let spans: Vec<Span> = line
-                         .chars()
-                         .enumerate()
-                         .map(|(x, ch)| {
-                             let color = gradient_color_at_point(
-                                 &ascii_colors,
-                                 config.qst_ascii.gradient_angle,
-                                 x as u16,
-                                 y as u16,
-                                 width,
-                                 height,
-                             );
-                             Span::styled(ch.to_string(), Style::default().fg(color))
-                         })
-                         .collect();
-
-                     if spans.is_empty() {
-                         Line::from(Span::raw(""))
-                     } else {
-                         Line::from(spans)
-                     }
-                 })
-                 .collect();
-             Paragraph::new(lines)
-        } else {
-             let mut p_widget = Paragraph::new(app.qst_ascii.as_str());
-               if let Some(color) = ascii_colors
-                  .first()
-                  .copied()
-                  .or_else(|| config.qst_ascii.section.fg.first().and_then(|v| crate::config::parse_color(v)))
-               {
-                  p_widget = p_widget.style(Style::default().fg(color));
-             }
-             p_widget
-        };
+    let mut widget = if ascii_colors.len() > 1 {
+        let width = qst_ascii
+            .lines()
+            .map(|line| line.width() as u16)
+            .max()
+            .unwrap_or(1)
+            .max(1);
+        let height = qst_lines.max(1);
 
-        widget = widget.alignment(config.qst_ascii.alignment.unwrap_or(crate::config::TextAlignment::Center).into());
-        f.render_widget(widget, inner_area);
-    }
-
-    let search_chunk = if config.input.is_visible() {
-        let chunk = chunks[chunk_index];
-        chunk_index += 1;
-        Some(chunk)
+        let lines: Vec<Line> = qst_ascii
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                let spans: Vec<Span> = char_columns(line)
+                    .map(|(x, ch)| {
+                        let color = gradient_color_at_point(
+                            &ascii_colors,
+                            config.qst_ascii.gradient_angle,
+                            x,
+                            y as u16,
+                            width,
+                            height,
+                        );
+                        Span::styled(ch.to_string(), Style::default().fg(color))
+                    })
+                    .collect();
+
+                if spans.is_empty() {
+                    Line::from(Span::raw(""))
+                } else {
+                    Line::from(spans)
+                }
+            })
+            .collect();
+        Paragraph::new(lines)
     } else {
-        None
+        let mut p_widget = Paragraph::new(qst_ascii);
+        if let Some(color) = ascii_colors
+            .first()
+            .copied()
+            .or_else(|| config.qst_ascii.section.fg.first().and_then(|v| crate::config::parse_color(v)))
+        {
+            p_widget = p_widget.style(Style::default().fg(color));
+        }
+        p_widget
     };
 
-    let status_chunk = if app.status_message.is_some() {
-        let chunk = chunks[chunk_index];
-        chunk_index += 1;
-        Some(chunk)
-    } else {
-        None
+    widget = widget.alignment(config.qst_ascii.alignment.unwrap_or(TextAlignment::Center).into());
+    f.render_widget(widget, inner_area);
+}
+
+// The terminal cursor is only placed here; with the input hidden it stays hidden.
+fn render_input(f: &mut Frame, chunk: Rect, config: &AppConfig, prompt: &str, query: &str, cursor: usize) {
+    let general = &config.general;
+    let input = &config.input.section;
+    let prompt_style = match config.input.prompt_fg.as_deref().and_then(crate::config::parse_color) {
+        Some(color) => input.style().fg(color),
+        None => input.style(),
     };
+    let mut text = Line::from(Span::styled(prompt, prompt_style));
+    match config.input.placeholder.as_deref() {
+        Some(placeholder) if query.is_empty() => {
+            text.spans.push(Span::styled(placeholder, input.style().add_modifier(Modifier::DIM)));
+        }
+        _ => text.spans.push(Span::styled(query, input.style())),
+    }
+    let search_widget = Paragraph::new(text).style(input.style()).block(input.block(general, " Search "));
+    f.render_widget(search_widget, chunk);
+    apply_section_border_colors(f, chunk, input, general);
+
+    // The placeholder is drawn under the cursor, which stays at the start of the empty query.
+    let cursor_offset = input.border_offset(general);
+    let cursor_column = prompt.width() + cursor_column(query, cursor);
+    let cursor_x = (chunk.x + cursor_offset + cursor_column as u16).min(chunk.x + chunk.width.saturating_sub(1));
+    let cursor_y = (chunk.y + cursor_offset).min(chunk.y + chunk.height.saturating_sub(1));
+    f.set_cursor_position((cursor_x, cursor_y));
+}
 
-    let list_chunk = chunks[chunk_index];
-
-    if let Some(chunk) = search_chunk {
-        let title = " Search ";
-        let search_widget = Paragraph::new(app.search_query.as_str())
-            .style(config.input.style())
-            .block(config.input.block(general, title));
-        f.render_widget(search_widget, chunk);
-        apply_section_border_colors(f, chunk, &config.input, general);
-
-        let cursor_offset = config.input.border_offset(general);
-        let cursor_x = (chunk.x + cursor_offset + app.search_cursor as u16)
-            .min(chunk.x + chunk.width.saturating_sub(1));
-        let cursor_y = (chunk.y + cursor_offset).min(chunk.y + chunk.height.saturating_sub(1));
-        f.set_cursor_position((cursor_x, cursor_y));
+// `cursor` counts chars; the terminal wants columns, which wide and combining chars throw off.
+fn cursor_column(query: &str, cursor: usize) -> usize {
+    query.chars().take(cursor).filter_map(|c| c.width()).sum()
+}
+
+fn render_preview(f: &mut Frame, area: Rect, app: &mut App) {
+    let lines: Vec<Line> = app
+        .selected_preview()
+        .unwrap_or_default()
+        .iter()
+        .map(|line| Line::from(line.clone()))
+        .collect();
+
+    let section = &app.config.preview.section;
+    let general = &app.config.general;
+    let widget = Paragraph::new(lines)
+        .style(section.style())
+        .block(section.block(general, " Preview "));
+    f.render_widget(widget, area);
+    apply_section_border_colors(f, area, section, general);
+}
+
+fn render_status(f: &mut Frame, chunk: Rect, message: &str) {
+    let status = Paragraph::new(message).style(Style::default().fg(Color::Yellow));
+    f.render_widget(status, chunk);
+}
+
+// One line, whatever the warning's length: TOML errors span several, so they're joined and cut
+// to fit before the dismiss hint.
+fn render_warning(f: &mut Frame, area: Rect, warning: &str, dismiss_key: &str, ellipsis: &str) {
+    let hint = format!("  {} to dismiss", dismiss_key);
+    let message = warning.split_whitespace().collect::<Vec<_>>().join(" ");
+    let width = (area.width as usize).saturating_sub(hint.width() + 2);
+    let line = Line::from(vec![
+        Span::styled(
+            format!("! {}", truncate_with_ellipsis(&message, width, ellipsis)),
+            Style::default().fg(Color::LightRed).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(hint, Style::default().fg(Color::DarkGray)),
+    ]);
+    f.render_widget(Paragraph::new(line), area);
+}
+
+fn list_inner_area(area: Rect, config: &AppConfig) -> Rect {
+    if config.list.section.is_visible() {
+        config.list.section.block_with_title(&config.general, "").inner(area)
     } else {
-        f.set_cursor_position((list_chunk.x, list_chunk.y));
+        area
     }
+}
 
-    if let Some(chunk) = status_chunk {
-        if let Some(message) = &app.status_message {
-            let status = Paragraph::new(message.as_str()).style(Style::default().fg(Color::Yellow));
-            f.render_widget(status, chunk);
+// Shared styling for every list row, whichever mode produced it.
+struct RowPainter<'a> {
+    config: &'a AppConfig,
+    selected_idx: Option<usize>,
+    highlight_symbol: &'a str,
+    ellipsis: &'a str,
+    text_area_width: u16,
+    full_row_width: u16,
+    normal_entry_style: Style,
+    entry_fg_colors: Vec<Color>,
+    entry_bg_colors: Vec<Color>,
+    selected_fg_colors: Vec<Color>,
+    selected_bg_colors: Vec<Color>,
+    favorite_fg_colors: Vec<Color>,
+}
+
+impl<'a> RowPainter<'a> {
+    fn new(config: &'a AppConfig, area: Rect, selected_idx: Option<usize>, reserved_width: u16) -> Self {
+        let padding = if config.list.section.is_visible() {
+            config.list.section.border_offset(&config.general) * 2
+        } else {
+            0
+        };
+        let entry_selected_visible = config.entry_selected.is_visible();
+        let selected_symbol_width = if entry_selected_visible {
+            highlight_symbol_width(config)
+        } else {
+            0
+        };
+        let highlight_symbol = if entry_selected_visible {
+            config.general.highlight_symbol.as_deref().unwrap_or(">> ")
+        } else {
+            ""
+        };
+        let text_area_width = area
+            .width
+            .saturating_sub(padding)
+            .saturating_sub(selected_symbol_width)
+            .saturating_sub(reserved_width);
+
+        Self {
+            config,
+            selected_idx,
+            highlight_symbol,
+            ellipsis: config.general.ellipsis.as_deref().unwrap_or("…"),
+            text_area_width,
+            full_row_width: text_area_width + selected_symbol_width,
+            normal_entry_style: config.entry.base_style(config.text.style()),
+            entry_fg_colors: parse_gradient_colors(&config.entry.fg),
+            entry_bg_colors: parse_gradient_colors(&config.entry.bg),
+            selected_fg_colors: parse_gradient_colors(&config.entry_selected.fg),
+            selected_bg_colors: parse_gradient_colors(&config.entry_selected.bg),
+            favorite_fg_colors: parse_gradient_colors(&config.favorite.fg),
         }
     }
 
-    let scroll_area = list_chunk;
+    fn row(&self, idx: usize, label: &str, dim: bool) -> Line<'static> {
+        self.styled_row(idx, label, self.entry_style(dim, false), self.fg_colors(false))
+    }
 
-    let padding = if config.list.section.is_visible() {
-        config.list.section.border_offset(general) * 2
-    } else {
-        0
-    };
-    let entry_selected_visible = config.entry_selected.is_visible();
-    let selected_symbol_width = if entry_selected_visible {
-        highlight_symbol_width(config)
-    } else {
-        0
-    };
-    let mut text_area_width = scroll_area.width.saturating_sub(padding);
-    text_area_width = text_area_width.saturating_sub(selected_symbol_width);
-    let full_row_width = text_area_width + selected_symbol_width;
+    fn entry_style(&self, dim: bool, favorite: bool) -> Style {
+        let style = if dim {
+            self.normal_entry_style.add_modifier(Modifier::DIM)
+        } else {
+            self.normal_entry_style
+        };
+        if favorite { style.patch(self.config.favorite.style()) } else { style }
+    }
 
-    let entry_style = Style::default();
-    let normal_entry_style = config.entry.base_style(config.text.style());
+    // A `[favorite]` fg replaces the entry colors, gradient included.
+    fn fg_colors(&self, favorite: bool) -> &[Color] {
+        if favorite && !self.favorite_fg_colors.is_empty() {
+            &self.favorite_fg_colors
+        } else {
+            &self.entry_fg_colors
+        }
+    }
 
-    let entry_fg_colors = parse_gradient_colors(&config.entry.fg);
-    let entry_bg_colors = parse_gradient_colors(&config.entry.bg);
-    let selected_fg_colors = parse_gradient_colors(&config.entry_selected.fg);
-    let selected_bg_colors = parse_gradient_colors(&config.entry_selected.bg);
+    // A row drawn in `fg` instead of the configured entry colors.
+    fn tinted_row(&self, idx: usize, label: &str, fg: Color) -> Line<'static> {
+        self.styled_row(idx, label, self.normal_entry_style.fg(fg), &[])
+    }
 
-    let selected_idx = app.list_state.selected();
-    let highlight_symbol = if entry_selected_visible {
-        config.general.highlight_symbol.as_deref().unwrap_or(">> ")
-    } else {
-        ""
-    };
+    // `badge` is right-aligned in the `[usage]` style; the label gives way to it, ellipsized.
+    fn badged_row(&self, idx: usize, label: &str, badge: &str, dim: bool, favorite: bool) -> Line<'static> {
+        let is_selected = Some(idx) == self.selected_idx;
+        let width = self.text_area_width as usize;
+        let badge_width = badge.width();
+        let row_style = self.entry_style(dim, favorite);
+        let fg_colors = self.fg_colors(favorite);
+        if width <= badge_width + 1 {
+            return self.styled_row(idx, label, row_style, fg_colors);
+        }
+        let label = pad_to_width(&truncate_with_ellipsis(label, width - badge_width - 1, self.ellipsis), width - badge_width);
+        if is_selected && self.config.entry_selected.is_visible() {
+            return self.styled_row(idx, &format!("{}{}", label, badge), row_style, fg_colors);
+        }
 
-    let items: Vec<ListItem> = if app.mode == AppMode::AppSelection {
-            app.filtered_entries
-                .iter()
-                .enumerate()
-                .map(|(idx, entry)| {
-                    if !config.text.is_visible() {
-                        return ListItem::new(Span::raw(""));
-                    }
+        let mut line = self.styled_line(idx, &label, TextAlignment::Left, row_style, fg_colors);
+        line.spans.push(Span::styled(badge.to_string(), row_style.patch(self.config.usage.style())));
+        line
+    }
 
-                    let is_fav = app.history.is_favorite(&entry.name);
-                    let fav_symbol = config.general.favorite_symbol.as_deref().unwrap_or("★ ");
-                    let empty_prefix = " ".repeat(fav_symbol.chars().count());
-                    let prefix = if is_fav { fav_symbol } else { &empty_prefix };
-                    let name_with_icon = format!("{}{}", prefix, entry.name);
+    fn styled_row(&self, idx: usize, label: &str, row_style: Style, fg_colors: &[Color]) -> Line<'static> {
+        self.styled_line(idx, label, self.config.text.alignment(), row_style, fg_colors)
+    }
 
-                    let mut display_text =
-                        aligned_text(&name_with_icon, text_area_width, config.text.alignment());
+    // The dimmed second line of a detailed entry. It carries the selection colors so the
+    // highlight spans both lines, but not the highlight symbol.
+    fn detail_line(&self, idx: usize, indent: usize, detail: &str) -> Line<'static> {
+        let is_selected = Some(idx) == self.selected_idx;
+        let width = self.text_area_width as usize;
+        let label = format!("{}{}", " ".repeat(indent), truncate_with_ellipsis(detail, width.saturating_sub(indent), self.ellipsis));
+        let mut display_text = aligned_text(&label, self.text_area_width, self.config.text.alignment());
+        if self.config.entry_selected.is_visible() {
+            display_text = format!("{}{}", " ".repeat(self.highlight_symbol.width()), display_text);
+        }
 
-                    if entry_selected_visible {
-                        let prefix = if Some(idx) == selected_idx {
-                            highlight_symbol.to_string()
-                        } else {
-                            " ".repeat(highlight_symbol.chars().count())
-                        };
-                        display_text = format!("{}{}", prefix, display_text);
-                    }
+        let style = self.normal_entry_style.add_modifier(Modifier::DIM);
+        self.list_line(&display_text, is_selected, &self.entry_fg_colors, style)
+    }
 
-                    build_list_item(
-                        &display_text,
-                        config,
-                        Some(idx) == selected_idx,
-                        &entry_fg_colors,
-                        &entry_bg_colors,
-                        &selected_fg_colors,
-                        &selected_bg_colors,
-                        config.entry.gradient_angle,
-                        config.entry_selected.gradient_angle,
-                        full_row_width,
-                        normal_entry_style,
-                        entry_style,
-                    )
-                })
-                .collect()
-        } else if app.mode == AppMode::ScriptResults {
-            app.script_items
-                .iter()
-                .enumerate()
-                .map(|(idx, item)| {
-                    let visible_title = item.meta.display.as_deref().unwrap_or(&item.title);
-                    let label = if item.meta.urgent {
-                        format!("! {}", visible_title)
-                    } else if item.meta.active {
-                        format!("* {}", visible_title)
-                    } else {
-                        visible_title.to_string()
-                    };
-
-                    let mut display_text = aligned_text(&label, text_area_width, config.text.alignment());
-                    if entry_selected_visible {
-                        let prefix = if Some(idx) == selected_idx {
-                            highlight_symbol.to_string()
-                        } else {
-                            " ".repeat(highlight_symbol.chars().count())
-                        };
-                        display_text = format!("{}{}", prefix, display_text);
-                    }
+    fn styled_line(
+        &self,
+        idx: usize,
+        label: &str,
+        alignment: TextAlignment,
+        row_style: Style,
+        fg_colors: &[Color],
+    ) -> Line<'static> {
+        let is_selected = Some(idx) == self.selected_idx;
+        let label = truncate_with_ellipsis(label, self.text_area_width as usize, self.ellipsis);
+        let mut display_text = aligned_text(&label, self.text_area_width, alignment);
+        if self.config.entry_selected.is_visible() {
+            let prefix = if is_selected {
+                self.highlight_symbol.to_string()
+            } else {
+                " ".repeat(self.highlight_symbol.width())
+            };
+            display_text = format!("{}{}", prefix, display_text);
+        }
 
-                    build_list_item(
-                        &display_text,
-                        config,
-                        Some(idx) == selected_idx,
-                        &entry_fg_colors,
-                        &entry_bg_colors,
-                        &selected_fg_colors,
-                        &selected_bg_colors,
-                        config.entry.gradient_angle,
-                        config.entry_selected.gradient_angle,
-                        full_row_width,
-                        normal_entry_style,
-                        entry_style,
-                    )
-                })
-                .collect()
-        } else {
-            app.filtered_files
-                .iter()
-                .enumerate()
-                .map(|(idx, file)| {
-                    let mut display_text = aligned_text(file, text_area_width, config.text.alignment());
-                    if entry_selected_visible {
-                        let prefix = if Some(idx) == selected_idx {
-                            highlight_symbol.to_string()
-                        } else {
-                            " ".repeat(highlight_symbol.chars().count())
-                        };
-                        display_text = format!("{}{}", prefix, display_text);
-                    }
+        self.list_line(&display_text, is_selected, fg_colors, row_style)
+    }
 
-                    build_list_item(
-                        &display_text,
-                        config,
-                        Some(idx) == selected_idx,
-                        &entry_fg_colors,
-                        &entry_bg_colors,
-                        &selected_fg_colors,
-                        &selected_bg_colors,
-                        config.entry.gradient_angle,
-                        config.entry_selected.gradient_angle,
-                        full_row_width,
-                        normal_entry_style,
-                        entry_style,
-                    )
-                })
-                .collect()
-        };
+    fn list_line(
+        &self,
+        display_text: &str,
+        is_selected: bool,
+        entry_fg_colors: &[Color],
+        normal_entry_style: Style,
+    ) -> Line<'static> {
+        let config = self.config;
+        let (entry_bg_colors, entry_angle) = (&self.entry_bg_colors, config.entry.gradient_angle);
+        let (selected_fg_colors, selected_bg_colors) = (&self.selected_fg_colors, &self.selected_bg_colors);
+        let (selected_angle, full_row_width) = (config.entry_selected.gradient_angle, self.full_row_width);
+        if !is_selected || !config.entry_selected.is_visible() {
+            if entry_fg_colors.len() > 1 || entry_bg_colors.len() > 1 {
+                let width = display_text.width().max(1) as u16;
+                let spans: Vec<Span<'static>> = char_columns(display_text)
+                    .map(|(idx, ch)| {
+                        let mut style = normal_entry_style;
+                        if !entry_fg_colors.is_empty() {
+                            let fg = if entry_fg_colors.len() == 1 {
+                                entry_fg_colors[0]
+                            } else {
+                                gradient_color_at_point(entry_fg_colors, entry_angle, idx, 0, width, 1)
+                            };
+                            style = style.fg(fg);
+                        }
+                        if !entry_bg_colors.is_empty() {
+                            let bg = if entry_bg_colors.len() == 1 {
+                                entry_bg_colors[0]
+                            } else {
+                                gradient_color_at_point(entry_bg_colors, entry_angle, idx, 0, width, 1)
+                            };
+                            style = style.bg(bg);
+                        }
+                        Span::styled(ch.to_string(), style)
+                    })
+                    .collect();
+
+                return Line::from(spans);
+            }
+
+            return Line::from(Span::styled(display_text.to_string(), normal_entry_style));
+        }
 
-    let mut list = List::new(items);
-    if config.list.section.is_visible() {
-        let title = if app.mode == AppMode::AppSelection {
-            config.list.apps_title.as_deref().unwrap_or(" Applications ")
-        } else if app.mode == AppMode::ScriptResults {
-            app.script_title.as_deref().unwrap_or(" Scripts ")
+        let selected_text = if config.entry_selected.full_width_highlight.unwrap_or(true) {
+            pad_to_width(display_text, full_row_width as usize)
         } else {
-            config.list.files_title.as_deref().unwrap_or(" Directories ")
+            display_text.to_string()
         };
-        list = list.block(config.list.section.block_with_title(general, title));
-    }
 
-    f.render_stateful_widget(list, scroll_area, &mut app.list_state);
-    if config.list.section.is_visible() {
-        apply_section_border_colors(f, scroll_area, &config.list.section, general);
-    }
-}
-
-fn build_list_item(
-    display_text: &str,
-    config: &crate::config::AppConfig,
-    is_selected: bool,
-    entry_fg_colors: &[Color],
-    entry_bg_colors: &[Color],
-    selected_fg_colors: &[Color],
-    selected_bg_colors: &[Color],
-    entry_angle: u16,
-    selected_angle: u16,
-    full_row_width: u16,
-    normal_entry_style: Style,
-    entry_style: Style,
-) -> ListItem<'static> {
-    if !is_selected || !config.entry_selected.is_visible() {
-        if entry_fg_colors.len() > 1 || entry_bg_colors.len() > 1 {
-            let width = display_text.chars().count().max(1) as u16;
-            let spans: Vec<Span<'static>> = display_text
-                .chars()
-                .enumerate()
+        // The selection swaps in its own colors but keeps the row's modifiers (a bold favorite stays
+        // bold), except the dimming of binaries and detail lines.
+        let inherited = normal_entry_style.add_modifier.difference(Modifier::DIM);
+        let selected_style = Style::default().add_modifier(inherited).patch(config.entry_selected.style());
+        let width = selected_text.width().max(1) as u16;
+        if selected_fg_colors.len() > 1 || selected_bg_colors.len() > 1 {
+            let spans: Vec<Span<'static>> = char_columns(&selected_text)
                 .map(|(idx, ch)| {
-                    let mut style = normal_entry_style;
-                    if !entry_fg_colors.is_empty() {
-                        let fg = if entry_fg_colors.len() == 1 {
-                            entry_fg_colors[0]
+                    let mut style = selected_style;
+                    if !selected_fg_colors.is_empty() {
+                        let fg = if selected_fg_colors.len() == 1 {
+                            selected_fg_colors[0]
                         } else {
-                            gradient_color_at_point(entry_fg_colors, entry_angle, idx as u16, 0, width, 1)
+                            gradient_color_at_point(selected_fg_colors, selected_angle, idx, 0, width, 1)
                         };
                         style = style.fg(fg);
                     }
-                    if !entry_bg_colors.is_empty() {
-                        let bg = if entry_bg_colors.len() == 1 {
-                            entry_bg_colors[0]
+                    if !selected_bg_colors.is_empty() {
+                        let bg = if selected_bg_colors.len() == 1 {
+                            selected_bg_colors[0]
                         } else {
-                            gradient_color_at_point(entry_bg_colors, entry_angle, idx as u16, 0, width, 1)
+                            gradient_color_at_point(selected_bg_colors, selected_angle, idx, 0, width, 1)
                         };
                         style = style.bg(bg);
                     }
@@ -376,53 +536,493 @@ fn build_list_item(
                 })
                 .collect();
 
-            return ListItem::new(Line::from(spans)).style(entry_style);
+            Line::from(spans)
+        } else {
+            Line::from(Span::styled(selected_text, selected_style))
         }
+    }
+}
 
-        return ListItem::new(Line::from(Span::styled(display_text.to_string(), normal_entry_style)))
-            .style(entry_style);
+fn app_entry_rows(app: &App, painter: &RowPainter) -> Vec<Text<'static>> {
+    let config = &app.config;
+    let fav_symbol = config.general.favorite_symbol.as_deref().unwrap_or("★ ");
+    let numbered = config.general.numbered_favorites;
+    let empty_prefix = " ".repeat(fav_symbol.width() + usize::from(numbered));
+    let power_color = config.power_menu.fg.as_deref().and_then(crate::config::parse_color);
+    let show_usage = config.features.show_usage_count;
+    let detailed = config.features.detailed_entries;
+
+    app.filtered_rows()
+        .enumerate()
+        .map(|(idx, entry)| {
+            if !config.text.is_visible() {
+                return Text::raw("");
+            }
+
+            let favorite_slot = app.history.favorite_slot(&entry.name);
+            let prefix = match favorite_slot {
+                Some(slot) if numbered && slot < 9 => format!("{}{}", slot + 1, fav_symbol),
+                Some(_) if numbered => format!(" {}", fav_symbol),
+                Some(_) => fav_symbol.to_string(),
+                None => empty_prefix.clone(),
+            };
+            let label = if entry.kind == EntryKind::Missing {
+                let last_seen = app
+                    .history
+                    .last_seen(&entry.name)
+                    .map(|secs| format!("last seen {}", format_date(secs)))
+                    .unwrap_or_else(|| "never seen".to_string());
+                format!("{}{} (missing, {})", prefix, entry.name, last_seen)
+            } else {
+                match app.filtered_matches.get(idx).and_then(|info| secondary_match_text(entry, info.field)) {
+                    Some(text) => format!("{}{}  · {}", prefix, entry.name, text),
+                    None => format!("{}{}", prefix, entry.name),
+                }
+            };
+
+            let dim = matches!(entry.kind, EntryKind::Binary | EntryKind::Missing);
+            let favorite = favorite_slot.is_some();
+            let count = app.history.get_count(&entry.name);
+            let line = match power_color {
+                Some(color) if entry.kind == EntryKind::Power => painter.tinted_row(idx, &label, color),
+                _ if show_usage && count > 0 => painter.badged_row(idx, &label, &count.to_string(), dim, favorite),
+                _ => painter.styled_row(idx, &label, painter.entry_style(dim, favorite), painter.fg_colors(favorite)),
+            };
+            if !detailed {
+                return Text::from(line);
+            }
+            // Every entry gets a second line, blank without a description, so rows stay evenly sized.
+            let detail = entry.comment.as_deref().or(entry.generic_name.as_deref()).unwrap_or("");
+            Text::from(vec![line, painter.detail_line(idx, empty_prefix.len(), detail)])
+        })
+        .collect()
+}
+
+fn secondary_match_text(entry: &AppEntry, field: MatchField) -> Option<&str> {
+    match field {
+        MatchField::GenericName => entry.generic_name.as_deref(),
+        MatchField::Keyword(idx) => entry.keywords.get(idx).map(String::as_str),
+        MatchField::Comment => entry.comment.as_deref(),
+        MatchField::Name | MatchField::Transliteration => None,
     }
+}
 
-    let selected_text = if config.entry_selected.full_width_highlight.unwrap_or(true) {
-        pad_to_width(display_text, full_row_width as usize)
+fn script_rows(app: &App, painter: &RowPainter) -> Vec<ListItem<'static>> {
+    app.script_items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| {
+            let visible_title = item.meta.display.as_deref().unwrap_or(&item.title);
+            let label = if item.meta.urgent {
+                format!("! {}", visible_title)
+            } else if item.meta.active {
+                format!("* {}", visible_title)
+            } else {
+                visible_title.to_string()
+            };
+            ListItem::new(painter.row(idx, &label, false))
+        })
+        .collect()
+}
+
+fn file_rows(app: &App, painter: &RowPainter) -> Vec<ListItem<'static>> {
+    let show_details = app.config.features.file_details;
+    let now = app.now();
+    let bookmarks = app.bookmarks();
+    let mut rows: Vec<ListItem<'static>> = app
+        .filtered_files
+        .iter()
+        .enumerate()
+        .map(|(idx, file)| {
+            let bookmark = file
+                .path
+                .strip_prefix(BOOKMARK_PREFIX)
+                .and_then(|name| bookmarks.get(name.trim_end_matches('/')));
+            let path = match bookmark {
+                Some(target) => format!("{}  → {}", file.path, target),
+                None if file.broken_link => format!("{}  (broken link)", file.path),
+                None => file.path.clone(),
+            };
+            // Checkboxes only once something is marked.
+            let path = match app.marked_files.contains(&file.path) {
+                true => format!("☑ {}", path),
+                false if !app.marked_files.is_empty() => format!("☐ {}", path),
+                false => path,
+            };
+            if !show_details {
+                let path = truncate_middle(&path, painter.text_area_width as usize, painter.ellipsis);
+                return ListItem::new(painter.row(idx, &path, file.broken_link));
+            }
+
+            let size = if file.is_dir { "-".to_string() } else { human_size(file.size) };
+            let age = file
+                .modified
+                .and_then(|modified| now.duration_since(modified).ok())
+                .map(|age| relative_age(age.as_secs()))
+                .unwrap_or_else(|| "-".to_string());
+            let details = format!("{:>10} {:>8}", size, age);
+
+            let name_width = (painter.text_area_width as usize).saturating_sub(details.width() + 1);
+            let name = truncate_middle(&path, name_width, painter.ellipsis);
+            let label = format!("{} {}", pad_to_width(&name, name_width), details);
+            ListItem::new(painter.row(idx, &label, file.broken_link))
+        })
+        .collect();
+    // Past the last file, so the selection never lands on it.
+    if app.files_omitted > 0 {
+        let label = format!("{} {} more (refine your search)", painter.ellipsis, group_thousands(app.files_omitted));
+        rows.push(ListItem::new(painter.row(rows.len(), &label, true)));
+    }
+    rows
+}
+
+// 49500 -> "49,500".
+fn group_thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut out = String::new();
+    for (idx, digit) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(digit);
+    }
+    out
+}
+
+fn relative_age(secs: u64) -> String {
+    match secs {
+        0..60 => "now".to_string(),
+        60..3_600 => format!("{}m ago", secs / 60),
+        3_600..86_400 => format!("{}h ago", secs / 3_600),
+        86_400..2_592_000 => format!("{}d ago", secs / 86_400),
+        2_592_000..31_536_000 => format!("{}mo ago", secs / 2_592_000),
+        _ => format!("{}y ago", secs / 31_536_000),
+    }
+}
+
+// Keeps the start of `text`, which is what tells app names apart.
+fn truncate_with_ellipsis(text: &str, width: usize, ellipsis: &str) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    if width <= ellipsis.width() {
+        return take_width(text.chars(), width);
+    }
+    take_width(text.chars(), width - ellipsis.width()) + ellipsis
+}
+
+// Keeps both ends of a path, favoring the end: "~/very/…/long/file.txt".
+fn truncate_middle(text: &str, width: usize, ellipsis: &str) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    if width <= ellipsis.width() {
+        return take_width(text.chars(), width);
+    }
+    let budget = width - ellipsis.width();
+    let head = take_width(text.chars(), budget / 3);
+    let tail: String = take_width(text.chars().rev(), budget - head.width()).chars().rev().collect();
+    // A combining mark whose base char was cut off would land on the ellipsis.
+    let tail = tail.trim_start_matches(|c: char| c.width() == Some(0));
+    format!("{}{}{}", head, ellipsis, tail)
+}
+
+// The longest run of `chars` that fits in `width` columns.
+fn take_width(chars: impl Iterator<Item = char>, width: usize) -> String {
+    let mut taken = String::new();
+    let mut used = 0;
+    for c in chars {
+        used += c.width().unwrap_or(0);
+        if used > width {
+            break;
+        }
+        taken.push(c);
+    }
+    taken
+}
+
+// The text before the query: `input.prompt` in app mode, otherwise the mode's own prompt, which
+// only falls back to a built-in one once `input.prompt` is set.
+fn input_prompt(app: &App) -> &str {
+    let input = &app.config.input;
+    let (own, builtin) = match app.mode {
+        AppMode::AppSelection => (&input.prompt, ""),
+        AppMode::FileSelection => (&input.files_prompt, "files ❯ "),
+        AppMode::ScriptResults => (&input.scripts_prompt, "scripts ❯ "),
+        AppMode::OpenWith(_) => (&input.open_with_prompt, "open with ❯ "),
+    };
+    match own.as_deref() {
+        Some(prompt) => prompt,
+        None if input.prompt.is_some() => builtin,
+        None => "",
+    }
+}
+
+fn list_title(app: &App) -> String {
+    let config = &app.config;
+    let mut title = match &app.mode {
+        AppMode::AppSelection => config.list.apps_title.as_deref().unwrap_or(" Applications ").to_string(),
+        AppMode::OpenWith(file) => {
+            let name = Path::new(file).file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+            format!(" Open {} with ", name)
+        }
+        AppMode::ScriptResults => app.script_title.as_deref().unwrap_or(" Scripts ").to_string(),
+        AppMode::FileSelection if app.search_query.trim_start().starts_with(RECENT_PREFIX) => " Recent files ".to_string(),
+        AppMode::FileSelection => config.list.files_title.as_deref().unwrap_or(" Directories ").to_string(),
+    };
+    if app.mode == AppMode::FileSelection {
+        let mut hints = Vec::new();
+        if app.file_sort != FileSort::Name {
+            hints.push(app.file_sort.label());
+        }
+        if app.show_hidden {
+            hints.push("hidden");
+        }
+        if !hints.is_empty() {
+            title = format!("{}({}) ", title, hints.join(", "));
+        }
+    }
+    if config.features.show_match_count {
+        let count = match app.mode {
+            AppMode::AppSelection => format!("{}/{}", app.filtered_len(), app.listed_entry_count()),
+            AppMode::OpenWith(_) => app.filtered_len().to_string(),
+            AppMode::FileSelection => (app.filtered_files.len() + app.files_omitted).to_string(),
+            AppMode::ScriptResults => {
+                app.script_items.iter().filter(|item| !item.meta.nonselectable).count().to_string()
+            }
+        };
+        if !title.ends_with(' ') {
+            title.push(' ');
+        }
+        title = format!("{}{} ", title, count);
+    }
+    if app.is_busy() && app.mode == AppMode::AppSelection {
+        format!("{}scanning… {} ", title, app.spinner_frame())
+    } else if app.is_busy() {
+        format!("{}{} ", title, app.spinner_frame())
     } else {
-        display_text.to_string()
+        title
+    }
+}
+
+fn render_list(f: &mut Frame, area: Rect, app: &mut App) {
+    let columns = app.grid_columns();
+    if columns > 1 {
+        render_grid(f, area, app, columns);
+        return;
+    }
+
+    let config = &app.config;
+    let inner = list_inner_area(area, config);
+    let row_count = match app.mode {
+        AppMode::AppSelection | AppMode::OpenWith(_) => app.filtered_len(),
+        AppMode::ScriptResults => app.script_items.len(),
+        AppMode::FileSelection => app.filtered_files.len(),
     };
+    let visible_rows = (inner.height / entry_height(app)) as usize;
+    let show_scrollbar = config.scrollbar.section.is_visible() && row_count > visible_rows;
 
-    let selected_style = config.entry_selected.style();
-    let width = selected_text.chars().count().max(1) as u16;
-    if selected_fg_colors.len() > 1 || selected_bg_colors.len() > 1 {
-        let spans: Vec<Span<'static>> = selected_text
-            .chars()
-            .enumerate()
-            .map(|(idx, ch)| {
-                let mut style = selected_style;
-                if !selected_fg_colors.is_empty() {
-                    let fg = if selected_fg_colors.len() == 1 {
-                        selected_fg_colors[0]
-                    } else {
-                        gradient_color_at_point(selected_fg_colors, selected_angle, idx as u16, 0, width, 1)
-                    };
-                    style = style.fg(fg);
-                }
-                if !selected_bg_colors.is_empty() {
-                    let bg = if selected_bg_colors.len() == 1 {
-                        selected_bg_colors[0]
-                    } else {
-                        gradient_color_at_point(selected_bg_colors, selected_angle, idx as u16, 0, width, 1)
-                    };
-                    style = style.bg(bg);
-                }
-                Span::styled(ch.to_string(), style)
-            })
-            .collect();
+    let painter = RowPainter::new(config, area, app.list_state.selected(), u16::from(show_scrollbar));
+    let items = match app.mode {
+        AppMode::AppSelection | AppMode::OpenWith(_) => {
+            app_entry_rows(app, &painter).into_iter().map(ListItem::new).collect()
+        }
+        AppMode::ScriptResults => script_rows(app, &painter),
+        AppMode::FileSelection => file_rows(app, &painter),
+    };
 
-        ListItem::new(Line::from(spans)).style(entry_style)
-    } else {
-        ListItem::new(Line::from(Span::styled(selected_text, selected_style))).style(entry_style)
+    let mut list = List::new(items);
+    if config.general.layout == ListLayout::Bottom {
+        list = list.direction(ListDirection::BottomToTop);
+    }
+    if config.list.section.is_visible() {
+        list = list.block(config.list.section.block_with_title(&config.general, list_title(app)));
+    }
+
+    app.list_height = visible_rows;
+    f.render_stateful_widget(list, area, &mut app.list_state);
+    if app.config.list.section.is_visible() {
+        apply_section_border_colors(f, area, &app.config.list.section, &app.config.general);
+    }
+
+    if show_scrollbar {
+        render_scrollbar(f, inner, &app.config, row_count, visible_rows, app.list_state.offset());
     }
 }
 
+// App entries laid out left to right, top to bottom in `general.columns` columns. The list
+// state's offset is kept at the first item of the top visible row so scrolling carries over.
+fn render_grid(f: &mut Frame, area: Rect, app: &mut App, columns: usize) {
+    let config = &app.config;
+    let inner = list_inner_area(area, config);
+    let height = entry_height(app);
+    let visible_rows = (inner.height / height) as usize;
+    let grid_rows = app.filtered_len().div_ceil(columns);
+    let show_scrollbar = config.scrollbar.section.is_visible() && grid_rows > visible_rows;
+
+    let column_width = inner.width.saturating_sub(u16::from(show_scrollbar)) / columns as u16;
+    let cell_area = Rect {
+        width: column_width + (area.width - inner.width),
+        ..area
+    };
+    let selected = app.list_state.selected();
+    let painter = RowPainter::new(config, cell_area, selected, 0);
+    let cells = app_entry_rows(app, &painter);
+
+    let mut first_row = app.list_state.offset() / columns;
+    if let Some(row) = selected.map(|idx| idx / columns) {
+        if row < first_row {
+            first_row = row;
+        } else if row >= first_row + visible_rows {
+            first_row = row + 1 - visible_rows;
+        }
+    }
+    first_row = first_row.min(grid_rows.saturating_sub(visible_rows));
+
+    if config.list.section.is_visible() {
+        f.render_widget(config.list.section.block_with_title(&config.general, list_title(app)), area);
+        apply_section_border_colors(f, area, &config.list.section, &config.general);
+    }
+    for (idx, cell) in cells.into_iter().enumerate().skip(first_row * columns).take(visible_rows * columns) {
+        let cell_rect = Rect {
+            x: inner.x + (idx % columns) as u16 * column_width,
+            y: row_y(inner, config, (idx / columns - first_row) as u16, height),
+            width: column_width,
+            height,
+        };
+        f.render_widget(Paragraph::new(cell), cell_rect);
+    }
+    if show_scrollbar {
+        render_scrollbar(f, inner, config, grid_rows, visible_rows, first_row);
+    }
+
+    *app.list_state.offset_mut() = first_row * columns;
+    app.list_height = visible_rows;
+}
+
+// Top line of the `row`th visible list row, counted from the input side in the bottom layout.
+fn row_y(area: Rect, config: &AppConfig, row: u16, height: u16) -> u16 {
+    match config.general.layout {
+        ListLayout::Top => area.y + row * height,
+        ListLayout::Bottom => area.bottom().saturating_sub((row + 1) * height),
+    }
+}
+
+// Lines per list row: app entries take two with `features.detailed-entries`.
+fn entry_height(app: &App) -> u16 {
+    let app_rows = matches!(app.mode, AppMode::AppSelection | AppMode::OpenWith(_));
+    if app_rows && app.config.features.detailed_entries { 2 } else { 1 }
+}
+
+fn render_scrollbar(
+    f: &mut Frame,
+    area: Rect,
+    config: &AppConfig,
+    row_count: usize,
+    visible_rows: usize,
+    offset: usize,
+) {
+    let scrollbar_config = &config.scrollbar;
+    let mut scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .style(scrollbar_config.section.style());
+    if let Some(symbol) = scrollbar_config.track_symbol.as_deref() {
+        scrollbar = scrollbar.track_symbol(Some(symbol));
+    }
+    if let Some(symbol) = scrollbar_config.thumb_symbol.as_deref() {
+        scrollbar = scrollbar.thumb_symbol(symbol);
+    }
+
+    let scrollable = row_count.saturating_sub(visible_rows);
+    let position = match config.general.layout {
+        ListLayout::Top => offset.min(scrollable),
+        ListLayout::Bottom => scrollable - offset.min(scrollable),
+    };
+    let mut state = ScrollbarState::new(scrollable).position(position);
+    f.render_stateful_widget(scrollbar, area, &mut state);
+}
+
+fn draw_debug_overlay(f: &mut Frame, app: &App, area: Rect) {
+    let style = Style::default().fg(Color::DarkGray).add_modifier(Modifier::DIM);
+    let offset = app.list_state.offset();
+    let selected = app.list_state.selected();
+    let height = entry_height(app);
+
+    for row in 0..area.height / height {
+        let idx = offset + row as usize;
+        let (Some(entry), Some(info)) = (app.filtered_entry(idx), app.filtered_matches.get(idx)) else {
+            break;
+        };
+
+        let tier = match info.tier {
+            MatchTier::Unfiltered => "all",
+            MatchTier::Full => "full",
+            MatchTier::Partial => "partial",
+        };
+        let mut annotation = format!(
+            "{} score:{} uses:{}",
+            tier,
+            info.score,
+            app.history.get_count(&entry.name)
+        );
+        if app.history.is_favorite(&entry.name) {
+            annotation.push_str(" fav");
+        }
+        if Some(idx) == selected {
+            let field = match info.field {
+                MatchField::Name => "name",
+                MatchField::Transliteration => "transliteration",
+                MatchField::GenericName => "generic-name",
+                MatchField::Keyword(_) => "keyword",
+                MatchField::Comment => "comment",
+            };
+            annotation.push_str(&format!(" via {}", field));
+        }
+        annotation.push(' ');
+
+        let width = annotation.width() as u16;
+        if width >= area.width {
+            continue;
+        }
+        let x = area.x + area.width - width;
+        f.buffer_mut().set_string(x, row_y(area, &app.config, row, height), annotation, style);
+    }
+}
+
+// Centered over the list, sized to its rows and clipped to the list area.
+fn draw_help_overlay(f: &mut Frame, app: &App, area: Rect) {
+    let rows = app.help_rows();
+    let key_width = rows.iter().map(|(key, _)| key.chars().count()).max().unwrap_or(0);
+    let key_style = Style::default().add_modifier(Modifier::BOLD);
+    let lines: Vec<Line> = rows
+        .iter()
+        .map(|(key, description)| {
+            Line::from(vec![
+                Span::styled(format!(" {:<width$}  ", key, width = key_width), key_style),
+                Span::raw(format!("{} ", description)),
+            ])
+        })
+        .collect();
+
+    let content_width = lines.iter().map(|line| line.width()).max().unwrap_or(0) as u16;
+    let width = (content_width + 2).min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(if app.config.general.rounded_corners { BorderType::Rounded } else { BorderType::Plain })
+        .title(" Help ");
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).style(app.config.text.style()).block(block), popup);
+}
+
 fn apply_section_border_colors(
     f: &mut Frame,
     area: Rect,
@@ -473,6 +1073,15 @@ fn apply_section_border_colors(
     }
 }
 
+// Each char with the column it starts at, so gradients are spread over cells rather than chars.
+fn char_columns(text: &str) -> impl Iterator<Item = (u16, char)> + '_ {
+    text.chars().scan(0usize, |column, ch| {
+        let start = *column;
+        *column += ch.width().unwrap_or(0);
+        Some((start as u16, ch))
+    })
+}
+
 fn parse_gradient_colors(values: &[String]) -> Vec<Color> {
     values
         .iter()
@@ -532,7 +1141,7 @@ fn gradient_factor(
 }
 
 fn pad_to_width(text: &str, width: usize) -> String {
-    let len = text.chars().count();
+    let len = text.width();
     if len >= width {
         text.to_string()
     } else {
@@ -546,7 +1155,7 @@ fn aligned_text(text: &str, width: u16, alignment: TextAlignment) -> String {
     }
 
     let width = width as usize;
-    let current = text.chars().count();
+    let current = text.width();
     if current >= width {
         return text.to_string();
     }
@@ -554,7 +1163,7 @@ fn aligned_text(text: &str, width: u16, alignment: TextAlignment) -> String {
     let padding = width - current;
     match alignment {
         TextAlignment::Left => text.to_string(),
-        TextAlignment::Right => format!("{:>width$}", text, width = width),
+        TextAlignment::Right => format!("{}{}", " ".repeat(padding), text),
         TextAlignment::Center => {
             let left = padding / 2;
             let right = padding - left;
@@ -572,7 +1181,7 @@ fn highlight_symbol_width(config: &crate::config::AppConfig) -> u16 {
         .general
         .highlight_symbol
         .as_deref()
-        .map(|s| s.chars().count() as u16)
+        .map(|s| s.width() as u16)
         .unwrap_or(0)
 }
 
@@ -606,6 +1215,260 @@ fn color_to_rgb(c: Color) -> (u8, u8, u8) {
         Color::LightBlue => (85, 85, 255),
         Color::LightMagenta => (255, 85, 255),
         Color::LightCyan => (85, 255, 255),
+        Color::Indexed(i) => indexed_to_rgb(i),
         _ => (255, 255, 255),
     }
 }
+
+// Approximates an xterm 256-color palette index as RGB so indexed colors can be blended.
+fn indexed_to_rgb(i: u8) -> (u8, u8, u8) {
+    const BASE: [(u8, u8, u8); 16] = [
+        (0, 0, 0), (128, 0, 0), (0, 128, 0), (128, 128, 0), (0, 0, 128), (128, 0, 128), (0, 128, 128), (192, 192, 192),
+        (128, 128, 128), (255, 0, 0), (0, 255, 0), (255, 255, 0), (0, 0, 255), (255, 0, 255), (0, 255, 255),
+        (255, 255, 255),
+    ];
+    match i {
+        0..=15 => BASE[i as usize],
+        16..=231 => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = i - 16;
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        _ => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_column_counts_cells() {
+        assert_eq!(cursor_column("Türkçe", 6), 6);
+        assert_eq!(cursor_column("日本語", 2), 4);
+        assert_eq!(cursor_column("e\u{301}x", 2), 1);
+        assert_eq!(cursor_column("e\u{301}x", 3), 2);
+        assert_eq!(cursor_column("日本", 9), 4);
+    }
+
+    #[test]
+    fn alignment_pads_by_display_width() {
+        assert_eq!(aligned_text("日本", 6, TextAlignment::Right), "  日本");
+        assert_eq!(aligned_text("日本", 7, TextAlignment::Center), " 日本  ");
+        assert_eq!(aligned_text("Cafe\u{301}", 6, TextAlignment::Right), "  Cafe\u{301}");
+        assert_eq!(aligned_text("日本語", 4, TextAlignment::Center), "日本語");
+        assert_eq!(pad_to_width("日本語", 8), "日本語  ");
+        assert_eq!(pad_to_width("e\u{301}", 3).width(), 3);
+    }
+
+    #[test]
+    fn truncation_keeps_the_start_of_names() {
+        assert_eq!(truncate_with_ellipsis("Firefox", 7, "…"), "Firefox");
+        assert_eq!(truncate_with_ellipsis("Firefox Developer Edition", 10, "…"), "Firefox D…");
+        assert_eq!(truncate_with_ellipsis("日本語テキスト", 7, "…"), "日本語…");
+        // A wide char that would straddle the edge is left out rather than split.
+        assert_eq!(truncate_with_ellipsis("日本語テキスト", 6, "…"), "日本…");
+        assert_eq!(truncate_with_ellipsis("Cafe\u{301} Cre\u{300}me", 6, "…"), "Cafe\u{301} …");
+        assert_eq!(truncate_with_ellipsis("Firefox", 4, "..."), "F...");
+        assert_eq!(truncate_with_ellipsis("Firefox", 2, "..."), "Fi");
+    }
+
+    #[test]
+    fn truncation_keeps_the_end_of_paths() {
+        assert_eq!(truncate_middle("~/a/b.txt", 9, "…"), "~/a/b.txt");
+        assert_eq!(truncate_middle("~/very/deep/path/to/file.txt", 16, "…"), "~/ver…o/file.txt");
+        assert_eq!(truncate_middle("~/文書/報告書/最終版.txt", 14, "…"), "~/文…終版.txt");
+        assert_eq!(truncate_middle("~/docs/re\u{301}sume\u{301}.pdf", 12, "…"), "~/d…sume\u{301}.pdf");
+        assert_eq!(truncate_middle("/long/path", 1, "…"), "/");
+        for width in 3..30 {
+            let truncated = truncate_middle("~/文書/報告書/最終版.txt", width, "…");
+            assert!(truncated.width() <= width, "{} > {}", truncated, width);
+        }
+    }
+
+    #[test]
+    fn gradients_spread_over_cells() {
+        let columns: Vec<(u16, char)> = char_columns("a日b").collect();
+        assert_eq!(columns, [(0, 'a'), (1, '日'), (3, 'b')]);
+        let colors = [Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)];
+        let width = "a日b".width() as u16;
+        assert_eq!(gradient_color_at_point(&colors, 0, 3, 0, width, 1), Color::Rgb(255, 255, 255));
+    }
+
+    use crate::app::FileEntry;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn test_app(config: AppConfig) -> App {
+        let entries = ["Firefox", "Files", "Foot"]
+            .map(|name| AppEntry::new(name.to_string(), vec![name.to_lowercase()], EntryKind::Desktop))
+            .to_vec();
+        let mut app = App::with_entries(config, entries);
+        app.qst_ascii = "qst".to_string();
+        app.update_filter();
+        app
+    }
+
+    fn render(width: u16, height: u16, paint: impl FnOnce(&mut Frame)) -> Terminal<TestBackend> {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(paint).unwrap();
+        terminal
+    }
+
+    fn rows(terminal: &Terminal<TestBackend>) -> Vec<String> {
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect::<String>().trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn layout_stacks_sections_toward_the_list() {
+        let mut app = test_app(AppConfig::default());
+        app.status_message = Some("Launched Foot".to_string());
+        app.config_warning = Some("bad config".to_string());
+        let area = Rect::new(0, 0, 40, 20);
+
+        let top = ScreenLayout::compute(area, &app);
+        assert_eq!((top.bounds, top.window, top.outer_box, top.preview), (area, None, None, None));
+        assert_eq!(top.warning, Some(Rect::new(0, 0, 40, 1)));
+        assert_eq!(top.ascii, Some(Rect::new(0, 1, 40, 1)));
+        assert_eq!(top.input, Some(Rect::new(0, 2, 40, 3)));
+        assert_eq!(top.status, Some(Rect::new(0, 5, 40, 3)));
+        assert_eq!(top.list, Rect::new(0, 8, 40, 12));
+
+        app.config.general.layout = ListLayout::Bottom;
+        let bottom = ScreenLayout::compute(area, &app);
+        assert_eq!(bottom.warning, top.warning);
+        assert_eq!(bottom.ascii, top.ascii);
+        assert_eq!(bottom.list, Rect::new(0, 2, 40, 12));
+        assert_eq!(bottom.status, Some(Rect::new(0, 14, 40, 3)));
+        assert_eq!(bottom.input, Some(Rect::new(0, 17, 40, 3)));
+
+        app.mode = AppMode::FileSelection;
+        assert_eq!(ScreenLayout::compute(area, &app).preview, None, "the preview is off by default");
+        app.config.preview.section.visible = Some(true);
+        let files = ScreenLayout::compute(area, &app);
+        assert_eq!(files.list, Rect::new(0, 2, 24, 12));
+        assert_eq!(files.preview, Some(Rect::new(24, 2, 16, 12)));
+    }
+
+    #[test]
+    fn hidden_input_leaves_the_cursor_alone() {
+        let mut config = AppConfig::default();
+        config.input.section.visible = Some(false);
+        let mut app = test_app(config);
+        assert_eq!(ScreenLayout::compute(Rect::new(0, 0, 40, 12), &app).input, None);
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+        terminal.backend_mut().set_cursor_position((39, 11)).unwrap();
+        terminal.draw(|f| draw(f, &mut app)).unwrap();
+        terminal.backend_mut().assert_cursor_position((39, 11));
+        assert!(rows(&terminal).iter().all(|row| !row.contains("Search")));
+
+        app.config.input.section.visible = Some(true);
+        terminal.draw(|f| draw(f, &mut app)).unwrap();
+        terminal.backend_mut().assert_cursor_position((1, 2));
+    }
+
+    #[test]
+    fn input_draws_the_prompt_and_places_the_cursor() {
+        let config = AppConfig::default();
+        let mut terminal = render(30, 3, |f| render_input(f, f.area(), &config, "run ❯ ", "fire", 2));
+        assert_eq!(
+            rows(&terminal),
+            ["╭ Search ────────────────────╮", "│run ❯ fire                  │", "╰────────────────────────────╯"]
+        );
+        terminal.backend_mut().assert_cursor_position((9, 1));
+
+        let mut terminal = render(30, 3, |f| render_input(f, f.area(), &config, "", "", 0));
+        assert_eq!(rows(&terminal)[1], "│Type to search, / for files,│");
+        terminal.backend_mut().assert_cursor_position((1, 1));
+    }
+
+    #[test]
+    fn status_and_warning_fit_one_line() {
+        let terminal = render(30, 1, |f| render_status(f, f.area(), "Launched Foot"));
+        assert_eq!(rows(&terminal), ["Launched Foot"]);
+        assert_eq!(terminal.backend().buffer()[(0, 0)].fg, Color::Yellow);
+
+        let warning = "a very long\nwarning about the config";
+        let terminal = render(30, 1, |f| render_warning(f, f.area(), warning, "alt+x", "…"));
+        assert_eq!(rows(&terminal), ["! a very lo…  alt+x to dismiss"]);
+    }
+
+    #[test]
+    fn ascii_follows_its_alignment() {
+        let mut config = AppConfig::default();
+        let terminal = render(11, 2, |f| render_ascii(f, f.area(), &config, "qst\nlaunch"));
+        assert_eq!(rows(&terminal), ["    qst", "  launch"]);
+
+        config.qst_ascii.alignment = Some(TextAlignment::Right);
+        config.qst_ascii.padding.right = 1;
+        let terminal = render(11, 2, |f| render_ascii(f, f.area(), &config, "qst\nlaunch"));
+        assert_eq!(rows(&terminal), ["       qst", "    launch"]);
+    }
+
+    #[test]
+    fn list_marks_the_selected_entry() {
+        let mut app = test_app(AppConfig::default());
+        let terminal = render(30, 5, |f| render_list(f, f.area(), &mut app));
+        assert_eq!(
+            rows(&terminal),
+            [
+                "╭ Applications ──────────────╮",
+                "│>>   Files                  │",
+                "│     Firefox                │",
+                "│     Foot                   │",
+                "╰────────────────────────────╯",
+            ]
+        );
+        assert_eq!(app.list_height, 3);
+    }
+
+    #[test]
+    fn grid_fills_rows_left_to_right() {
+        let mut config = AppConfig::default();
+        config.general.columns = 2;
+        let mut app = test_app(config);
+        let terminal = render(40, 4, |f| render_list(f, f.area(), &mut app));
+        assert_eq!(
+            rows(&terminal),
+            [
+                "╭ Applications ────────────────────────╮",
+                "│>>   Files              Firefox       │",
+                "│     Foot                             │",
+                "╰──────────────────────────────────────╯",
+            ]
+        );
+        assert_eq!(app.list_height, 2);
+    }
+
+    #[test]
+    fn preview_shows_the_selected_file() {
+        let dir = std::env::temp_dir().join(format!("qst-preview-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("notes.txt");
+        std::fs::write(&file, "first line\nsecond line\n").unwrap();
+
+        let mut app = test_app(AppConfig::default());
+        app.mode = AppMode::FileSelection;
+        app.filtered_files = vec![FileEntry {
+            path: file.to_string_lossy().into_owned(),
+            is_dir: false,
+            broken_link: false,
+            size: 23,
+            modified: None,
+        }];
+        app.list_state.select(Some(0));
+        let terminal = render(20, 4, |f| render_preview(f, f.area(), &mut app));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            rows(&terminal),
+            ["╭ Preview ─────────╮", "│first line        │", "│second line       │", "╰──────────────────╯"]
+        );
+    }
+}